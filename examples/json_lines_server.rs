@@ -0,0 +1,82 @@
+// 標準入力から1行に1単語を受け取り、結果を1行のJSONで標準出力に返すサーバー
+//
+// echo applo | cargo run --example json_lines_server --features examples
+//
+// 機密の文書を扱う場合は、単語と候補をキー付きのハッシュに置き換えて返す
+// echo applo | cargo run --example json_lines_server --features examples -- --redact-key <key>
+use std::io::{self, BufRead, Write};
+
+use typo_checker::{
+    check_untrusted, get_typo_type_name, redact_word, CheckOptions, TypoCheckResult,
+};
+
+/// JSONの文字列としてエスケープする
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// チェックの結果をJSONにする
+fn result_to_json(check_word: &str, typo_check_result: &TypoCheckResult) -> String {
+    let suggestion_list: Vec<String> = typo_check_result
+        .get_similar_word_list()
+        .iter()
+        .map(|similar_word| {
+            format!(
+                "{{\"spelling\":{},\"distance\":{},\"type\":{}}}",
+                escape_json(similar_word.get_spelling()),
+                similar_word.get_levenshtein_length(),
+                escape_json(&get_typo_type_name(similar_word.get_typo_type()))
+            )
+        })
+        .collect();
+    format!(
+        "{{\"word\":{},\"match\":{},\"suggestions\":[{}]}}",
+        escape_json(check_word),
+        typo_check_result.is_match(),
+        suggestion_list.join(",")
+    )
+}
+
+fn main() -> io::Result<()> {
+    let options = CheckOptions::default();
+    let argument_list: Vec<String> = std::env::args().collect();
+    let redact_key = argument_list
+        .iter()
+        .position(|argument| argument == "--redact-key")
+        .and_then(|index| argument_list.get(index + 1));
+    let mut stdout = io::stdout().lock();
+
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let check_word = line.trim();
+        let response_word = match redact_key {
+            Some(key) => redact_word(check_word, key),
+            None => check_word.to_string(),
+        };
+        let response = match check_untrusted(check_word, &options) {
+            Ok(typo_check_result) => match redact_key {
+                Some(key) => result_to_json(&response_word, &typo_check_result.redacted(key)),
+                None => result_to_json(&response_word, &typo_check_result),
+            },
+            Err(error) => format!(
+                "{{\"word\":{},\"error\":{}}}",
+                escape_json(&response_word),
+                escape_json(&error.to_string())
+            ),
+        };
+        writeln!(stdout, "{}", response)?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
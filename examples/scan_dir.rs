@@ -0,0 +1,72 @@
+// ディレクトリ内のテキストファイルの単語をチェックし、タイポの候補を表示する
+//
+// cargo run --example scan_dir --features examples -- <directory>
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use typo_checker::{check_untrusted, CheckOptions, Profile};
+
+/// ディレクトリを再帰的に走査し、ファイルごとにチェックを行う
+fn scan_dir(dir: &Path, options: &CheckOptions, finding_num: &mut usize) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            scan_dir(&path, options, finding_num)?;
+        } else if let Ok(text) = fs::read_to_string(&path) {
+            // UTF-8でないファイルはスキップする
+            scan_text(&path, &text, options, finding_num);
+        }
+    }
+    Ok(())
+}
+
+/// 行の英字の並びを、開始位置(バイト)と一緒に返す
+fn find_ascii_words(line: &str) -> Vec<(usize, &str)> {
+    let mut word_list = Vec::new();
+    let mut start = None;
+    for (index, c) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (c.is_ascii_alphabetic(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                word_list.push((word_start, &line[word_start..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    word_list
+}
+
+/// テキストを英字の並びに区切り、タイポの候補を表示する
+fn scan_text(path: &Path, text: &str, options: &CheckOptions, finding_num: &mut usize) {
+    for (line_index, line) in text.lines().enumerate() {
+        for (start, word) in find_ascii_words(line) {
+            // 辞書の単語より長い単語などは拒否されるためスキップする
+            let Ok(typo_check_result) = check_untrusted(word, options) else {
+                continue;
+            };
+            if let Some(message) = typo_check_result.did_you_mean(3) {
+                *finding_num += 1;
+                println!(
+                    "{}:{}:{}: {}: {}",
+                    path.display(),
+                    line_index + 1,
+                    start + 1,
+                    word,
+                    message
+                );
+            }
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let dir = std::env::args().nth(1).unwrap_or_else(|| ".".to_string());
+    let options = CheckOptions::with_profile(Profile::Prose);
+
+    let mut finding_num = 0;
+    scan_dir(Path::new(&dir), &options, &mut finding_num)?;
+    eprintln!("{} findings", finding_num);
+    Ok(())
+}
@@ -0,0 +1,137 @@
+// 入力した単語ごとに、順位付けした候補の表を表示する
+// `:set <key> <value>`でオプションを変更し、`:add <word>`で単語を個人の辞書に加える
+//
+// cargo run --example word_repl --features examples,toml
+use std::io::{self, BufRead, Write};
+
+use typo_checker::{check_untrusted, CheckOptions, ConfigResolver};
+
+const HELP: &str = "\
+<word>               show the ranked suggestions of the word
+:set <key> <value>   change an option, such as `:set cutoff 3`
+:add <word>          add a word to the personal dictionary
+:options             show the current options
+:help                show this help
+:quit                exit";
+
+/// 短い名前をオプションのキーに変換する
+fn option_key(key: &str) -> &str {
+    match key {
+        "cutoff" => "output_levenshtein_cutoff",
+        "pickup" => "pickup_similar_word_num",
+        "length_aware" => "length_aware_cutoff",
+        key => key,
+    }
+}
+
+/// 変更したオプションと個人の辞書の単語を保持する
+struct Session {
+    flag_list: Vec<(String, String)>,
+    personal_word_list: Vec<String>,
+    options: CheckOptions,
+}
+
+impl Session {
+    /// オプションを変更し、設定として正しくない場合は変更を取り消す
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        self.flag_list
+            .push((option_key(key).to_string(), value.to_string()));
+        match self.resolve() {
+            Ok(options) => {
+                self.options = options;
+                Ok(())
+            }
+            Err(message) => {
+                self.flag_list.pop();
+                Err(message)
+            }
+        }
+    }
+
+    /// 単語を個人の辞書に加える
+    fn add(&mut self, word: &str) {
+        self.personal_word_list.push(word.to_lowercase());
+        self.options.stop_words.push(word.to_lowercase());
+    }
+
+    /// 変更したオプションから検証したCheckOptionsを返す
+    fn resolve(&self) -> Result<CheckOptions, String> {
+        let resolver = self
+            .flag_list
+            .iter()
+            .fold(ConfigResolver::new(), |resolver, (key, value)| {
+                resolver.command_line_flag(key, value)
+            });
+        let mut options = resolver.validate().map_err(|problem_list| {
+            problem_list
+                .iter()
+                .map(|problem| problem.to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })?;
+        // 個人の辞書の単語はチェックしない
+        options
+            .stop_words
+            .extend(self.personal_word_list.iter().cloned());
+        Ok(options)
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut session = Session {
+        flag_list: Vec::new(),
+        personal_word_list: Vec::new(),
+        options: CheckOptions::default(),
+    };
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    loop {
+        write!(stdout, "> ")?;
+        stdout.flush()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            break;
+        }
+        let input = line.trim();
+        let mut argument_list = input.split_whitespace();
+
+        match argument_list.next() {
+            None => {}
+            Some(":quit") => break,
+            Some(":help") => writeln!(stdout, "{}", HELP)?,
+            Some(":options") => writeln!(stdout, "{:#?}", session.options)?,
+            Some(":set") => match (argument_list.next(), argument_list.next()) {
+                (Some(key), Some(value)) => match session.set(key, value) {
+                    Ok(()) => writeln!(stdout, "{} = {}", option_key(key), value)?,
+                    Err(message) => writeln!(stdout, "error: {}", message)?,
+                },
+                _ => writeln!(stdout, "usage: :set <key> <value>")?,
+            },
+            Some(":add") => match argument_list.next() {
+                Some(word) => {
+                    session.add(word);
+                    writeln!(stdout, "added {}", word)?;
+                }
+                None => writeln!(stdout, "usage: :add <word>")?,
+            },
+            Some(command) if command.starts_with(':') => {
+                writeln!(stdout, "unknown command: {}\n{}", command, HELP)?
+            }
+            // 入力はそのまま渡さず、拒否された場合は理由を表示する
+            Some(_) => match check_untrusted(input, &session.options) {
+                Ok(_) if session.options.is_skipped_word(input) => writeln!(
+                    stdout,
+                    "{} is skipped (personal dictionary or stop word)",
+                    input
+                )?,
+                Ok(typo_check_result) => {
+                    writeln!(stdout, "{}", typo_check_result.to_debug_table())?
+                }
+                Err(error) => writeln!(stdout, "error: {}", error)?,
+            },
+        }
+    }
+    Ok(())
+}
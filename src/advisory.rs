@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use crate::levenshtein;
+
+/// Struct that stores an advisory for a correctly spelled but rare word that is one edit away from a common word.
+/// It is a low-severity hint of a real-word typo such as "wan" for "want".
+///
+/// 正しい綴りだが珍しい単語が、よく使われる単語と1文字違いであることを知らせる構造体です。
+/// "want"を"wan"と打つような実在する単語へのタイポの、重要度の低いヒントです。
+///
+/// # Arguments
+///
+/// * `word` - The rare word(珍しい単語)
+/// * `word_frequency` - Frequency of the rare word(珍しい単語の頻度)
+/// * `common_word` - The common word one edit away(1文字違いのよく使われる単語)
+/// * `common_word_frequency` - Frequency of the common word(よく使われる単語の頻度)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RareWordAdvisory {
+    pub word: String,
+    pub word_frequency: f64,
+    pub common_word: String,
+    pub common_word_frequency: f64,
+}
+
+/// Returns an advisory when the check word is rarer than `rare_threshold` and a word one edit away is at least as frequent as `common_threshold`.
+/// Words not in the frequency map are treated as frequency 0. If several common words are found, the most frequent one is returned.
+///
+/// チェックする単語の頻度が`rare_threshold`より低く、1文字違いの単語の頻度が`common_threshold`以上の場合に注意を返します。
+/// 頻度のハッシュマップに無い単語は頻度0として扱います。よく使われる単語が複数ある場合は最も頻度が高い単語を返します。
+///
+/// # Arguments
+///
+/// * `check_word` - The check word(チェックする単語)
+/// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+/// * `rare_threshold` - Frequency below which the check word is rare(チェックする単語を珍しいとみなす頻度)
+/// * `common_threshold` - Frequency at or above which a word is common(よく使われる単語とみなす頻度)
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use typo_checker::find_rare_word_advisory;
+///
+/// let frequency_map: HashMap<String, f64> = [("wan", 0.5), ("want", 40.0), ("was", 90.0)]
+///     .into_iter()
+///     .map(|(word, frequency)| (word.to_string(), frequency))
+///     .collect();
+///
+/// let advisory = find_rare_word_advisory("wan", &frequency_map, 1.0, 10.0).unwrap();
+/// assert_eq!(advisory.common_word, "was");
+/// assert!(find_rare_word_advisory("want", &frequency_map, 1.0, 10.0).is_none());
+/// ```
+pub fn find_rare_word_advisory(
+    check_word: &str,
+    frequency_map: &HashMap<String, f64>,
+    rare_threshold: f64,
+    common_threshold: f64,
+) -> Option<RareWordAdvisory> {
+    let lowercase_check_word = check_word.to_lowercase();
+    let word_frequency = frequency_map
+        .get(&lowercase_check_word)
+        .copied()
+        .unwrap_or(0.0);
+    if word_frequency >= rare_threshold {
+        return None;
+    }
+
+    let check_word_length = lowercase_check_word.chars().count();
+    frequency_map
+        .iter()
+        .filter(|(word, frequency)| {
+            **frequency >= common_threshold
+                && word.chars().count().abs_diff(check_word_length) <= 1
+                && levenshtein(&lowercase_check_word, word) == 1
+        })
+        .max_by(|a, b| a.1.total_cmp(b.1).then_with(|| b.0.cmp(a.0)))
+        .map(|(common_word, common_word_frequency)| RareWordAdvisory {
+            word: lowercase_check_word.clone(),
+            word_frequency,
+            common_word: common_word.clone(),
+            common_word_frequency: *common_word_frequency,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frequency_map() -> HashMap<String, f64> {
+        [
+            ("form", 50.0),
+            ("from", 500.0),
+            ("fork", 20.0),
+            ("frm", 0.1),
+        ]
+        .into_iter()
+        .map(|(word, frequency)| (word.to_string(), frequency))
+        .collect()
+    }
+
+    #[test]
+    fn test_find_rare_word_advisory_picks_most_frequent() {
+        let advisory = find_rare_word_advisory("Frm", &frequency_map(), 1.0, 10.0).unwrap();
+
+        assert_eq!(advisory.word, "frm");
+        assert_eq!(advisory.common_word, "from");
+        assert_eq!(advisory.word_frequency, 0.1);
+    }
+
+    #[test]
+    fn test_find_rare_word_advisory_none() {
+        // 頻度の高い単語同士は報告しない
+        assert!(find_rare_word_advisory("form", &frequency_map(), 1.0, 10.0).is_none());
+        // 近くによく使われる単語がない
+        assert!(find_rare_word_advisory("xyz", &frequency_map(), 1.0, 10.0).is_none());
+    }
+}
@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+
+use crate::Dictionary;
+
+/// 組み込み辞書のブルームフィルタの1単語あたりのビット数(偽陽性率は約1%)
+const DEFAULT_BITS_PER_WORD: usize = 10;
+
+/// Struct of a bloom filter built over the words of a dictionary, to screen out words that are not in the dictionary without searching it.
+/// A word that is in the dictionary is always accepted, and a word that is not in the dictionary is rarely accepted by mistake.
+///
+/// 辞書を探索せずに辞書に無い単語を除外するための、辞書の単語から作るブルームフィルタの構造体です。
+/// 辞書にある単語は必ず受け入れ、辞書に無い単語を誤って受け入れることはまれです。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_a_word_with, CheckOptions, Dictionary, WordBloomFilter};
+///
+/// let bloom_filter = WordBloomFilter::new(&Dictionary::builtin(), 10);
+/// for word in ["apple", "Hello", "applo"] {
+///     // 辞書に無いことが確かな単語のみ候補を探す
+///     if !bloom_filter.is_probably_word(word) {
+///         let typo_chec_result = check_a_word_with(word, &CheckOptions::default());
+///         println!("typo_chec_result: {:?}", typo_chec_result);
+///     }
+/// }
+/// assert!(bloom_filter.is_probably_word("apple"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordBloomFilter {
+    bit_list: Vec<u64>,
+    hash_num: u32,
+}
+
+impl Default for WordBloomFilter {
+    fn default() -> WordBloomFilter {
+        WordBloomFilter::new(&Dictionary::builtin(), DEFAULT_BITS_PER_WORD)
+    }
+}
+
+impl WordBloomFilter {
+    /// Builds a bloom filter over the words of the dictionary with the number of bits per word. 10 bits per word gives a false positive rate of about 1%(1単語あたりのビット数を指定して、辞書の単語からブルームフィルタを作ります。1単語あたり10ビットで偽陽性率は約1%です)
+    pub fn new(dictionary: &Dictionary, bits_per_word: usize) -> WordBloomFilter {
+        let bits_per_word = bits_per_word.max(1);
+        let bit_num = (dictionary.len() * bits_per_word).max(64);
+        // 偽陽性率が最も低くなるハッシュ関数の数は、1単語あたりのビット数×ln2
+        let hash_num =
+            ((bits_per_word as f64 * std::f64::consts::LN_2).round() as u32).clamp(1, 16);
+        let mut bloom_filter = WordBloomFilter {
+            bit_list: vec![0; bit_num.div_ceil(64)],
+            hash_num,
+        };
+        for word in dictionary.words() {
+            for bit_index in bloom_filter.bit_index_list(word) {
+                bloom_filter.bit_list[bit_index / 64] |= 1 << (bit_index % 64);
+            }
+        }
+        bloom_filter
+    }
+
+    /// Returns false if the word is certainly not in the dictionary, and true if it probably is, regardless of case(大文字小文字に関係なく、単語が辞書に無いことが確かな場合にfalseを、おそらく辞書にある場合にtrueを返します)
+    pub fn is_probably_word(&self, word: &str) -> bool {
+        // 小文字のみの単語は文字列を作らずに調べる
+        let word = if word.chars().any(char::is_uppercase) {
+            Cow::Owned(word.to_lowercase())
+        } else {
+            Cow::Borrowed(word)
+        };
+        self.bit_index_list(&word)
+            .all(|bit_index| self.bit_list[bit_index / 64] & (1 << (bit_index % 64)) != 0)
+    }
+
+    /// Returns the size of the filter in bits(フィルタのビット数を返します)
+    pub fn bit_num(&self) -> usize {
+        self.bit_list.len() * 64
+    }
+
+    /// 2つのハッシュ値を組み合わせて、単語に対応するビットの位置を返す
+    fn bit_index_list(&self, word: &str) -> impl Iterator<Item = usize> {
+        let bit_num = self.bit_num() as u64;
+        let first_hash = hash_with_seed(word, 0);
+        let second_hash = hash_with_seed(word, 1) | 1;
+        (0..u64::from(self.hash_num))
+            .map(move |i| (first_hash.wrapping_add(i.wrapping_mul(second_hash)) % bit_num) as usize)
+    }
+}
+
+/// シードを指定して単語のハッシュ値を計算する
+fn hash_with_seed(word: &str, seed: u64) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    word.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns false if the word is certainly not in the built-in dictionary, and true if it probably is.
+/// The bloom filter of the built-in dictionary is built on the first call, so it is much faster than `check_a_word_with` on hot paths such as checks per keystroke.
+///
+/// 単語が組み込み辞書に無いことが確かな場合にfalseを、おそらく組み込み辞書にある場合にtrueを返します。
+/// 組み込み辞書のブルームフィルタは最初の呼び出しで作るため、キー入力ごとのチェックなどの頻繁に呼ばれる処理で`check_a_word_with`よりはるかに高速です。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::is_probably_word;
+///
+/// assert!(is_probably_word("apple"));
+/// assert!(is_probably_word("World"));
+/// ```
+pub fn is_probably_word(word: &str) -> bool {
+    static BUILTIN_BLOOM_FILTER: OnceLock<WordBloomFilter> = OnceLock::new();
+    BUILTIN_BLOOM_FILTER
+        .get_or_init(WordBloomFilter::default)
+        .is_probably_word(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_filter_has_no_false_negative() {
+        let dictionary = Dictionary::builtin();
+        let bloom_filter = WordBloomFilter::default();
+        assert!(dictionary
+            .words()
+            .all(|word| bloom_filter.is_probably_word(word)));
+        assert!(is_probably_word("Apple"));
+
+        let dictionary = Dictionary::from_lines("kubernetes\nrust\n".as_bytes()).unwrap();
+        let bloom_filter = WordBloomFilter::new(&dictionary, 10);
+        assert!(bloom_filter.is_probably_word("kubernetes"));
+        assert!(!bloom_filter.is_probably_word("apple"));
+    }
+
+    #[test]
+    fn test_bloom_filter_false_positive_rate() {
+        let dictionary = Dictionary::builtin();
+        let bloom_filter = WordBloomFilter::default();
+        // 辞書の単語の末尾に文字を足して辞書に無い単語を作る
+        let non_word_list: Vec<String> = dictionary
+            .words()
+            .step_by(20)
+            .map(|word| format!("{}q", word))
+            .filter(|word| !dictionary.contains(word))
+            .collect();
+        let false_positive_num = non_word_list
+            .iter()
+            .filter(|word| bloom_filter.is_probably_word(word))
+            .count();
+        assert!(!non_word_list.is_empty());
+        assert!(false_positive_num * 100 < non_word_list.len() * 3);
+    }
+}
@@ -0,0 +1,378 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use crate::{check_a_word_with_dictionary, CheckOptions, Dictionary, TypoCheckResult};
+
+/// Returns the fingerprint of the options. Options that give different results have different fingerprints.
+/// The fingerprint is only stable within a process, so do not store it.
+///
+/// オプションのフィンガープリントを返します。結果が変わるオプションは異なるフィンガープリントになります。
+/// フィンガープリントは同じプロセスの中でのみ同じ値になるため、保存しないでください。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{options_fingerprint, CheckOptions};
+///
+/// let options = CheckOptions::default();
+/// assert_eq!(options_fingerprint(&options), options_fingerprint(&options.clone()));
+/// assert_ne!(
+///     options_fingerprint(&options),
+///     options_fingerprint(&options.clone().pickup_similar_word_num(10))
+/// );
+/// ```
+pub fn options_fingerprint(options: &CheckOptions) -> u64 {
+    // フィールドを追加したときにここで扱うように、全てのフィールドを分解する
+    let CheckOptions {
+        output_levenshtein_cutoff,
+        pickup_similar_word_num,
+        sort_order_of_typo_type,
+        typo_type_limits,
+        ranking_weights,
+        use_common_misspellings,
+        detect_caps_lock,
+        length_aware_cutoff,
+        fold_german_umlauts,
+        min_word_length,
+        stop_words,
+        keyboard_layout,
+        keyboard_adjacency,
+        replace_layout_adjacency,
+        shape_profile,
+        similar_shapes,
+        distance_metric,
+        max_length_delta,
+        prescreen,
+        widen_cutoff,
+        resource_limits,
+        excluded_suggestions,
+        unit_suffixes,
+        split_suggestion,
+        abbreviations,
+    } = options;
+
+    let mut hasher = DefaultHasher::new();
+    output_levenshtein_cutoff.hash(&mut hasher);
+    pickup_similar_word_num.hash(&mut hasher);
+    sort_order_of_typo_type.hash(&mut hasher);
+    typo_type_limits.hash(&mut hasher);
+    // 浮動小数点数はHashを実装しないため、Debug表記をハッシュする
+    format!("{:?}", ranking_weights).hash(&mut hasher);
+    use_common_misspellings.hash(&mut hasher);
+    detect_caps_lock.hash(&mut hasher);
+    length_aware_cutoff.hash(&mut hasher);
+    fold_german_umlauts.hash(&mut hasher);
+    min_word_length.hash(&mut hasher);
+    stop_words.hash(&mut hasher);
+    keyboard_layout.hash(&mut hasher);
+    keyboard_adjacency.hash(&mut hasher);
+    replace_layout_adjacency.hash(&mut hasher);
+    shape_profile.hash(&mut hasher);
+    similar_shapes.hash(&mut hasher);
+    distance_metric.hash(&mut hasher);
+    max_length_delta.hash(&mut hasher);
+    prescreen.hash(&mut hasher);
+    widen_cutoff.hash(&mut hasher);
+    resource_limits.hash(&mut hasher);
+    excluded_suggestions.hash(&mut hasher);
+    unit_suffixes.hash(&mut hasher);
+    split_suggestion.hash(&mut hasher);
+    abbreviations.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// キャッシュのキー
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    options_fingerprint: u64,
+    // 探索した辞書の世代(Dictionary::generation)
+    searched_dictionary_generation: u64,
+    dictionary_generation: u64,
+    check_word: String,
+}
+
+/// Struct that caches the results of typo check for long-lived use in servers.
+/// Results are keyed by the fingerprint of the options, the generation of the searched dictionary and the generation of the cache, so they never leak across configurations or dictionaries.
+/// The generation of a Dictionary changes with `add_word` and `remove_word`, and advancing the generation of the cache removes the old results.
+///
+/// サーバーで長期間使うための、タイポチェックの結果のキャッシュです。
+/// 結果はオプションのフィンガープリントと探索した辞書の世代とキャッシュの世代をキーにするため、異なる設定や辞書の間で混ざることはありません。
+/// Dictionaryの世代は`add_word`や`remove_word`で変わり、キャッシュの世代を進めると古い結果を削除します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{CheckOptions, ResultCache};
+///
+/// let mut result_cache = ResultCache::new(1000);
+/// let options = CheckOptions::default();
+///
+/// let typo_chec_result = result_cache.check("applo", &options);
+/// println!("typo_chec_result: {:?}", typo_chec_result);
+/// assert_eq!(result_cache.len(), 1);
+///
+/// result_cache.advance_dictionary_generation();
+/// assert!(result_cache.is_empty());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ResultCache {
+    capacity: usize,
+    dictionary_generation: u64,
+    result_map: HashMap<CacheKey, TypoCheckResult>,
+    // 追加した順のキー(容量を超えた場合は古いものから削除する)
+    key_queue: VecDeque<CacheKey>,
+}
+
+impl ResultCache {
+    /// Returns an empty cache that stores up to `capacity` results(最大`capacity`件の結果を格納する空のキャッシュを返します)
+    pub fn new(capacity: usize) -> ResultCache {
+        ResultCache {
+            capacity,
+            ..ResultCache::default()
+        }
+    }
+
+    /// Returns the current dictionary generation(現在の辞書の世代を返します)
+    pub fn dictionary_generation(&self) -> u64 {
+        self.dictionary_generation
+    }
+
+    /// Advances the dictionary generation, removes the results of the older generations and returns the new generation(辞書の世代を進めて古い世代の結果を削除し、新しい世代を返します)
+    pub fn advance_dictionary_generation(&mut self) -> u64 {
+        self.dictionary_generation += 1;
+        self.invalidate_before(self.dictionary_generation);
+        self.dictionary_generation
+    }
+
+    /// Removes the results of the generations older than `generation` and returns the number of removed results(`generation`より古い世代の結果を削除し、削除した件数を返します)
+    pub fn invalidate_before(&mut self, generation: u64) -> usize {
+        let before_len = self.result_map.len();
+        self.result_map
+            .retain(|key, _| key.dictionary_generation >= generation);
+        self.key_queue
+            .retain(|key| key.dictionary_generation >= generation);
+        before_len - self.result_map.len()
+    }
+
+    /// Returns the cached result of the word, or checks it with `check_a_word_with` and caches the result(単語のキャッシュした結果を返すか、`check_a_word_with`でチェックして結果をキャッシュします)
+    pub fn check(&mut self, check_word: &str, options: &CheckOptions) -> TypoCheckResult {
+        self.check_with_dictionary(check_word, options, &Dictionary::builtin())
+    }
+
+    /// Same as `check`, but checks with `check_a_word_with_dictionary` and keys the result by the generation of the dictionary.
+    ///
+    /// `check`と同じですが、`check_a_word_with_dictionary`でチェックし、辞書の世代を結果のキーにします。
+    ///
+    /// # Arguments
+    ///
+    /// * `check_word` - Words to check(チェックする単語)
+    /// * `options` - Options of typo check(タイポチェックのオプション)
+    /// * `dictionary` - Dictionary to search(探索する辞書)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{CheckOptions, Dictionary, ResultCache};
+    ///
+    /// let mut result_cache = ResultCache::new(1000);
+    /// let options = CheckOptions::default();
+    /// let mut dictionary = Dictionary::builtin();
+    ///
+    /// assert!(!result_cache.check_with_dictionary("kubernetes", &options, &dictionary).is_match());
+    /// dictionary.add_word("kubernetes");
+    /// assert!(result_cache.check_with_dictionary("kubernetes", &options, &dictionary).is_match());
+    /// ```
+    pub fn check_with_dictionary(
+        &mut self,
+        check_word: &str,
+        options: &CheckOptions,
+        dictionary: &Dictionary,
+    ) -> TypoCheckResult {
+        self.check_by(
+            check_word,
+            options,
+            dictionary,
+            check_a_word_with_dictionary,
+        )
+    }
+
+    /// Same as `check_with_dictionary`, but checks with the given function such as a check with a TypoChecker.
+    ///
+    /// `check_with_dictionary`と同じですが、TypoCheckerでのチェックなど、指定した関数でチェックします。
+    ///
+    /// # Arguments
+    ///
+    /// * `check_word` - Words to check(チェックする単語)
+    /// * `options` - Options of typo check(タイポチェックのオプション)
+    /// * `dictionary` - Dictionary searched by the function(関数が探索する辞書)
+    /// * `check` - Function to check the word when the result is not cached(結果がキャッシュされていない場合に単語をチェックする関数)
+    pub fn check_by<F>(
+        &mut self,
+        check_word: &str,
+        options: &CheckOptions,
+        dictionary: &Dictionary,
+        check: F,
+    ) -> TypoCheckResult
+    where
+        F: FnOnce(&str, &CheckOptions, &Dictionary) -> TypoCheckResult,
+    {
+        let key = CacheKey {
+            options_fingerprint: options_fingerprint(options),
+            searched_dictionary_generation: dictionary.generation(),
+            dictionary_generation: self.dictionary_generation,
+            check_word: check_word.to_string(),
+        };
+        if let Some(typo_check_result) = self.result_map.get(&key) {
+            return typo_check_result.clone();
+        }
+
+        let typo_check_result = check(check_word, options, dictionary);
+        if self.capacity == 0 {
+            return typo_check_result;
+        }
+        if self.result_map.len() == self.capacity {
+            if let Some(oldest_key) = self.key_queue.pop_front() {
+                self.result_map.remove(&oldest_key);
+            }
+        }
+        self.key_queue.push_back(key.clone());
+        self.result_map.insert(key, typo_check_result.clone());
+        typo_check_result
+    }
+
+    /// Returns the number of cached results(キャッシュした結果の件数を返します)
+    pub fn len(&self) -> usize {
+        self.result_map.len()
+    }
+
+    /// Returns true if no result is cached(結果がキャッシュされていない場合にtrueを返します)
+    pub fn is_empty(&self) -> bool {
+        self.result_map.is_empty()
+    }
+
+    /// Removes all cached results(キャッシュした全ての結果を削除します)
+    pub fn clear(&mut self) {
+        self.result_map.clear();
+        self.key_queue.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_result_cache_separates_options() {
+        let mut result_cache = ResultCache::new(10);
+        let options = CheckOptions::default();
+        let one_word_options = options.clone().pickup_similar_word_num(1);
+
+        assert_eq!(
+            result_cache
+                .check("applo", &options)
+                .get_similar_word_list()
+                .len(),
+            5
+        );
+        assert_eq!(
+            result_cache
+                .check("applo", &one_word_options)
+                .get_similar_word_list()
+                .len(),
+            1
+        );
+        assert_eq!(
+            result_cache
+                .check("applo", &options)
+                .get_similar_word_list()
+                .len(),
+            5
+        );
+        assert_eq!(result_cache.len(), 2);
+    }
+
+    #[test]
+    fn test_options_fingerprint_of_separately_built_options() {
+        let build_options = || {
+            CheckOptions::default()
+                .keyboard_adjacency_map(HashMap::from([
+                    ('1', vec!['2', 'q']),
+                    ('2', vec!['1', '3']),
+                    ('3', vec!['2', '4']),
+                ]))
+                .stop_words(["a", "the"])
+        };
+        assert_eq!(
+            options_fingerprint(&build_options()),
+            options_fingerprint(&build_options())
+        );
+        assert_ne!(
+            options_fingerprint(&build_options()),
+            options_fingerprint(&build_options().replace_layout_adjacency(false))
+        );
+
+        let mut result_cache = ResultCache::new(10);
+        for _ in 0..3 {
+            result_cache.check("applo", &build_options());
+        }
+        assert_eq!(result_cache.len(), 1);
+    }
+
+    #[test]
+    fn test_result_cache_generation_and_capacity() {
+        let mut result_cache = ResultCache::new(2);
+        let options = CheckOptions::default();
+        let check_num = Cell::new(0);
+        let dictionary = Dictionary::builtin();
+        let check = |check_word: &str, options: &CheckOptions, dictionary: &Dictionary| {
+            check_num.set(check_num.get() + 1);
+            check_a_word_with_dictionary(check_word, options, dictionary)
+        };
+
+        result_cache.check_by("applo", &options, &dictionary, check);
+        result_cache.check_by("applo", &options, &dictionary, check);
+        assert_eq!(check_num.get(), 1);
+
+        // 世代を進めると再度チェックする
+        assert_eq!(result_cache.advance_dictionary_generation(), 1);
+        result_cache.check_by("applo", &options, &dictionary, check);
+        assert_eq!(check_num.get(), 2);
+
+        // 容量を超えると古い結果から削除する
+        result_cache.check_by("teh", &options, &dictionary, check);
+        result_cache.check_by("wrold", &options, &dictionary, check);
+        assert_eq!(result_cache.len(), 2);
+        result_cache.check_by("applo", &options, &dictionary, check);
+        assert_eq!(check_num.get(), 5);
+
+        assert_eq!(result_cache.invalidate_before(2), 2);
+        assert!(result_cache.is_empty());
+    }
+
+    #[test]
+    fn test_result_cache_separates_dictionaries() {
+        let mut result_cache = ResultCache::new(10);
+        let options = CheckOptions::default();
+        let mut dictionary = Dictionary::builtin();
+
+        assert!(result_cache.check("apple", &options).is_match());
+        // 辞書を変更すると世代が変わり、古い結果を使わない
+        dictionary.remove_word("apple");
+        assert!(!result_cache
+            .check_with_dictionary("apple", &options, &dictionary)
+            .is_match());
+        dictionary.add_word("apple");
+        assert!(result_cache
+            .check_with_dictionary("apple", &options, &dictionary)
+            .is_match());
+
+        // 同じ単語の別の辞書の結果とは混ざらない
+        let custom_dictionary = Dictionary::from_lines("kubernetes\n".as_bytes()).unwrap();
+        assert!(!result_cache
+            .check_with_dictionary("apple", &options, &custom_dictionary)
+            .is_match());
+        assert_eq!(result_cache.len(), 4);
+    }
+}
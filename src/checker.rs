@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::forbidden::find_forbidden_words_with;
+use crate::page::{check_paged_by, suggestion_cursor_by};
+use crate::query::correct_query_with_dictionary;
+use crate::text::collect_text_typos;
+use crate::{
+    check_a_word_with_telemetry, CharacterTable, CheckOptions, Dictionary, ForbiddenWordFinding,
+    Profile, QueryCorrection, SearchTelemetry, SuggestionCursor, SuggestionPage, TextTypo,
+    TypoCheckResult, TypoClassifier,
+};
+
+/// Struct that holds the options and the tables prepared once, to check many words without rebuilding them per call.
+/// The keyboard placement with `keyboard_adjacency` of the options and the similar shapes are built when it is created.
+///
+/// 呼び出しごとに作り直さずに多くの単語をチェックするために、オプションと一度だけ準備した表を保持する構造体です。
+/// オプションの`keyboard_adjacency`を加えたキーボード配置と形状が似ている文字は作成時に作ります。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_a_word_with, CheckOptions, TypoChecker};
+///
+/// let typo_checker = TypoChecker::new(CheckOptions::default().pickup_similar_word_num(3));
+/// for check_word in ["applo", "wrold", "hello"] {
+///     let typo_chec_result = typo_checker.check(check_word);
+///     println!("typo_chec_result: {:?}", typo_chec_result);
+/// }
+/// assert_eq!(
+///     typo_checker.check("applo").get_similar_word_list(),
+///     check_a_word_with("applo", typo_checker.options()).get_similar_word_list()
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TypoChecker {
+    options: CheckOptions,
+    dictionary: Dictionary,
+    character_table: CharacterTable,
+}
+
+impl Default for TypoChecker {
+    fn default() -> TypoChecker {
+        TypoChecker::new(CheckOptions::default())
+    }
+}
+
+impl TypoChecker {
+    /// Creates a TypoChecker with the options(オプションを指定してTypoCheckerを作成します)
+    pub fn new(options: CheckOptions) -> TypoChecker {
+        let character_table = CharacterTable::for_options(&options);
+        TypoChecker {
+            options,
+            dictionary: Dictionary::builtin(),
+            character_table,
+        }
+    }
+
+    /// Creates a TypoChecker with the options of the bundled profile, see `CheckOptions::with_profile`(同梱のプロファイルのオプションでTypoCheckerを作成します。`CheckOptions::with_profile`を参照してください)
+    pub fn with_profile(profile: Profile) -> TypoChecker {
+        TypoChecker::new(CheckOptions::with_profile(profile))
+    }
+
+    /// Sets the word frequencies used to rank the candidates when `ranking_weights` of the options is set, such as by `Profile::Query`.
+    /// Words not in the map have a frequency of 0.0.
+    ///
+    /// オプションの`ranking_weights`が設定されている場合(`Profile::Query`など)に、候補の順位付けに使う単語の頻度を設定します。
+    /// ハッシュマップに無い単語の頻度は0.0とします。
+    ///
+    /// # Arguments
+    ///
+    /// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use typo_checker::{Profile, TypoChecker};
+    ///
+    /// let typo_checker = TypoChecker::with_profile(Profile::Query);
+    /// assert_eq!(typo_checker.check("applo").get_similar_word_list()[0].get_spelling(), "apple");
+    ///
+    /// let frequency_map: HashMap<String, f64> = [("apply".to_string(), 100.0)].into();
+    /// let typo_checker = typo_checker.with_frequency(frequency_map);
+    /// assert_eq!(typo_checker.check("applo").get_similar_word_list()[0].get_spelling(), "apply");
+    /// ```
+    pub fn with_frequency(mut self, frequency_map: HashMap<String, f64>) -> Self {
+        self.character_table.frequency_map = Arc::new(frequency_map);
+        self
+    }
+
+    /// Sets the dictionary to search instead of the built-in dictionary(組み込み辞書の代わりに探索する辞書を設定します)
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> Self {
+        self.dictionary = dictionary;
+        self
+    }
+
+    /// Registers a user-defined classifier that runs before the built-in classification, see `TypoClassifier`(組み込みの判別の前に実行する、ユーザーが定義した分類器を登録します。`TypoClassifier`を参照してください)
+    pub fn with_classifier<C: TypoClassifier + 'static>(mut self, classifier: C) -> Self {
+        self.character_table
+            .classifier_list
+            .push(Arc::new(classifier));
+        self
+    }
+
+    /// Adds the word to the dictionary of the checker and returns true if it was not in the dictionary, see `Dictionary::add_word`(チェックで探索する辞書に単語を追加し、辞書に無かった場合にtrueを返します。`Dictionary::add_word`を参照してください)
+    pub fn add_word(&mut self, word: &str) -> bool {
+        self.dictionary.add_word(word)
+    }
+
+    /// Removes the word from the dictionary of the checker and returns true if it was in the dictionary(チェックで探索する辞書から単語を削除し、辞書にあった場合にtrueを返します)
+    pub fn remove_word(&mut self, word: &str) -> bool {
+        self.dictionary.remove_word(word)
+    }
+
+    /// Returns the options of the checker(チェックに使うオプションを返します)
+    pub fn options(&self) -> &CheckOptions {
+        &self.options
+    }
+
+    /// Returns the dictionary searched by the checker(チェックで探索する辞書を返します)
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    /// Same as `check_a_word_with` with the options of the checker, but reuses the prepared tables(チェックに使うオプションを指定した`check_a_word_with`と同じですが、準備した表を使い回します)
+    pub fn check(&self, check_word: &str) -> TypoCheckResult {
+        check_a_word_with_telemetry(
+            check_word,
+            &self.options,
+            SearchTelemetry::default(),
+            &self.dictionary,
+            &self.character_table,
+        )
+    }
+
+    /// Same as `check_paged` with the options of the checker, but reuses the prepared tables(チェックに使うオプションを指定した`check_paged`と同じですが、準備した表を使い回します)
+    pub fn check_paged(&self, check_word: &str, page: usize, page_size: usize) -> SuggestionPage {
+        check_paged_by(&self.options, page, page_size, |paged_options| {
+            check_a_word_with_telemetry(
+                check_word,
+                paged_options,
+                SearchTelemetry::default(),
+                &self.dictionary,
+                &self.character_table,
+            )
+        })
+    }
+
+    /// Same as `suggestion_cursor` with the options of the checker, but reuses the prepared tables(チェックに使うオプションを指定した`suggestion_cursor`と同じですが、準備した表を使い回します)
+    pub fn suggestion_cursor(&self, check_word: &str, page_size: usize) -> SuggestionCursor {
+        suggestion_cursor_by(&self.options, page_size, |all_options| {
+            check_a_word_with_telemetry(
+                check_word,
+                all_options,
+                SearchTelemetry::default(),
+                &self.dictionary,
+                &self.character_table,
+            )
+        })
+    }
+
+    /// Checks the words in order with the prepared tables, see `check_words`(準備した表を使って単語を順にチェックします。`check_words`を参照してください)
+    pub fn check_words<S: AsRef<str>>(&self, check_word_list: &[S]) -> Vec<TypoCheckResult> {
+        check_word_list
+            .iter()
+            .map(|check_word| self.check(check_word.as_ref()))
+            .collect()
+    }
+
+    /// Same as `check_text` with the options of the checker, but reuses the prepared tables(チェックに使うオプションを指定した`check_text`と同じですが、準備した表を使い回します)
+    pub fn check_text(&self, text: &str) -> Vec<TextTypo> {
+        collect_text_typos(text, &self.options, |word| self.check(word))
+    }
+
+    /// Same as `find_forbidden_words` with the dictionary of the checker, accepting numbers with `unit_suffixes` of the options(チェックで探索する辞書を指定した`find_forbidden_words`と同じですが、オプションの`unit_suffixes`の単位が付いた数を受け入れます)
+    pub fn find_forbidden_words(&self, text: &str) -> Vec<ForbiddenWordFinding> {
+        find_forbidden_words_with(text, &self.dictionary, &self.options.unit_suffixes)
+    }
+
+    /// Same as `correct_query_with_dictionary` with the options and the dictionary of the checker(チェックに使うオプションと辞書を指定した`correct_query_with_dictionary`と同じです)
+    pub fn correct_query(
+        &self,
+        query: &str,
+        frequency_map: &HashMap<String, f64>,
+    ) -> QueryCorrection {
+        correct_query_with_dictionary(query, &self.options, frequency_map, &self.dictionary)
+    }
+}
+
+/// Checks the words in order and returns the results in the same order.
+/// The character tables are prepared once for all words, unlike calling `check_a_word_with` in a loop.
+///
+/// 単語を順にチェックし、同じ順で結果を返します。
+/// `check_a_word_with`をループで呼び出す場合と違い、文字の表は全ての単語に対して一度だけ準備します。
+///
+/// # Arguments
+///
+/// * `check_word_list` - Words to check(チェックする単語のリスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_words, CheckOptions};
+///
+/// let typo_chec_result_list = check_words(&["apple", "applo", "teh"], &CheckOptions::default());
+/// assert_eq!(typo_chec_result_list.len(), 3);
+/// assert!(typo_chec_result_list[0].is_match());
+/// assert!(!typo_chec_result_list[1].is_match());
+/// ```
+pub fn check_words<S: AsRef<str>>(
+    check_word_list: &[S],
+    options: &CheckOptions,
+) -> Vec<TypoCheckResult> {
+    TypoChecker::new(options.clone()).check_words(check_word_list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_a_word_with, WordMetadata};
+
+    #[test]
+    fn test_typo_checker_matches_check_a_word_with() {
+        let options_list = [
+            CheckOptions::default(),
+            CheckOptions::default()
+                .keyboard_adjacency('2', vec!['1', '3'])
+                .use_common_misspellings(false),
+        ];
+        for options in options_list {
+            let typo_checker = TypoChecker::new(options.clone());
+            for check_word in ["applo", "teh", "hello", "a2c", "applw", "x"] {
+                let typo_check_result = typo_checker.check(check_word);
+                let expected_result = check_a_word_with(check_word, &options);
+                assert_eq!(typo_check_result.match_word, expected_result.match_word);
+                assert_eq!(
+                    typo_check_result.similar_word_list,
+                    expected_result.similar_word_list
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_typo_checker_with_cutoff_of_one() {
+        // カットオフ値が1の場合はパニックせずに距離1以内の候補を返す
+        let typo_checker =
+            TypoChecker::new(CheckOptions::default().output_levenshtein_cutoff(Some(1)));
+        let similar_word_list = typo_checker.check("applo").get_similar_word_list();
+
+        assert!(!similar_word_list.is_empty());
+        assert!(similar_word_list
+            .iter()
+            .all(|similar_word| similar_word.get_levenshtein_length() <= 1));
+        assert_eq!(typo_checker.check_words(&["applo", "hello"]).len(), 2);
+    }
+
+    #[test]
+    fn test_typo_checker_with_dictionary() {
+        let dictionary = Dictionary::from_lines("a\nkubernetes\nrust\n".as_bytes()).unwrap();
+        let typo_checker = TypoChecker::default().with_dictionary(dictionary);
+        let typo_check_result_list = typo_checker.check_words(&["A", "rust", "rusty"]);
+        let match_list: Vec<bool> = typo_check_result_list
+            .iter()
+            .map(TypoCheckResult::is_match)
+            .collect();
+        assert_eq!(match_list, vec![true, true, false]);
+        let text_typo_list = typo_checker.check_text("Rust and rusty");
+        assert_eq!(text_typo_list.len(), 2);
+        assert_eq!((text_typo_list[1].start, text_typo_list[1].end), (9, 14));
+
+        assert!(typo_checker.check("A").is_match());
+        assert!(typo_checker.check("Kubernetes").is_match());
+        assert!(!typo_checker.check("apple").is_match());
+        let similar_word_list = typo_checker.check("kubernets").get_similar_word_list();
+        assert_eq!(similar_word_list.len(), 1);
+        assert_eq!(similar_word_list[0].get_spelling(), "kubernetes");
+
+        let mut typo_checker = TypoChecker::default();
+        assert!(!typo_checker.check("kubernetes").is_match());
+        typo_checker.add_word("kubernetes");
+        assert!(typo_checker.check("Kubernetes").is_match());
+        typo_checker.remove_word("apple");
+        assert!(!typo_checker.check("apple").is_match());
+        assert_eq!(
+            typo_checker
+                .correct_query("kuber netes", &HashMap::new())
+                .corrected_query(),
+            "kubernetes"
+        );
+
+        // 候補にしない単語は一致はするが候補にならない
+        let dictionary = Dictionary::builtin().with_metadata(
+            "apple",
+            WordMetadata::default().forbidden(true).tag("fruit"),
+        );
+        let typo_checker = TypoChecker::default().with_dictionary(dictionary);
+        assert!(typo_checker
+            .check("applo")
+            .get_similar_word_list()
+            .iter()
+            .all(|similar_word| similar_word.get_spelling() != "apple"));
+        assert_eq!(
+            typo_checker
+                .check("apple")
+                .get_match_metadata()
+                .unwrap()
+                .tags,
+            vec!["fruit"]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+
+use crate::TypoType;
+
+/// Struct that stores a user-defined category of a typo returned by a TypoClassifier
+///
+/// TypoClassifierが返す、ユーザーが定義したタイポの分類を格納する構造体です
+///
+/// # Arguments
+///
+/// * `name` - Name of the category, stored in `TypoType::Custom` and used in `sort_order_of_typo_type` and reports(分類の名前。`TypoType::Custom`に格納され、`sort_order_of_typo_type`や報告で使われます)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Classification {
+    pub name: String,
+}
+
+impl Classification {
+    /// Creates a Classification with the name of the category(分類の名前を指定してClassificationを作成します)
+    pub fn new(name: &str) -> Classification {
+        Classification {
+            name: name.to_string(),
+        }
+    }
+
+    /// Returns the TypoType that carries the category(分類を格納したTypoTypeを返します)
+    pub fn typo_type(&self) -> TypoType {
+        TypoType::Custom(self.name.clone())
+    }
+}
+
+/// Trait of a user-defined classifier of typos, registered with `TypoChecker::with_classifier`.
+/// Registered classifiers run in the order of registration before the built-in classification of shapes, keyboard placement and extra or missing characters, and the first category returned is used.
+///
+/// `TypoChecker::with_classifier`で登録する、ユーザーが定義したタイポの分類器のトレイトです。
+/// 登録した分類器は、形状・キーボード配置・文字の過不足の組み込みの判別の前に登録した順に実行し、最初に返した分類を使います。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{CheckOptions, Classification, TypoChecker, TypoClassifier, TypoType};
+///
+/// // 母音だけが違う単語を分類する
+/// struct VowelClassifier;
+///
+/// impl TypoClassifier for VowelClassifier {
+///     fn classify(&self, check_word: &str, candidate: &str) -> Option<Classification> {
+///         let is_vowel = |c: char| "aeiou".contains(c);
+///         let only_vowels_differ = check_word.chars().count() == candidate.chars().count()
+///             && check_word
+///                 .chars()
+///                 .zip(candidate.chars())
+///                 .all(|(c, s)| c == s || (is_vowel(c) && is_vowel(s)));
+///         only_vowels_differ.then(|| Classification::new("Vowel"))
+///     }
+/// }
+///
+/// let options = CheckOptions::default().sort_order_of_typo_type(vec![
+///     TypoType::Custom("Vowel".to_string()),
+///     TypoType::UndefinedType,
+/// ]);
+/// let typo_checker = TypoChecker::new(options).with_classifier(VowelClassifier);
+/// let similar_word_list = typo_checker.check("hillo").get_similar_word_list();
+/// assert_eq!(similar_word_list[0].get_typo_type(), &TypoType::Custom("Vowel".to_string()));
+/// ```
+pub trait TypoClassifier: Send + Sync {
+    /// Returns the category of the typo from the check word to the candidate, or None to leave it to the other classifiers(チェックする単語から候補へのタイポの分類を返します。他の分類器に任せる場合はNoneを返します)
+    fn classify(&self, check_word: &str, candidate: &str) -> Option<Classification>;
+}
+
+impl fmt::Debug for dyn TypoClassifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TypoClassifier")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CheckOptions, TypoChecker};
+
+    /// 1文字目が同じ候補のみを分類する
+    struct SameHeadClassifier;
+
+    impl TypoClassifier for SameHeadClassifier {
+        fn classify(&self, check_word: &str, candidate: &str) -> Option<Classification> {
+            (check_word.chars().next() == candidate.chars().next())
+                .then(|| Classification::new("SameHead"))
+        }
+    }
+
+    #[test]
+    fn test_classifier_runs_before_built_in_classification() {
+        let typo_checker = TypoChecker::new(CheckOptions::default().pickup_similar_word_num(100))
+            .with_classifier(SameHeadClassifier);
+        let similar_word_list = typo_checker.check("applo").get_similar_word_list();
+
+        assert!(!similar_word_list.is_empty());
+        for similar_word in &similar_word_list {
+            if similar_word.get_spelling().starts_with('a') {
+                assert_eq!(
+                    similar_word.get_typo_type(),
+                    &TypoType::Custom("SameHead".to_string())
+                );
+            } else {
+                assert_ne!(
+                    similar_word.get_typo_type(),
+                    &TypoType::Custom("SameHead".to_string())
+                );
+            }
+        }
+        // 分類されない候補は組み込みの判別を行う
+        let typo_checker = TypoChecker::default().with_classifier(SameHeadClassifier);
+        let similar_word_list = typo_checker.check("spple").get_similar_word_list();
+        assert_eq!(similar_word_list[0].get_spelling(), "apple");
+        assert_eq!(
+            similar_word_list[0].get_typo_type(),
+            &TypoType::CloseKeyboardPlacement
+        );
+    }
+}
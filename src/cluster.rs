@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use crate::{dictionary, levenshtein};
+
+fn find_root(parent_list: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parent_list[root] != root {
+        root = parent_list[root];
+    }
+    // 経路圧縮を行う
+    let mut current = index;
+    while parent_list[current] != root {
+        let next = parent_list[current];
+        parent_list[current] = root;
+        current = next;
+    }
+    root
+}
+
+/// Returns clusters of words connected by Levenshtein distance of `max_distance` or less.
+/// Only clusters with two or more words are returned, and each cluster is sorted.
+/// Only words whose lengths differ by `max_distance` or less are compared, but it is still meant for offline use.
+///
+/// レーベンシュタイン距離が`max_distance`以下でつながる単語のクラスタを返します。
+/// 2単語以上のクラスタのみを返し、各クラスタはソートされています。
+/// 文字数の差が`max_distance`以下の単語同士のみを比較しますが、オフラインでの利用を想定しています。
+///
+/// # Arguments
+///
+/// * `word_list` - Words to be clustered(クラスタに分ける単語)
+/// * `max_distance` - Maximum Levenshtein distance to connect two words(2単語をつなぐ最大のレーベンシュタイン距離)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::find_near_duplicate_clusters;
+///
+/// let cluster_list = find_near_duplicate_clusters(&["form", "from", "fort", "apple"], 1);
+/// assert_eq!(cluster_list, vec![vec!["form".to_string(), "fort".to_string()]]);
+/// ```
+pub fn find_near_duplicate_clusters<S: AsRef<str>>(
+    word_list: &[S],
+    max_distance: usize,
+) -> Vec<Vec<String>> {
+    let mut parent_list: Vec<usize> = (0..word_list.len()).collect();
+
+    // 文字数ごとに単語のインデックスをまとめる
+    let mut length_bucket: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (index, word) in word_list.iter().enumerate() {
+        length_bucket
+            .entry(word.as_ref().chars().count())
+            .or_default()
+            .push(index);
+    }
+
+    for (length, index_list) in length_bucket.iter() {
+        for (other_length, other_index_list) in length_bucket.range(*length..=length + max_distance)
+        {
+            for (i, &index) in index_list.iter().enumerate() {
+                // 同じ文字数の場合は同じ組を2回比較しない
+                let start = if length == other_length { i + 1 } else { 0 };
+                for &other_index in other_index_list[start..].iter() {
+                    if levenshtein(word_list[index].as_ref(), word_list[other_index].as_ref())
+                        <= max_distance
+                    {
+                        let root = find_root(&mut parent_list, index);
+                        let other_root = find_root(&mut parent_list, other_index);
+                        parent_list[root] = other_root;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut cluster_map: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+    for (index, word) in word_list.iter().enumerate() {
+        let root = find_root(&mut parent_list, index);
+        cluster_map
+            .entry(root)
+            .or_default()
+            .push(word.as_ref().to_string());
+    }
+
+    let mut cluster_list: Vec<Vec<String>> = cluster_map
+        .into_values()
+        .filter(|cluster| cluster.len() >= 2)
+        .map(|mut cluster| {
+            cluster.sort();
+            cluster
+        })
+        .collect();
+    cluster_list.sort();
+    cluster_list
+}
+
+/// Returns clusters of the built-in dictionary words connected by Levenshtein distance of `max_distance` or less.
+/// It compares many words, so it is meant for dictionary maintenance rather than for checking.
+///
+/// 組み込み辞書の単語のうち、レーベンシュタイン距離が`max_distance`以下でつながるクラスタを返します。
+/// 多くの単語を比較するため、チェックではなく辞書のメンテナンス用途を想定しています。
+///
+/// # Arguments
+///
+/// * `max_distance` - Maximum Levenshtein distance to connect two words(2単語をつなぐ最大のレーベンシュタイン距離)
+pub fn find_dictionary_near_duplicate_clusters(max_distance: usize) -> Vec<Vec<String>> {
+    let word_list: Vec<&str> = dictionary::DICTIONARY
+        .iter()
+        .flat_map(|same_length_word_list| same_length_word_list.iter().map_while(|word| *word))
+        .collect();
+    find_near_duplicate_clusters(&word_list, max_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_near_duplicate_clusters_transitive() {
+        // cat-cot-coat は直接つながらない cat と coat も同じクラスタになる
+        let cluster_list =
+            find_near_duplicate_clusters(&["coat", "dog", "cat", "cot", "apple", "apples"], 1);
+
+        assert_eq!(
+            cluster_list,
+            vec![
+                vec!["apple".to_string(), "apples".to_string()],
+                vec!["cat".to_string(), "coat".to_string(), "cot".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_near_duplicate_clusters_none() {
+        let cluster_list = find_near_duplicate_clusters(&["apple", "banana"], 1);
+
+        assert!(cluster_list.is_empty());
+    }
+}
@@ -0,0 +1,504 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::{CheckOptions, Profile, RankingWeights};
+
+/// 環境変数のキーの接頭辞
+const ENVIRONMENT_PREFIX: &str = "TYPO_CHECKER_";
+
+/// Enum of the configuration layers. A later variant overrides an earlier one.
+///
+/// 設定の階層の列挙型です。後の列挙子ほど優先されます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConfigSource {
+    /// Configuration of the user(ユーザーの設定)
+    User,
+    /// Configuration of the project(プロジェクトの設定)
+    Project,
+    /// Environment variables(環境変数)
+    Environment,
+    /// Command line flags(コマンドラインのフラグ)
+    CommandLine,
+}
+
+/// Error when the configuration cannot be resolved
+///
+/// 設定を解決できなかったときのエラーです
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration file cannot be read(設定ファイルを読み込めません)
+    Io(std::io::Error),
+    /// The configuration is not valid TOML or has a wrong value(設定がTOMLとして正しくないか、誤った値があります)
+    Parse(toml::de::Error),
+    /// The effective configuration cannot be written as TOML(適用される設定をTOMLとして書き出せません)
+    Serialize(toml::ser::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "failed to read config file: {}", error),
+            ConfigError::Parse(error) => write!(f, "invalid config: {}", error),
+            ConfigError::Serialize(error) => write!(f, "failed to write config: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Problem of the configuration found by `ConfigResolver::validate`
+///
+/// `ConfigResolver::validate`で見つかった設定の問題です
+#[derive(Debug)]
+pub enum ConfigProblem {
+    /// The configuration is not valid TOML or has a value of a wrong type(設定がTOMLとして正しくないか、誤った型の値があります)
+    Parse(toml::de::Error),
+    /// The key is not an option, such as a misspelled key(キーがオプションではありません。キーの綴り間違いなど)
+    UnknownKey(String),
+    /// The value has the right type but cannot be used(値の型は正しいものの、使えない値です)
+    InvalidValue { key: String, message: String },
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigProblem::Parse(error) => write!(f, "invalid config: {}", error),
+            ConfigProblem::UnknownKey(key) => write!(f, "unknown key: {}", key),
+            ConfigProblem::InvalidValue { key, message } => {
+                write!(f, "invalid value of {}: {}", key, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigProblem {}
+
+/// 省略可能なオプションを全て設定し、全てのキーを含むTOMLの表を返す
+fn known_key_table() -> toml::Table {
+    let options = CheckOptions {
+        sort_order_of_typo_type: Some(Vec::new()),
+        ..CheckOptions::default()
+    }
+    .ranking_weights(RankingWeights::default())
+    .max_length_delta(0)
+    .widen_cutoff(0, 0);
+    let mut table = toml::Table::try_from(options).unwrap_or_default();
+    table.insert("profile".to_string(), toml::Value::String(String::new()));
+    table
+}
+
+/// 既知のキーの表に無いキーを再帰的に集める
+fn find_unknown_keys(
+    table: &toml::Table,
+    known_table: &toml::Table,
+    parent_key: &str,
+    unknown_key_list: &mut Vec<String>,
+) {
+    for (key, value) in table {
+        let full_key = if parent_key.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", parent_key, key)
+        };
+        match (known_table.get(key), value) {
+            (None, _) => unknown_key_list.push(full_key),
+            (Some(toml::Value::Table(known_value)), toml::Value::Table(value)) => {
+                find_unknown_keys(value, known_value, &full_key, unknown_key_list)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 有限でない浮動小数点数のキーを再帰的に集める
+fn find_non_finite_floats(table: &toml::Table, parent_key: &str, key_list: &mut Vec<String>) {
+    for (key, value) in table {
+        let full_key = if parent_key.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", parent_key, key)
+        };
+        match value {
+            toml::Value::Float(value) if !value.is_finite() => key_list.push(full_key),
+            toml::Value::Table(value) => find_non_finite_floats(value, &full_key, key_list),
+            _ => {}
+        }
+    }
+}
+
+/// 解決したオプションのうち、使えない値の問題を返す
+fn find_invalid_values(options: &CheckOptions) -> Vec<ConfigProblem> {
+    let mut problem_list = Vec::new();
+    let mut invalid = |key: &str, message: &str| {
+        problem_list.push(ConfigProblem::InvalidValue {
+            key: key.to_string(),
+            message: message.to_string(),
+        })
+    };
+
+    if options.pickup_similar_word_num == 0 {
+        invalid("pickup_similar_word_num", "must be greater than 0");
+    }
+    if options.resource_limits.max_word_length == 0 {
+        invalid("resource_limits.max_word_length", "must be greater than 0");
+    }
+    if options.resource_limits.max_input_bytes == 0 {
+        invalid("resource_limits.max_input_bytes", "must be greater than 0");
+    }
+
+    // 重みは有限の値にする
+    let mut key_list = Vec::new();
+    if let Ok(table) = toml::Table::try_from(options) {
+        find_non_finite_floats(&table, "", &mut key_list);
+    }
+    for key in key_list {
+        invalid(&key, "must be a finite number");
+    }
+    problem_list
+}
+
+/// Struct that resolves CheckOptions from layered configurations.
+/// The priority is defaults < user config < project config < environment < command line flags, regardless of the order they are added.
+///
+/// 階層化された設定からCheckOptionsを解決する構造体です。
+/// 追加した順序に関係なく、優先度はデフォルト < ユーザーの設定 < プロジェクトの設定 < 環境変数 < コマンドラインのフラグになります。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::ConfigResolver;
+///
+/// let resolver = ConfigResolver::new()
+///     .user_config_str("pickup_similar_word_num = 10\noutput_levenshtein_cutoff = 3\n")
+///     .unwrap()
+///     .project_config_str("pickup_similar_word_num = 3\n")
+///     .unwrap()
+///     .environment([("TYPO_CHECKER_DETECT_CAPS_LOCK".to_string(), "false".to_string())])
+///     .command_line_flag("output-levenshtein-cutoff", "2");
+///
+/// let options = resolver.resolve().unwrap();
+/// assert_eq!(options.pickup_similar_word_num, 3);
+/// assert_eq!(options.output_levenshtein_cutoff, Some(2));
+/// assert!(!options.detect_caps_lock);
+/// println!("{}", resolver.dump().unwrap());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConfigResolver {
+    layer_list: Vec<(ConfigSource, toml::Table)>,
+}
+
+/// 値をTOMLとして読み込み、読み込めない場合は文字列として扱う
+fn parse_value(raw_value: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {}", raw_value))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(raw_value.to_string()))
+}
+
+/// 上位の表の値で下位の表を上書きする(表同士は再帰的に結合する)
+fn merge_table(base_table: &mut toml::Table, override_table: toml::Table) {
+    for (key, value) in override_table {
+        match (base_table.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_value)), toml::Value::Table(override_value)) => {
+                merge_table(base_value, override_value)
+            }
+            (_, value) => {
+                base_table.insert(key, value);
+            }
+        }
+    }
+}
+
+impl ConfigResolver {
+    /// Returns an empty ConfigResolver, which resolves to the default CheckOptions(デフォルトのCheckOptionsに解決される空のConfigResolverを返します)
+    pub fn new() -> ConfigResolver {
+        ConfigResolver::default()
+    }
+
+    /// Adds a layer of the given source from a TOML string(TOML形式の文字列から指定した階層の設定を追加します)
+    pub fn layer_str(mut self, source: ConfigSource, toml_str: &str) -> Result<Self, ConfigError> {
+        let table: toml::Table = toml::from_str(toml_str).map_err(ConfigError::Parse)?;
+        self.layer_list.push((source, table));
+        Ok(self)
+    }
+
+    /// Adds the user config from a TOML string(TOML形式の文字列からユーザーの設定を追加します)
+    pub fn user_config_str(self, toml_str: &str) -> Result<Self, ConfigError> {
+        self.layer_str(ConfigSource::User, toml_str)
+    }
+
+    /// Adds the project config from a TOML string(TOML形式の文字列からプロジェクトの設定を追加します)
+    pub fn project_config_str(self, toml_str: &str) -> Result<Self, ConfigError> {
+        self.layer_str(ConfigSource::Project, toml_str)
+    }
+
+    /// Adds a layer of the given source from a TOML file. A missing file is skipped(TOMLファイルから指定した階層の設定を追加します。ファイルが無い場合はスキップします)
+    pub fn layer_file<P: AsRef<Path>>(
+        self,
+        source: ConfigSource,
+        path: P,
+    ) -> Result<Self, ConfigError> {
+        match std::fs::read_to_string(path) {
+            Ok(toml_str) => self.layer_str(source, &toml_str),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(self),
+            Err(error) => Err(ConfigError::Io(error)),
+        }
+    }
+
+    /// Adds the environment variables starting with `TYPO_CHECKER_`, such as `TYPO_CHECKER_PICKUP_SIMILAR_WORD_NUM=10`(`TYPO_CHECKER_PICKUP_SIMILAR_WORD_NUM=10`のような`TYPO_CHECKER_`で始まる環境変数を追加します)
+    pub fn environment<I: IntoIterator<Item = (String, String)>>(mut self, vars: I) -> Self {
+        let mut table = toml::Table::new();
+        for (key, raw_value) in vars {
+            if let Some(option_key) = key.strip_prefix(ENVIRONMENT_PREFIX) {
+                table.insert(option_key.to_lowercase(), parse_value(&raw_value));
+            }
+        }
+        self.layer_list.push((ConfigSource::Environment, table));
+        self
+    }
+
+    /// Adds the environment variables of the current process(現在のプロセスの環境変数を追加します)
+    pub fn process_environment(self) -> Self {
+        self.environment(std::env::vars())
+    }
+
+    /// Adds a command line flag such as `--pickup-similar-word-num 10`. Nested keys are separated by dots, such as `ranking-weights.prefix-bonus`(`--pickup-similar-word-num 10`のようなコマンドラインのフラグを追加します。入れ子のキーは`ranking-weights.prefix-bonus`のようにドットで区切ります)
+    pub fn command_line_flag(mut self, key: &str, raw_value: &str) -> Self {
+        let key = key.trim_start_matches('-').replace('-', "_");
+        let mut key_list: Vec<&str> = key.split('.').collect();
+        let last_key = key_list.pop().unwrap_or_default();
+
+        let mut table = toml::Table::new();
+        table.insert(last_key.to_string(), parse_value(raw_value));
+        for parent_key in key_list.into_iter().rev() {
+            let mut parent_table = toml::Table::new();
+            parent_table.insert(parent_key.to_string(), toml::Value::Table(table));
+            table = parent_table;
+        }
+        self.layer_list.push((ConfigSource::CommandLine, table));
+        self
+    }
+
+    /// Returns the merged TOML table of all layers(全ての階層を結合したTOMLの表を返します)
+    fn merged_table(&self) -> toml::Table {
+        let mut layer_list = self.layer_list.clone();
+        // 同じ階層の中では追加した順序を保つ
+        layer_list.sort_by_key(|(source, _)| *source);
+
+        let mut merged_table = toml::Table::new();
+        for (_, table) in layer_list {
+            merge_table(&mut merged_table, table);
+        }
+        merged_table
+    }
+
+    /// Returns the effective CheckOptions. When `profile` is set, the other keys override the options of the profile(適用されるCheckOptionsを返します。`profile`が設定されている場合、他のキーはプロファイルのオプションを上書きします)
+    pub fn resolve(&self) -> Result<CheckOptions, ConfigError> {
+        let mut merged_table = self.merged_table();
+
+        // プロファイルのオプションを一番下の階層にする
+        let mut base_table = match merged_table.remove("profile") {
+            Some(profile_value) => {
+                let profile: Profile = profile_value.try_into().map_err(ConfigError::Parse)?;
+                toml::Table::try_from(profile.check_options()).map_err(ConfigError::Serialize)?
+            }
+            None => toml::Table::new(),
+        };
+        merge_table(&mut base_table, merged_table);
+        base_table.try_into().map_err(ConfigError::Parse)
+    }
+
+    /// Validates the configuration without checking any word and returns the effective CheckOptions, or all problems found at once.
+    /// Unknown keys, which are ignored by `resolve`, are also reported.
+    ///
+    /// 単語をチェックせずに設定を検証し、適用されるCheckOptionsか、見つかった全ての問題を一度に返します。
+    /// `resolve`では無視される未知のキーも問題として報告します。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{ConfigProblem, ConfigResolver};
+    ///
+    /// let problem_list = ConfigResolver::new()
+    ///     .project_config_str("pickup_similar_word_nun = 3\npickup_similar_word_num = 0\n")
+    ///     .unwrap()
+    ///     .validate()
+    ///     .unwrap_err();
+    /// for problem in &problem_list {
+    ///     println!("{}", problem);
+    /// }
+    /// assert_eq!(problem_list.len(), 2);
+    /// ```
+    pub fn validate(&self) -> Result<CheckOptions, Vec<ConfigProblem>> {
+        let mut unknown_key_list = Vec::new();
+        find_unknown_keys(
+            &self.merged_table(),
+            &known_key_table(),
+            "",
+            &mut unknown_key_list,
+        );
+        let mut problem_list: Vec<ConfigProblem> = unknown_key_list
+            .into_iter()
+            .map(ConfigProblem::UnknownKey)
+            .collect();
+
+        match self.resolve() {
+            Ok(options) => {
+                problem_list.extend(find_invalid_values(&options));
+                if problem_list.is_empty() {
+                    return Ok(options);
+                }
+            }
+            Err(ConfigError::Parse(error)) => problem_list.push(ConfigProblem::Parse(error)),
+            Err(error) => problem_list.push(ConfigProblem::InvalidValue {
+                key: "profile".to_string(),
+                message: error.to_string(),
+            }),
+        }
+        Err(problem_list)
+    }
+
+    /// Returns the effective configuration as a TOML string, including the default values(デフォルト値を含めた、適用される設定をTOML形式の文字列で返します)
+    pub fn dump(&self) -> Result<String, ConfigError> {
+        toml::to_string(&self.resolve()?).map_err(ConfigError::Serialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_priority_ignores_insertion_order() {
+        let options = ConfigResolver::new()
+            .command_line_flag("--pickup-similar-word-num", "1")
+            .project_config_str("pickup_similar_word_num = 2\nmin_word_length = 4\n")
+            .unwrap()
+            .user_config_str("pickup_similar_word_num = 3\nstop_words = [\"the\"]\n")
+            .unwrap()
+            .resolve()
+            .unwrap();
+
+        assert_eq!(options.pickup_similar_word_num, 1);
+        assert_eq!(options.min_word_length, 4);
+        assert_eq!(options.stop_words, vec!["the".to_string()]);
+        assert!(options.use_common_misspellings);
+    }
+
+    #[test]
+    fn test_nested_flag_and_dump() {
+        let resolver = ConfigResolver::new()
+            .user_config_str("[ranking_weights]\ndistance_penalty = 2.0\n")
+            .unwrap()
+            .environment([
+                (
+                    "TYPO_CHECKER_STOP_WORDS".to_string(),
+                    "[\"a\", \"of\"]".to_string(),
+                ),
+                ("HOME".to_string(), "/root".to_string()),
+            ])
+            .command_line_flag("ranking-weights.prefix-bonus", "0.5");
+
+        let options = resolver.resolve().unwrap();
+        let ranking_weights = options.ranking_weights.unwrap();
+        assert_eq!(ranking_weights.distance_penalty, 2.0);
+        assert_eq!(ranking_weights.prefix_bonus, 0.5);
+        assert_eq!(options.stop_words, vec!["a".to_string(), "of".to_string()]);
+
+        // 書き出した設定を読み込むと同じ設定になる
+        let dump = resolver.dump().unwrap();
+        let dumped_options = ConfigResolver::new()
+            .project_config_str(&dump)
+            .unwrap()
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            dumped_options.stop_words,
+            vec!["a".to_string(), "of".to_string()]
+        );
+        assert_eq!(dumped_options.ranking_weights.unwrap().prefix_bonus, 0.5);
+    }
+
+    #[test]
+    fn test_resolve_keyboard_adjacency() {
+        let resolver = ConfigResolver::new()
+            .project_config_str(
+                "replace_layout_adjacency = true\nkeyboard_adjacency = [[\"1\", [\"2\", \"q\"]], [\"й\", [\"ц\"]]]\n",
+            )
+            .unwrap();
+        let options = resolver.validate().unwrap();
+        assert!(options.replace_layout_adjacency);
+        assert_eq!(
+            options.keyboard_adjacency,
+            vec![('1', vec!['2', 'q']), ('й', vec!['ц'])]
+        );
+
+        let dumped_options = ConfigResolver::new()
+            .project_config_str(&resolver.dump().unwrap())
+            .unwrap()
+            .resolve()
+            .unwrap();
+        assert_eq!(
+            dumped_options.keyboard_adjacency,
+            options.keyboard_adjacency
+        );
+    }
+
+    #[test]
+    fn test_resolve_profile_with_override() {
+        let options = ConfigResolver::new()
+            .project_config_str("profile = \"code\"\n")
+            .unwrap()
+            .command_line_flag("min-word-length", "5")
+            .resolve()
+            .unwrap();
+
+        assert_eq!(options.min_word_length, 5);
+        assert!(options.length_aware_cutoff);
+        assert!(!options.detect_caps_lock);
+    }
+
+    #[test]
+    fn test_resolve_invalid_value() {
+        let result = ConfigResolver::new()
+            .command_line_flag("pickup-similar-word-num", "many")
+            .resolve();
+
+        assert!(matches!(result, Err(ConfigError::Parse(_))));
+    }
+
+    #[test]
+    fn test_validate_reports_all_problems() {
+        let problem_list = ConfigResolver::new()
+            .user_config_str("[ranking_weights]\ndistance_penalty = nan\nprefix_bonu = 1.0\n")
+            .unwrap()
+            .project_config_str("pickup_similar_word_num = 0\n")
+            .unwrap()
+            .command_line_flag("resource-limits.max-input-bytes", "0")
+            .validate()
+            .unwrap_err();
+        let message_list: Vec<String> = problem_list.iter().map(|p| p.to_string()).collect();
+
+        assert_eq!(
+            message_list,
+            vec![
+                "unknown key: ranking_weights.prefix_bonu",
+                "invalid value of pickup_similar_word_num: must be greater than 0",
+                "invalid value of resource_limits.max_input_bytes: must be greater than 0",
+                "invalid value of ranking_weights.distance_penalty: must be a finite number",
+            ]
+        );
+
+        let problem_list = ConfigResolver::new()
+            .command_line_flag("pickup-similar-word-num", "many")
+            .validate()
+            .unwrap_err();
+        assert!(matches!(problem_list[..], [ConfigProblem::Parse(_)]));
+
+        assert!(ConfigResolver::new()
+            .project_config_str("profile = \"code\"\noutput_levenshtein_cutoff = 1\n")
+            .unwrap()
+            .validate()
+            .is_ok());
+    }
+}
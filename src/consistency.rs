@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use crate::sentence::match_case;
+use crate::{tokenize, TextToken};
+
+/// Struct that stores a term of a document that is written differently from the majority form of the same term, such as "e-mail" in a document that mostly uses "email"
+///
+/// 主に"email"を使う文書の"e-mail"のように、同じ用語の多数派の表記と異なる書き方をした文書の用語を格納する構造体です
+///
+/// # Arguments
+///
+/// * `word` - The term as written in the text(テキストに書かれたままの用語)
+/// * `start` - Byte offset of the start of the term in the text(テキストでの用語の開始のバイト位置)
+/// * `end` - Byte offset of the end of the term in the text, exclusive(テキストでの用語の終了のバイト位置。終了位置は含みません)
+/// * `suggestion` - The majority form, in the case of the term(用語の大文字小文字に合わせた多数派の表記)
+/// * `majority_num` - Number of times the majority form appears in the text(テキストで多数派の表記が現れる回数)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyFinding {
+    pub word: String,
+    pub start: usize,
+    pub end: usize,
+    pub suggestion: String,
+    pub majority_num: usize,
+}
+
+impl ConsistencyFinding {
+    /// Name of the rule in reports, to tell the findings apart from typos(報告でタイポと区別するための規則の名前)
+    pub const RULE: &'static str = "inconsistent-term";
+
+    /// Returns a human-readable message such as `"e-mail" is inconsistent with "email" (used 2 times)`(`"e-mail" is inconsistent with "email" (used 2 times)`のような文章を返します)
+    pub fn message(&self) -> String {
+        format!(
+            "\"{}\" is inconsistent with \"{}\" (used {} {})",
+            self.word,
+            self.suggestion,
+            self.majority_num,
+            if self.majority_num == 1 {
+                "time"
+            } else {
+                "times"
+            }
+        )
+    }
+}
+
+/// Returns a hashmap of British spellings and their American spellings, which are treated as variants of the same term.
+///
+/// 同じ用語の異なる表記として扱う、英国の綴りと米国の綴りのハッシュマップを返します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::spelling_variant_list;
+///
+/// let spelling_variant_map = spelling_variant_list();
+/// assert_eq!(spelling_variant_map.get("colour"), Some(&"color"));
+/// ```
+pub fn spelling_variant_list() -> HashMap<&'static str, &'static str> {
+    include_str!("spelling_variants.txt")
+        .lines()
+        .filter_map(|line| line.split_once("->"))
+        .collect()
+}
+
+/// Returns the terms of the document that are written differently from the majority form of the same term, in the order of the text.
+/// Terms are the same if they are equal regardless of case and hyphens, or are variants in `spelling_variant_list`.
+/// The majority form is the most frequent form, and ties are broken by the first appearance.
+///
+/// 同じ用語の多数派の表記と異なる書き方をした文書の用語を、テキストの順に返します。
+/// 大文字小文字とハイフンに関係なく等しい用語や、`spelling_variant_list`で異なる表記とされている用語は同じ用語です。
+/// 多数派の表記は最も多く現れる表記で、同数の場合は先に現れる表記です。
+///
+/// # Arguments
+///
+/// * `text` - Text of the document to check(チェックする文書のテキスト)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{find_inconsistent_terms, ConsistencyFinding};
+///
+/// let finding_list = find_inconsistent_terms("Send an email. The e-mail and the Email use the same color, not colour.");
+///
+/// assert_eq!(finding_list.len(), 2);
+/// assert_eq!(finding_list[0].word, "e-mail");
+/// assert_eq!(finding_list[0].suggestion, "email");
+/// assert_eq!(finding_list[1].suggestion, "color");
+/// println!("{}: {}", ConsistencyFinding::RULE, finding_list[0].message());
+/// ```
+pub fn find_inconsistent_terms(text: &str) -> Vec<ConsistencyFinding> {
+    find_inconsistent_terms_with(text, &spelling_variant_list())
+}
+
+/// Same as `find_inconsistent_terms`, but uses the given hashmap of variants instead of `spelling_variant_list`.
+///
+/// `find_inconsistent_terms`と同じですが、`spelling_variant_list`の代わりに指定した異なる表記のハッシュマップを使います。
+///
+/// # Arguments
+///
+/// * `text` - Text of the document to check(チェックする文書のテキスト)
+/// * `spelling_variant_map` - Hashmap of a variant and the spelling it is grouped with, in lowercase(異なる表記とまとめる綴りのハッシュマップ。小文字で指定します)
+pub fn find_inconsistent_terms_with(
+    text: &str,
+    spelling_variant_map: &HashMap<&str, &str>,
+) -> Vec<ConsistencyFinding> {
+    let term_list = join_hyphenated_tokens(text, tokenize(text));
+
+    // 用語ごとに、表記と回数を現れた順に集める
+    let mut form_count_map: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+    let mut term_key_list = Vec::with_capacity(term_list.len());
+    for term in &term_list {
+        let form = term.text.to_lowercase();
+        let term_key = term_key(&form, spelling_variant_map);
+        let form_count_list = form_count_map.entry(term_key.clone()).or_default();
+        match form_count_list
+            .iter_mut()
+            .find(|(counted_form, _)| *counted_form == form)
+        {
+            Some((_, count)) => *count += 1,
+            None => form_count_list.push((form, 1)),
+        }
+        term_key_list.push(term_key);
+    }
+
+    term_list
+        .iter()
+        .zip(term_key_list)
+        .filter_map(|(term, term_key)| {
+            let form_count_list = &form_count_map[&term_key];
+            if form_count_list.len() < 2 {
+                return None;
+            }
+            let (majority_form, majority_num) =
+                form_count_list
+                    .iter()
+                    .fold(&form_count_list[0], |majority, candidate| {
+                        if candidate.1 > majority.1 {
+                            candidate
+                        } else {
+                            majority
+                        }
+                    });
+            if term.text.to_lowercase() == *majority_form {
+                return None;
+            }
+            Some(ConsistencyFinding {
+                word: term.text.to_string(),
+                start: term.start,
+                end: term.end,
+                suggestion: match_case(term.text, majority_form),
+                majority_num: *majority_num,
+            })
+        })
+        .collect()
+}
+
+/// ハイフン1つで繋がったトークンを1つの用語にまとめる
+fn join_hyphenated_tokens<'a>(text: &'a str, token_list: Vec<TextToken<'a>>) -> Vec<TextToken<'a>> {
+    let mut term_list: Vec<TextToken<'a>> = Vec::with_capacity(token_list.len());
+    for token in token_list {
+        match term_list.last_mut() {
+            Some(last_term) if &text[last_term.end..token.start] == "-" => {
+                last_term.end = token.end;
+                last_term.text = &text[last_term.start..token.end];
+            }
+            _ => term_list.push(token),
+        }
+    }
+    term_list
+}
+
+/// 同じ用語をまとめるためのキー。ハイフンを除き、異なる表記はまとめる先の綴りにする
+fn term_key(form: &str, spelling_variant_map: &HashMap<&str, &str>) -> String {
+    let term_key = form.replace('-', "");
+    match spelling_variant_map.get(term_key.as_str()) {
+        Some(spelling) => spelling.to_string(),
+        None => term_key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_inconsistent_terms() {
+        let text = "Colour and colour, but color. E-mail, email and e-mail. Grey.";
+        let finding_list = find_inconsistent_terms(text);
+        let result_list: Vec<(&str, &str, usize)> = finding_list
+            .iter()
+            .map(|finding| {
+                (
+                    finding.word.as_str(),
+                    finding.suggestion.as_str(),
+                    finding.majority_num,
+                )
+            })
+            .collect();
+        // 大文字小文字の違いは異なる表記としない
+        assert_eq!(
+            result_list,
+            vec![("color", "colour", 2), ("email", "e-mail", 2)]
+        );
+        for finding in &finding_list {
+            assert_eq!(&text[finding.start..finding.end], finding.word);
+        }
+        assert_eq!(
+            finding_list[0].message(),
+            "\"color\" is inconsistent with \"colour\" (used 2 times)"
+        );
+
+        let spelling_variant_map = HashMap::from([("postgresql", "postgres")]);
+        let finding_list =
+            find_inconsistent_terms_with("Postgres or PostgreSQL? postgres", &spelling_variant_map);
+        assert_eq!(finding_list.len(), 1);
+        assert_eq!(finding_list[0].word, "PostgreSQL");
+        assert_eq!(finding_list[0].suggestion, "Postgres");
+    }
+}
@@ -35,18 +35,16 @@ def create_rust_dictionary_file(input_file, output_file):
 
     # Rustファイルの生成
     with open(output_file, 'w', encoding='utf-8') as outfile:
-        outfile.write('pub fn get_dictionary() -> [[Option<&\'static str>; {}]; {}] {{\n'.format(max_length, len(result)-1))
-        outfile.write('    [\n')
+        outfile.write('pub(crate) static DICTIONARY: [[Option<&str>; {}]; {}] = [\n'.format(max_length, len(result)-1))
         for group in result[1:]:
-            outfile.write('        [\n')
+            outfile.write('    [\n')
             for word in group:
                 if word is not None:
-                    outfile.write(f'            Some("{word}"),\n')
+                    outfile.write(f'        Some("{word}"),\n')
                 else:
-                    outfile.write('            None,\n')
-            outfile.write('        ],\n')
-        outfile.write('    ]\n')
-        outfile.write('}\n')
+                    outfile.write('        None,\n')
+            outfile.write('    ],\n')
+        outfile.write('];\n')
 
 # 実行部分
 input_file = '20241025_ejdict-hand-utf8.txt'  # 入力ファイル名
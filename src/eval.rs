@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::{CheckOptions, SuggestionSource, TypoChecker};
+
+/// Struct that stores one pair of a typo and the expected word
+///
+/// タイポと期待する単語の組を格納する構造体です
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalCase {
+    pub typo: String,
+    pub expected_word: String,
+}
+
+/// Struct that stores the result of an evaluation run
+///
+/// 評価の実行結果を格納する構造体です
+///
+/// # Arguments
+///
+/// * `case_num` - Number of evaluated cases(評価したケース数)
+/// * `top1_accuracy` - Ratio of cases whose first suggestion is the expected word(最初の候補が期待する単語だったケースの割合)
+/// * `top5_accuracy` - Ratio of cases whose top 5 suggestions contain the expected word(上位5件の候補に期待する単語が含まれていたケースの割合)
+/// * `latency_p50` - 50th percentile of latency per check(1回のチェックにかかった時間の50パーセンタイル)
+/// * `latency_p90` - 90th percentile of latency per check(1回のチェックにかかった時間の90パーセンタイル)
+/// * `latency_p99` - 99th percentile of latency per check(1回のチェックにかかった時間の99パーセンタイル)
+/// * `top1_hit_num_by_source` - Number of top-1 hits of suggestions for each pass that found them. Exact matches are not included(候補がtop-1の正解だった件数を、候補を見つけた処理ごとに集計したもの。完全一致は含みません)
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalReport {
+    pub case_num: usize,
+    pub top1_accuracy: f64,
+    pub top5_accuracy: f64,
+    pub latency_p50: Duration,
+    pub latency_p90: Duration,
+    pub latency_p99: Duration,
+    pub top1_hit_num_by_source: HashMap<SuggestionSource, usize>,
+}
+
+/// Parses a corpus in the Birkbeck format.
+/// A line starting with `$` is the expected word, and the following lines are its typos.
+///
+/// Birkbeck形式のコーパスを読み込みます。
+/// `$`で始まる行が期待する単語で、それに続く行がそのタイポです。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::eval::parse_birkbeck;
+///
+/// let eval_case_list = parse_birkbeck("$apple\napple\napplo\n");
+/// assert_eq!(eval_case_list.len(), 2);
+/// ```
+pub fn parse_birkbeck(corpus: &str) -> Vec<EvalCase> {
+    let mut eval_case_list = Vec::new();
+    let mut expected_word: Option<&str> = None;
+
+    for line in corpus
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+    {
+        if let Some(word) = line.strip_prefix('$') {
+            expected_word = Some(word);
+        } else if let Some(word) = expected_word {
+            // "typo 3"のように出現回数が付いている場合は1列目のみ使う
+            let typo = line.split_whitespace().next().unwrap();
+            eval_case_list.push(EvalCase {
+                typo: typo.to_string(),
+                expected_word: word.to_string(),
+            });
+        }
+    }
+    eval_case_list
+}
+
+/// Parses a corpus in the Wikipedia common misspellings format (`typo->expected`).
+/// When several expected words are listed, the first one is used.
+///
+/// Wikipediaのよくある綴り間違い形式(`typo->expected`)のコーパスを読み込みます。
+/// 期待する単語が複数ある場合は最初の単語を使います。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::eval::parse_wikipedia;
+///
+/// let eval_case_list = parse_wikipedia("abandonned->abandoned\naberation->aberration, abrasion\n");
+/// assert_eq!(eval_case_list[1].expected_word, "aberration");
+/// ```
+pub fn parse_wikipedia(corpus: &str) -> Vec<EvalCase> {
+    corpus
+        .lines()
+        .filter_map(|line| line.split_once("->"))
+        .filter_map(|(typo, expected_word_list)| {
+            let expected_word = expected_word_list.split(',').next()?.trim();
+            if typo.trim().is_empty() || expected_word.is_empty() {
+                return None;
+            }
+            Some(EvalCase {
+                typo: typo.trim().to_string(),
+                expected_word: expected_word.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn percentile(sorted_latency_list: &[Duration], percent: usize) -> Duration {
+    if sorted_latency_list.is_empty() {
+        return Duration::ZERO;
+    }
+    // 最近傍順位法でパーセンタイルを求める
+    let rank = (percent * sorted_latency_list.len()).div_ceil(100).max(1);
+    sorted_latency_list[rank - 1]
+}
+
+/// Runs the checker for each case and reports top-1/top-5 accuracy and latency percentiles.
+/// An exact match of the expected word counts as a top-1 hit.
+/// To measure top-5 accuracy, set `pickup_similar_word_num` of `options` to 5 or more.
+/// The tables are prepared once with `TypoChecker` before the run, so the latency is only the time of each check.
+///
+/// 各ケースでチェックを実行し、top-1/top-5の正解率と処理時間のパーセンタイルを返します。
+/// 期待する単語に完全一致した場合はtop-1の正解として扱います。
+/// top-5の正解率を測るには`options`の`pickup_similar_word_num`を5以上にしてください。
+/// 表は実行前に`TypoChecker`で一度だけ準備するため、処理時間はそれぞれのチェックの時間のみです。
+///
+/// # Arguments
+///
+/// * `eval_case_list` - Pairs of a typo and the expected word(タイポと期待する単語の組)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::CheckOptions;
+/// use typo_checker::eval::{evaluate, parse_wikipedia};
+///
+/// let eval_case_list = parse_wikipedia("applo->apple\n");
+/// let eval_report = evaluate(&eval_case_list, &CheckOptions::default());
+/// println!("eval_report: {:?}", eval_report);
+/// ```
+pub fn evaluate(eval_case_list: &[EvalCase], options: &CheckOptions) -> EvalReport {
+    let typo_checker = TypoChecker::new(options.clone());
+    let mut top1_hit_num = 0;
+    let mut top5_hit_num = 0;
+    let mut top1_hit_num_by_source: HashMap<SuggestionSource, usize> = HashMap::new();
+    let mut latency_list: Vec<Duration> = Vec::with_capacity(eval_case_list.len());
+
+    for eval_case in eval_case_list {
+        let expected_word = eval_case.expected_word.to_lowercase();
+
+        let start = Instant::now();
+        let typo_check_result = typo_checker.check(&eval_case.typo);
+        latency_list.push(start.elapsed());
+
+        let similar_word_list = typo_check_result
+            .similar_word_list
+            .as_deref()
+            .unwrap_or(&[]);
+        let rank = if typo_check_result.match_word.as_deref() == Some(expected_word.as_str()) {
+            Some(0)
+        } else {
+            let rank = similar_word_list
+                .iter()
+                .position(|word| word.spelling == expected_word);
+            if rank == Some(0) {
+                *top1_hit_num_by_source
+                    .entry(similar_word_list[0].source)
+                    .or_default() += 1;
+            }
+            rank
+        };
+
+        match rank {
+            Some(0) => {
+                top1_hit_num += 1;
+                top5_hit_num += 1;
+            }
+            Some(rank) if rank < 5 => top5_hit_num += 1,
+            _ => {}
+        }
+    }
+
+    latency_list.sort();
+    let case_num = eval_case_list.len();
+    let ratio = |hit_num: usize| {
+        if case_num == 0 {
+            0.0
+        } else {
+            hit_num as f64 / case_num as f64
+        }
+    };
+
+    EvalReport {
+        case_num,
+        top1_accuracy: ratio(top1_hit_num),
+        top5_accuracy: ratio(top5_hit_num),
+        latency_p50: percentile(&latency_list, 50),
+        latency_p90: percentile(&latency_list, 90),
+        latency_p99: percentile(&latency_list, 99),
+        top1_hit_num_by_source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_birkbeck_with_count() {
+        let eval_case_list = parse_birkbeck("$hello\nhelo 2\nhallo\n$world\nwrold\n");
+
+        assert_eq!(eval_case_list.len(), 3);
+        assert_eq!(
+            eval_case_list[0],
+            EvalCase {
+                typo: "helo".to_string(),
+                expected_word: "hello".to_string(),
+            }
+        );
+        assert_eq!(eval_case_list[2].expected_word, "world");
+    }
+
+    #[test]
+    fn test_evaluate_accuracy() {
+        let eval_case_list = parse_wikipedia("apple->apple\napplo->apple\nzzzzzz->apple\n");
+        let eval_report = evaluate(&eval_case_list, &CheckOptions::default());
+
+        assert_eq!(eval_report.case_num, 3);
+        assert!(eval_report.top1_accuracy >= 1.0 / 3.0);
+        assert!(eval_report.top5_accuracy >= eval_report.top1_accuracy);
+        assert!(eval_report.top5_accuracy < 1.0);
+        assert!(eval_report.latency_p50 <= eval_report.latency_p99);
+
+        // カットオフ値が1でもパニックせずに評価する
+        let options = CheckOptions::default().output_levenshtein_cutoff(Some(1));
+        assert_eq!(evaluate(&eval_case_list, &options).case_num, 3);
+    }
+
+    #[test]
+    fn test_evaluate_top1_hit_num_by_source() {
+        let eval_case_list = parse_wikipedia(
+            "apple->apple
+applo->apple
+teh->the
+",
+        );
+        let eval_report = evaluate(&eval_case_list, &CheckOptions::default());
+
+        assert_eq!(
+            eval_report.top1_hit_num_by_source[&SuggestionSource::CommonMisspellings],
+            1
+        );
+        // 完全一致した"apple"は集計しない
+        assert_eq!(
+            eval_report.top1_hit_num_by_source.values().sum::<usize>(),
+            2
+        );
+    }
+}
@@ -0,0 +1,682 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::sentence::match_case;
+use crate::text::collect_text_typos;
+use crate::{
+    check_a_word_with, get_typo_type_name, redact_word, CheckOptions, RankingWeights, SimilarWord,
+    TextTypo, TypoCheckResult, TypoType,
+};
+
+/// Struct that stores the criteria for applying a correction automatically.
+/// A correction is applied only when it meets all criteria.
+///
+/// 修正を自動で適用するための条件を格納する構造体です。
+/// 全ての条件を満たす場合のみ修正が適用されます。
+///
+/// # Arguments
+///
+/// * `require_single_candidate` - Whether the candidate within `max_distance` must be only one(`max_distance`以内の候補が1つのみである必要があるかどうか)
+/// * `max_distance` - Maximum Levenshtein distance of the correction. CommonMisspelling is not limited(修正の最大のレーベンシュタイン距離。CommonMisspellingは制限されません)
+/// * `min_score` - Minimum score of the correction calculated with `ranking_weights`(`ranking_weights`で計算した修正の最小のスコア)
+/// * `ranking_weights` - Weights to calculate the score(スコアの計算に使う重み)
+/// * `allowed_typo_types` - TypoTypes that can be applied. All TypoTypes are allowed if it is None(適用できるTypoType。Noneの場合は全てのTypoTypeを許可します)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FixPolicy {
+    pub require_single_candidate: bool,
+    pub max_distance: usize,
+    pub min_score: Option<f64>,
+    pub ranking_weights: RankingWeights,
+    pub allowed_typo_types: Option<Vec<TypoType>>,
+}
+
+impl Default for FixPolicy {
+    fn default() -> FixPolicy {
+        FixPolicy {
+            require_single_candidate: true,
+            max_distance: 1,
+            min_score: None,
+            ranking_weights: RankingWeights::default(),
+            allowed_typo_types: None,
+        }
+    }
+}
+
+/// Enum of the reasons why a correction is not applied
+///
+/// 修正が適用されなかった理由の列挙型です
+#[derive(Debug, Clone, PartialEq)]
+pub enum FixSkipReason {
+    /// The check word is already correct(チェックする単語は既に正しい)
+    AlreadyCorrect,
+    /// There is no candidate within the maximum distance(最大の距離以内の候補がない)
+    NoCandidate,
+    /// There are several candidates within the maximum distance(最大の距離以内の候補が複数ある)
+    MultipleCandidates(usize),
+    /// The score of the candidate is lower than the minimum score(候補のスコアが最小のスコアより低い)
+    LowScore(f64),
+    /// The TypoType of the candidate is not allowed(候補のTypoTypeが許可されていない)
+    TypoTypeNotAllowed(TypoType),
+}
+
+/// Enum of the result of FixPolicy
+///
+/// FixPolicyの判定結果の列挙型です
+#[derive(Debug, Clone, PartialEq)]
+pub enum FixDecision {
+    /// Apply the correction(修正を適用する)
+    Apply(SimilarWord),
+    /// Skip the correction(修正をスキップする)
+    Skip(FixSkipReason),
+}
+
+/// Struct that stores an applied correction for auditing
+///
+/// 監査のために適用した修正を格納する構造体です
+///
+/// # Arguments
+///
+/// * `index` - Index of the result in the checked list(チェックした結果のリストでの位置)
+/// * `file` - File of the corrected text, if it is known(修正したテキストのファイル。分かる場合のみ)
+/// * `start` - Byte offset of the start of the word in the text, when fixing text(テキストを修正する場合の、テキストでの単語の開始のバイト位置)
+/// * `end` - Byte offset of the end of the word in the text, exclusive, when fixing text(テキストを修正する場合の、テキストでの単語の終了のバイト位置。終了位置は含みません)
+/// * `old` - Word before the correction(修正前の単語)
+/// * `new` - Word after the correction(修正後の単語)
+/// * `rule` - Name of the TypoType of the correction(修正のTypoTypeの名前)
+/// * `score` - Score of the correction calculated with `ranking_weights`(`ranking_weights`で計算した修正のスコア)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixLogEntry {
+    pub index: usize,
+    pub file: Option<PathBuf>,
+    pub start: Option<usize>,
+    pub end: Option<usize>,
+    pub old: String,
+    pub new: String,
+    pub rule: String,
+    pub score: f64,
+}
+
+/// Struct that stores the machine-readable log of the applied corrections
+///
+/// 適用した修正の機械可読なログを格納する構造体です
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixLog {
+    entry_list: Vec<FixLogEntry>,
+}
+
+/// 値があればJSONの値に、無ければnullにする
+fn to_json_or_null<T, F>(value: Option<T>, to_json: F) -> String
+where
+    F: FnOnce(T) -> String,
+{
+    value.map(to_json).unwrap_or_else(|| "null".to_string())
+}
+
+/// JSONの文字列として書き出せるようにエスケープする
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl FixLog {
+    /// Returns the entries of the log(ログの項目を返します)
+    pub fn entries(&self) -> &[FixLogEntry] {
+        &self.entry_list
+    }
+
+    /// Returns the log with the old and new words replaced by `redact_word`, for logging sensitive documents.
+    ///
+    /// 機密の文書のログのために、修正前と修正後の単語を`redact_word`で置き換えたログを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Secret key of the hash(ハッシュの秘密のキー)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{check_a_word_with, CheckOptions, FixPolicy};
+    ///
+    /// let typo_chec_result_list = vec![check_a_word_with("hELLO", &CheckOptions::default())];
+    /// let fix_log = FixPolicy::default().fix_log(&typo_chec_result_list).redacted("secret");
+    /// assert!(!fix_log.to_json().contains("hello"));
+    /// println!("{}", fix_log.to_json());
+    /// ```
+    pub fn redacted(&self, key: &str) -> FixLog {
+        FixLog {
+            entry_list: self
+                .entry_list
+                .iter()
+                .map(|entry| FixLogEntry {
+                    old: redact_word(&entry.old, key),
+                    new: redact_word(&entry.new, key),
+                    ..entry.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the log as a JSON array(ログをJSONの配列で返します)
+    pub fn to_json(&self) -> String {
+        let entry_json_list: Vec<String> = self
+            .entry_list
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{{\"index\":{},\"file\":{},\"start\":{},\"end\":{},\"old\":\"{}\",\"new\":\"{}\",\"rule\":\"{}\",\"score\":{}}}",
+                    entry.index,
+                    to_json_or_null(entry.file.as_ref(), |file| format!(
+                        "\"{}\"",
+                        escape_json(&file.to_string_lossy())
+                    )),
+                    to_json_or_null(entry.start, |start| start.to_string()),
+                    to_json_or_null(entry.end, |end| end.to_string()),
+                    escape_json(&entry.old),
+                    escape_json(&entry.new),
+                    escape_json(&entry.rule),
+                    if entry.score.is_finite() {
+                        entry.score.to_string()
+                    } else {
+                        "null".to_string()
+                    }
+                )
+            })
+            .collect();
+        format!("[{}]", entry_json_list.join(","))
+    }
+
+    /// Writes the log as a JSON array(ログをJSONの配列で書き出します)
+    pub fn write_json<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(self.to_json().as_bytes())
+    }
+}
+
+/// Struct that records the original words of the applied corrections so that they can be rolled back
+///
+/// 適用した修正の元の単語を記録し、修正を取り消せるようにする構造体です
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixTransaction {
+    original_word_list: Vec<String>,
+    fix_log: FixLog,
+}
+
+impl FixTransaction {
+    /// Returns the log of the applied corrections(適用した修正のログを返します)
+    pub fn log(&self) -> &FixLog {
+        &self.fix_log
+    }
+
+    /// Returns the number of applied corrections(適用した修正の数を返します)
+    pub fn len(&self) -> usize {
+        self.original_word_list.len()
+    }
+
+    /// Returns true if no correction is applied(修正を適用していない場合にtrueを返します)
+    pub fn is_empty(&self) -> bool {
+        self.original_word_list.is_empty()
+    }
+
+    /// Rolls back the corrections and returns the number of restored words.
+    /// A word changed again after the correction is left as it is.
+    ///
+    /// 修正を取り消し、元に戻した単語の数を返します。
+    /// 修正した後にさらに変更された単語はそのままにします。
+    ///
+    /// # Arguments
+    ///
+    /// * `word_list` - Words that the corrections were applied to(修正を適用した単語)
+    pub fn revert(&self, word_list: &mut [String]) -> usize {
+        let mut revert_num = 0;
+        for (entry, original_word) in self.fix_log.entry_list.iter().zip(&self.original_word_list) {
+            if let Some(word) = word_list.get_mut(entry.index) {
+                if *word == entry.new {
+                    *word = original_word.clone();
+                    revert_num += 1;
+                }
+            }
+        }
+        revert_num
+    }
+}
+
+impl FixPolicy {
+    /// Sets the maximum Levenshtein distance of the correction(修正の最大のレーベンシュタイン距離を設定します)
+    pub fn max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Sets the minimum score of the correction(修正の最小のスコアを設定します)
+    pub fn min_score(mut self, min_score: f64) -> Self {
+        self.min_score = Some(min_score);
+        self
+    }
+
+    /// Sets the TypoTypes that can be applied(適用できるTypoTypeを設定します)
+    pub fn allowed_typo_types(mut self, allowed_typo_types: Vec<TypoType>) -> Self {
+        self.allowed_typo_types = Some(allowed_typo_types);
+        self
+    }
+
+    /// Decides whether the top correction of the result can be applied.
+    ///
+    /// 結果の最上位の修正を適用できるかどうかを判定します。
+    ///
+    /// # Arguments
+    ///
+    /// * `typo_check_result` - Result of typo check(タイポチェックの結果)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{check_a_word_with, CheckOptions, FixDecision, FixPolicy};
+    ///
+    /// let fix_policy = FixPolicy::default();
+    /// let typo_chec_result = check_a_word_with("teh", &CheckOptions::default());
+    /// match fix_policy.decide(&typo_chec_result) {
+    ///     FixDecision::Apply(similar_word) => println!("fix: {:?}", similar_word),
+    ///     FixDecision::Skip(reason) => println!("skip: {:?}", reason),
+    /// }
+    /// ```
+    pub fn decide(&self, typo_check_result: &TypoCheckResult) -> FixDecision {
+        let similar_word_list = typo_check_result
+            .similar_word_list
+            .as_deref()
+            .unwrap_or_default();
+
+        // 一致する単語があってもCaps Lockの場合は修正する
+        if typo_check_result.match_word.is_some()
+            && !similar_word_list
+                .iter()
+                .any(|word| word.typo_type == TypoType::CapsLock)
+        {
+            return FixDecision::Skip(FixSkipReason::AlreadyCorrect);
+        }
+
+        // よくある綴り間違いは距離に関係なく候補とする
+        let candidate_list: Vec<&SimilarWord> = similar_word_list
+            .iter()
+            .filter(|word| {
+                word.levenshtein_length <= self.max_distance
+                    || word.typo_type == TypoType::CommonMisspelling
+            })
+            .collect();
+        let candidate = match candidate_list.first() {
+            None => return FixDecision::Skip(FixSkipReason::NoCandidate),
+            Some(_) if self.require_single_candidate && candidate_list.len() > 1 => {
+                return FixDecision::Skip(FixSkipReason::MultipleCandidates(candidate_list.len()))
+            }
+            Some(candidate) => *candidate,
+        };
+
+        if let Some(allowed_typo_types) = &self.allowed_typo_types {
+            let typo_type_name = get_typo_type_name(&candidate.typo_type);
+            if !allowed_typo_types
+                .iter()
+                .any(|typo_type| get_typo_type_name(typo_type) == typo_type_name)
+            {
+                return FixDecision::Skip(FixSkipReason::TypoTypeNotAllowed(
+                    candidate.typo_type.clone(),
+                ));
+            }
+        }
+
+        if let Some(min_score) = self.min_score {
+            let score = self
+                .ranking_weights
+                .score(&typo_check_result.check_word, candidate, 0.0);
+            if score < min_score {
+                return FixDecision::Skip(FixSkipReason::LowScore(score));
+            }
+        }
+
+        FixDecision::Apply(candidate.clone())
+    }
+
+    /// Splits the results into the corrections to apply and the skipped results with their reasons.
+    ///
+    /// 結果を適用する修正と、スキップした結果とその理由に分けます。
+    ///
+    /// # Arguments
+    ///
+    /// * `typo_check_result_list` - Results of typo check(タイポチェックの結果)
+    #[allow(clippy::type_complexity)]
+    pub fn partition<'a>(
+        &self,
+        typo_check_result_list: &'a [TypoCheckResult],
+    ) -> (
+        Vec<(&'a TypoCheckResult, SimilarWord)>,
+        Vec<(&'a TypoCheckResult, FixSkipReason)>,
+    ) {
+        let mut fix_list = Vec::new();
+        let mut skip_list = Vec::new();
+        for typo_check_result in typo_check_result_list {
+            match self.decide(typo_check_result) {
+                FixDecision::Apply(similar_word) => {
+                    fix_list.push((typo_check_result, similar_word))
+                }
+                FixDecision::Skip(reason) => skip_list.push((typo_check_result, reason)),
+            }
+        }
+        (fix_list, skip_list)
+    }
+
+    /// Returns the log of the corrections that can be applied to the results.
+    ///
+    /// 結果に適用できる修正のログを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `typo_check_result_list` - Results of typo check(タイポチェックの結果)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{check_a_word_with, CheckOptions, FixPolicy};
+    ///
+    /// let typo_check_result_list = vec![
+    ///     check_a_word_with("hello", &CheckOptions::default()),
+    ///     check_a_word_with("hELLO", &CheckOptions::default()),
+    /// ];
+    /// let fix_log = FixPolicy::default().fix_log(&typo_check_result_list);
+    /// assert_eq!(fix_log.entries()[0].index, 1);
+    /// println!("{}", fix_log.to_json());
+    /// ```
+    pub fn fix_log(&self, typo_check_result_list: &[TypoCheckResult]) -> FixLog {
+        let entry_list = typo_check_result_list
+            .iter()
+            .enumerate()
+            .filter_map(
+                |(index, typo_check_result)| match self.decide(typo_check_result) {
+                    FixDecision::Apply(similar_word) => Some(FixLogEntry {
+                        index,
+                        file: None,
+                        start: None,
+                        end: None,
+                        old: typo_check_result.check_word.clone(),
+                        rule: get_typo_type_name(&similar_word.typo_type),
+                        score: self.ranking_weights.score(
+                            &typo_check_result.check_word,
+                            &similar_word,
+                            0.0,
+                        ),
+                        new: similar_word.spelling,
+                    }),
+                    FixDecision::Skip(_) => None,
+                },
+            )
+            .collect();
+        FixLog { entry_list }
+    }
+
+    /// Returns the log of the corrections that can be applied to the typos of a text, with the byte offsets of the words and the file of the text.
+    /// The index of an entry is the index of the typo in the list.
+    ///
+    /// テキストのタイポに適用できる修正のログを、単語のバイト位置とテキストのファイルとともに返します。
+    /// 項目の位置はリストでのタイポの位置です。
+    ///
+    /// # Arguments
+    ///
+    /// * `text_typo_list` - Typos of the text, see `check_text`(テキストのタイポ。`check_text`を参照してください)
+    /// * `file` - File of the text(テキストのファイル)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use typo_checker::{check_text, FixPolicy};
+    ///
+    /// let fix_log = FixPolicy::default().fix_text_log(&check_text("I sat on teh chair."), Some(Path::new("note.txt")));
+    /// assert_eq!(fix_log.entries()[0].start, Some(9));
+    /// assert_eq!(fix_log.entries()[0].end, Some(12));
+    /// println!("{}", fix_log.to_json());
+    /// ```
+    pub fn fix_text_log(&self, text_typo_list: &[TextTypo], file: Option<&Path>) -> FixLog {
+        let typo_check_result_list: Vec<TypoCheckResult> = text_typo_list
+            .iter()
+            .map(|text_typo| text_typo.typo_check_result.clone())
+            .collect();
+        let mut fix_log = self.fix_log(&typo_check_result_list);
+        for entry in &mut fix_log.entry_list {
+            let text_typo = &text_typo_list[entry.index];
+            entry.file = file.map(Path::to_path_buf);
+            entry.start = Some(text_typo.start);
+            entry.end = Some(text_typo.end);
+            entry.old = text_typo.text.clone();
+            // Caps Lockの修正以外は元の単語の大文字小文字に合わせる
+            if entry.rule != get_typo_type_name(&TypoType::CapsLock) {
+                entry.new = match_case(&text_typo.text, &entry.new);
+            }
+        }
+        fix_log
+    }
+
+    /// Checks the text and returns the text with the corrections applied and the log of the corrections.
+    ///
+    /// テキストをチェックし、修正を適用したテキストと修正のログを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Text to check and correct(チェックして修正するテキスト)
+    /// * `options` - Options of typo check(タイポチェックのオプション)
+    /// * `file` - File of the text(テキストのファイル)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{CheckOptions, FixPolicy};
+    ///
+    /// let (fixed_text, fix_log) = FixPolicy::default().apply_to_text("Teh cat sat.", &CheckOptions::default(), None);
+    /// assert_eq!(fixed_text, "The cat sat.");
+    /// assert_eq!(fix_log.entries()[0].old, "Teh");
+    /// ```
+    pub fn apply_to_text(
+        &self,
+        text: &str,
+        options: &CheckOptions,
+        file: Option<&Path>,
+    ) -> (String, FixLog) {
+        let text_typo_list =
+            collect_text_typos(text, options, |word| check_a_word_with(word, options));
+        let fix_log = self.fix_text_log(&text_typo_list, file);
+
+        let mut fixed_text = text.to_string();
+        // 後ろから置き換えることで、前の単語のバイト位置がずれないようにする
+        for entry in fix_log.entry_list.iter().rev() {
+            if let (Some(start), Some(end)) = (entry.start, entry.end) {
+                fixed_text.replace_range(start..end, &entry.new);
+            }
+        }
+        (fixed_text, fix_log)
+    }
+
+    /// Checks the words, applies the corrections in place and returns the transaction to roll them back.
+    ///
+    /// 単語をチェックしてその場で修正を適用し、修正を取り消すためのトランザクションを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `word_list` - Words to check and correct(チェックして修正する単語)
+    /// * `options` - Options of typo check(タイポチェックのオプション)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{CheckOptions, FixPolicy};
+    ///
+    /// let mut word_list = vec!["hello".to_string(), "hELLO".to_string()];
+    /// let transaction = FixPolicy::default().apply(&mut word_list, &CheckOptions::default());
+    /// assert_eq!(word_list[1], "Hello");
+    ///
+    /// transaction.revert(&mut word_list);
+    /// assert_eq!(word_list[1], "hELLO");
+    /// ```
+    pub fn apply(&self, word_list: &mut [String], options: &CheckOptions) -> FixTransaction {
+        let typo_check_result_list: Vec<TypoCheckResult> = word_list
+            .iter()
+            .map(|word| check_a_word_with(word, options))
+            .collect();
+        let fix_log = self.fix_log(&typo_check_result_list);
+
+        let mut original_word_list = Vec::with_capacity(fix_log.entry_list.len());
+        for entry in &fix_log.entry_list {
+            original_word_list.push(std::mem::replace(
+                &mut word_list[entry.index],
+                entry.new.clone(),
+            ));
+        }
+        FixTransaction {
+            original_word_list,
+            fix_log,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_a_word_with, CheckOptions};
+
+    #[test]
+    fn test_decide_single_candidate() {
+        let fix_policy = FixPolicy::default();
+
+        let typo_chec_result = check_a_word_with("applo", &CheckOptions::default());
+        assert!(matches!(
+            fix_policy.decide(&typo_chec_result),
+            FixDecision::Skip(FixSkipReason::MultipleCandidates(_))
+        ));
+
+        // よくある綴り間違いは候補が1つのため修正する
+        let typo_chec_result = check_a_word_with("teh", &CheckOptions::default());
+        match fix_policy.decide(&typo_chec_result) {
+            FixDecision::Apply(similar_word) => assert_eq!(similar_word.spelling, "the"),
+            decision => panic!("unexpected decision: {:?}", decision),
+        }
+
+        let typo_chec_result = check_a_word_with("hELLO", &CheckOptions::default());
+        match fix_policy.decide(&typo_chec_result) {
+            FixDecision::Apply(similar_word) => assert_eq!(similar_word.spelling, "Hello"),
+            decision => panic!("unexpected decision: {:?}", decision),
+        }
+
+        let typo_chec_result = check_a_word_with("hello", &CheckOptions::default());
+        assert_eq!(
+            fix_policy.decide(&typo_chec_result),
+            FixDecision::Skip(FixSkipReason::AlreadyCorrect)
+        );
+    }
+
+    #[test]
+    fn test_partition_with_allowed_typo_types() {
+        let fix_policy = FixPolicy {
+            require_single_candidate: false,
+            ..FixPolicy::default()
+        }
+        .allowed_typo_types(vec![TypoType::CommonMisspelling]);
+        let typo_check_result_list = vec![
+            check_a_word_with("teh", &CheckOptions::default()),
+            check_a_word_with("aplpe", &CheckOptions::default()),
+        ];
+
+        let (fix_list, skip_list) = fix_policy.partition(&typo_check_result_list);
+
+        assert_eq!(fix_list.len(), 1);
+        assert_eq!(fix_list[0].1.spelling, "the");
+        assert_eq!(skip_list.len(), 1);
+    }
+
+    #[test]
+    fn test_fix_log_to_json() {
+        let fix_log = FixLog {
+            entry_list: vec![FixLogEntry {
+                index: 2,
+                file: Some(PathBuf::from("docs/a.txt")),
+                start: Some(4),
+                end: Some(7),
+                old: "a\"b".to_string(),
+                new: "ab".to_string(),
+                rule: "ExtraCharacters".to_string(),
+                score: 1.5,
+            }],
+        };
+
+        assert_eq!(
+            fix_log.to_json(),
+            r#"[{"index":2,"file":"docs/a.txt","start":4,"end":7,"old":"a\"b","new":"ab","rule":"ExtraCharacters","score":1.5}]"#
+        );
+        let mut buffer = Vec::new();
+        fix_log.write_json(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), fix_log.to_json());
+        assert_eq!(FixLog::default().to_json(), "[]");
+    }
+
+    #[test]
+    fn test_apply_to_text_logs_spans() {
+        let text = "Teh cat and teh dog";
+        let (fixed_text, fix_log) = FixPolicy::default().apply_to_text(
+            text,
+            &CheckOptions::default(),
+            Some(Path::new("pets.txt")),
+        );
+
+        assert_eq!(fixed_text, "The cat and the dog");
+        let span_list: Vec<(Option<usize>, Option<usize>)> = fix_log
+            .entries()
+            .iter()
+            .map(|entry| (entry.start, entry.end))
+            .collect();
+        assert_eq!(span_list, vec![(Some(0), Some(3)), (Some(12), Some(15))]);
+        for entry in fix_log.entries() {
+            assert_eq!(entry.file.as_deref(), Some(Path::new("pets.txt")));
+            assert_eq!(&text[entry.start.unwrap()..entry.end.unwrap()], entry.old);
+        }
+        assert!(fix_log.to_json().starts_with(
+            r#"[{"index":0,"file":"pets.txt","start":0,"end":3,"old":"Teh","new":"The""#
+        ));
+
+        // 単語のリストの修正ではバイト位置は無い
+        let fix_log =
+            FixPolicy::default().fix_log(&[check_a_word_with("teh", &CheckOptions::default())]);
+        assert!(fix_log
+            .to_json()
+            .contains(r#""file":null,"start":null,"end":null"#));
+    }
+
+    #[test]
+    fn test_fix_transaction_revert() {
+        let fix_policy = FixPolicy {
+            require_single_candidate: false,
+            ..FixPolicy::default()
+        }
+        .allowed_typo_types(vec![TypoType::CommonMisspelling, TypoType::CapsLock]);
+        let mut word_list: Vec<String> = ["teh", "apple", "hELLO"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+
+        let transaction = fix_policy.apply(&mut word_list, &CheckOptions::default());
+        assert_eq!(transaction.len(), 2);
+        assert_eq!(word_list, vec!["the", "apple", "Hello"]);
+
+        // 修正した後に変更された単語は元に戻さない
+        word_list[2] = "Hi".to_string();
+        assert_eq!(transaction.revert(&mut word_list), 1);
+        assert_eq!(word_list, vec!["teh", "apple", "Hi"]);
+    }
+}
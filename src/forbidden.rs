@@ -0,0 +1,133 @@
+use crate::{tokenize_with, CheckOptions, Dictionary};
+
+/// Struct that stores a word of a text that is spelled correctly but forbidden by the metadata of the dictionary, such as an internal codename or a non-inclusive term
+///
+/// 社内のコードネームや包括的でない用語などの、綴りは正しいが辞書のメタデータで禁止されているテキストの単語を格納する構造体です
+///
+/// # Arguments
+///
+/// * `word` - The forbidden word as written in the text(テキストに書かれたままの禁止されている単語)
+/// * `start` - Byte offset of the start of the word in the text(テキストでの単語の開始のバイト位置)
+/// * `end` - Byte offset of the end of the word in the text, exclusive(テキストでの単語の終了のバイト位置。終了位置は含みません)
+/// * `replacements` - Words to use instead, from the metadata(メタデータにある、代わりに使う単語)
+/// * `tags` - Tags of the word, from the metadata(メタデータにある単語のタグ)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForbiddenWordFinding {
+    pub word: String,
+    pub start: usize,
+    pub end: usize,
+    pub replacements: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl ForbiddenWordFinding {
+    /// Name of the rule in reports, to tell the findings apart from typos(報告でタイポと区別するための規則の名前)
+    pub const RULE: &'static str = "forbidden-word";
+
+    /// Returns a human-readable message such as `"foo" is forbidden, use "bar"`(`"foo" is forbidden, use "bar"`のような文章を返します)
+    pub fn message(&self) -> String {
+        let quoted_word_list: Vec<String> = self
+            .replacements
+            .iter()
+            .map(|replacement| format!("\"{}\"", replacement))
+            .collect();
+        match quoted_word_list.split_last() {
+            None => format!("\"{}\" is forbidden", self.word),
+            Some((last, [])) => format!("\"{}\" is forbidden, use {}", self.word, last),
+            Some((last, rest)) => format!(
+                "\"{}\" is forbidden, use {} or {}",
+                self.word,
+                rest.join(", "),
+                last
+            ),
+        }
+    }
+}
+
+/// Returns the words of the text that are forbidden by the metadata of the dictionary, in the order of the text.
+/// The text is split as `tokenize` does, and the words are compared regardless of case.
+///
+/// 辞書のメタデータで禁止されているテキストの単語を、テキストの順に返します。
+/// テキストは`tokenize`と同じように分割し、単語は大文字小文字に関係なく比較します。
+///
+/// # Arguments
+///
+/// * `text` - Text to check(チェックするテキスト)
+/// * `dictionary` - Dictionary with the metadata of the forbidden words(禁止されている単語のメタデータを持つ辞書)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{find_forbidden_words, Dictionary, ForbiddenWordFinding};
+///
+/// let dictionary = Dictionary::from_lines("whitelist forbidden replacement=allowlist\n".as_bytes()).unwrap();
+/// let finding_list = find_forbidden_words("Add it to the Whitelist.", &dictionary);
+///
+/// assert_eq!(finding_list.len(), 1);
+/// assert_eq!((finding_list[0].start, finding_list[0].end), (14, 23));
+/// println!("{}: {}", ForbiddenWordFinding::RULE, finding_list[0].message());
+/// ```
+pub fn find_forbidden_words(text: &str, dictionary: &Dictionary) -> Vec<ForbiddenWordFinding> {
+    find_forbidden_words_with(text, dictionary, &CheckOptions::default().unit_suffixes)
+}
+
+/// `find_forbidden_words`と同じだが、指定した単位で数を受け入れる
+pub(crate) fn find_forbidden_words_with(
+    text: &str,
+    dictionary: &Dictionary,
+    unit_suffixes: &[String],
+) -> Vec<ForbiddenWordFinding> {
+    tokenize_with(text, unit_suffixes)
+        .into_iter()
+        .filter_map(|token| {
+            let metadata = dictionary
+                .metadata(token.text)
+                .filter(|metadata| metadata.forbidden)?;
+            Some(ForbiddenWordFinding {
+                word: token.text.to_string(),
+                start: token.start,
+                end: token.end,
+                replacements: metadata.replacements.clone(),
+                tags: metadata.tags.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WordMetadata;
+
+    #[test]
+    fn test_find_forbidden_words() {
+        let dictionary = Dictionary::builtin()
+            .with_metadata(
+                "blacklist",
+                WordMetadata::default()
+                    .forbidden(true)
+                    .replacement("blocklist")
+                    .replacement("denylist")
+                    .tag("inclusive"),
+            )
+            .with_metadata("colour", WordMetadata::default().region("GB"))
+            .with_metadata("projectx", WordMetadata::default().forbidden(true));
+
+        let finding_list = find_forbidden_words(
+            "The colour of the blacklist, aka ProjectX, and 5GB",
+            &dictionary,
+        );
+        let message_list: Vec<String> = finding_list
+            .iter()
+            .map(ForbiddenWordFinding::message)
+            .collect();
+        assert_eq!(
+            message_list,
+            vec![
+                "\"blacklist\" is forbidden, use \"blocklist\" or \"denylist\"",
+                "\"ProjectX\" is forbidden",
+            ]
+        );
+        assert_eq!(finding_list[0].tags, vec!["inclusive"]);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    get_top_similar_words_with_table, CharacterTable, CheckOptions, SimilarWord, SuggestionSource,
+    TypoCheckResult, TypoType,
+};
+
+/// アクセント記号のみが違う文字の置換コスト
+const ACCENT_COST: f64 = 0.25;
+
+/// アクセント記号を取り除いた文字を返す
+fn base_char(c: char) -> char {
+    match c {
+        'à' | 'â' | 'ä' => 'a',
+        'ç' => 'c',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'î' | 'ï' => 'i',
+        'ô' | 'ö' => 'o',
+        'ù' | 'û' | 'ü' => 'u',
+        'ÿ' => 'y',
+        _ => c,
+    }
+}
+
+/// Returns the lowercase word with the French accents removed.
+///
+/// フランス語のアクセント記号を取り除いた小文字の単語を返します。
+///
+/// # Arguments
+///
+/// * `word` - French word(フランス語の単語)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strip_accents;
+///
+/// assert_eq!(strip_accents("Élève"), "eleve");
+/// ```
+pub fn strip_accents(word: &str) -> String {
+    word.to_lowercase().chars().map(base_char).collect()
+}
+
+/// Calculate the Levenshtein distance where a substitution that differs only in accents costs 0.25 instead of 1.
+///
+/// アクセント記号のみが違う文字の置換を1ではなく0.25として、レーベンシュタイン距離を計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::accent_levenshtein;
+///
+/// assert_eq!(0.5, accent_levenshtein("eleve", "élève"));
+/// assert_eq!(1.25, accent_levenshtein("cafés", "cafe"));
+/// ```
+pub fn accent_levenshtein(a: &str, b: &str) -> f64 {
+    let a_char_list: Vec<char> = a.to_lowercase().chars().collect();
+    let b_char_list: Vec<char> = b.to_lowercase().chars().collect();
+
+    let mut cache: Vec<f64> = (0..=b_char_list.len()).map(|i| i as f64).collect();
+    for (i, a_char) in a_char_list.iter().enumerate() {
+        let mut previous_diagonal = cache[0];
+        cache[0] = (i + 1) as f64;
+        for (j, b_char) in b_char_list.iter().enumerate() {
+            let substitution_cost = if a_char == b_char {
+                0.0
+            } else if base_char(*a_char) == base_char(*b_char) {
+                ACCENT_COST
+            } else {
+                1.0
+            };
+            let distance = (previous_diagonal + substitution_cost)
+                .min(cache[j] + 1.0)
+                .min(cache[j + 1] + 1.0);
+            previous_diagonal = cache[j + 1];
+            cache[j + 1] = distance;
+        }
+    }
+    cache[b_char_list.len()]
+}
+
+/// Returns TypoCheckResult type words that match or are similar to the French word, using the given French word list.
+/// Words that differ only in accents are classified as MissingAccent, and accent differences count as fractional-cost edits.
+///
+/// 指定したフランス語の単語リストを使って、チェックするフランス語の単語に合致、もしくは類似する単語をTypoCheckResult型で返却します。
+/// アクセント記号のみが違う単語はMissingAccentとして判別され、アクセント記号の違いは1未満のコストとして扱われます。
+///
+/// # Arguments
+///
+/// * `check_word` - French word to check(チェックするフランス語の単語)
+/// * `french_word_list` - List of correct French words(正しいフランス語の単語のリスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_french_word, CheckOptions};
+///
+/// let french_word_list = ["élève", "école", "fenêtre"];
+/// let typo_chec_result = check_french_word("eleve", &french_word_list, &CheckOptions::default());
+/// println!("typo_chec_result: {:?}", typo_chec_result);
+/// ```
+pub fn check_french_word<S: AsRef<str>>(
+    check_word: &str,
+    french_word_list: &[S],
+    options: &CheckOptions,
+) -> TypoCheckResult {
+    let lowercase_check_word = check_word.to_lowercase();
+    let stripped_check_word = strip_accents(&lowercase_check_word);
+    let mut output = TypoCheckResult::new(lowercase_check_word.clone());
+    let mut weighted_word_list: Vec<(f64, SimilarWord)> = Vec::new();
+
+    for word in french_word_list.iter().map(|word| word.as_ref()) {
+        let weighted_length = accent_levenshtein(&lowercase_check_word, word);
+        if weighted_length == 0.0 {
+            output.match_word = Some(word.to_string());
+            return output;
+        }
+
+        // 距離は切り上げて格納し、アクセント記号のみの違いはMissingAccentとする
+        let mut similar_word = SimilarWord::new(word.to_string(), weighted_length.ceil() as usize)
+            .with_source(SuggestionSource::PersonalDictionary);
+        if strip_accents(word) == stripped_check_word {
+            similar_word.typo_type = TypoType::MissingAccent;
+        }
+        weighted_word_list.push((weighted_length, similar_word));
+    }
+
+    // 同じ距離の中では小数のコストが小さい順になるように先にソートする
+    weighted_word_list.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let similar_word_list: Vec<SimilarWord> = weighted_word_list
+        .into_iter()
+        .map(|(_, similar_word)| similar_word)
+        .collect();
+
+    let check_word_length = lowercase_check_word.chars().count();
+    output.telemetry.scanned_word_num = french_word_list.len();
+    output.telemetry.pass_list = vec![SuggestionSource::PersonalDictionary];
+    output.similar_word_list = Some(get_top_similar_words_with_table(
+        lowercase_check_word,
+        check_word_length,
+        similar_word_list,
+        options,
+        &CharacterTable::for_options(options),
+        &mut output.telemetry,
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_french_word_missing_accent() {
+        let french_word_list = ["élève", "élevé", "relève", "cheval"];
+
+        let typo_chec_result =
+            check_french_word("eleve", &french_word_list, &CheckOptions::default());
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+
+        assert_eq!(similar_word_list[0].typo_type, TypoType::MissingAccent);
+        assert_eq!(similar_word_list[0].levenshtein_length, 1);
+        assert_eq!(similar_word_list[1].typo_type, TypoType::MissingAccent);
+        assert_eq!(similar_word_list[2].spelling, "relève");
+        assert_ne!(similar_word_list[2].typo_type, TypoType::MissingAccent);
+    }
+
+    #[test]
+    fn test_check_french_word_match() {
+        let typo_chec_result = check_french_word("École", &["école"], &CheckOptions::default());
+
+        assert_eq!(typo_chec_result.get_match_word(), "école");
+    }
+}
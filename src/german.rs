@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::{
+    get_top_similar_words_with_table, levenshtein, CharacterTable, CheckOptions, Prescreen,
+    SimilarWord, SuggestionSource, TypoCheckResult,
+};
+
+/// Returns the lowercase word with German umlauts and ß transliterated (ü => ue, ö => oe, ä => ae, ß => ss).
+///
+/// ドイツ語のウムラウトとßを置き換えた小文字の単語を返します(ü => ue, ö => oe, ä => ae, ß => ss)。
+///
+/// # Arguments
+///
+/// * `word` - German word(ドイツ語の単語)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::fold_german_umlauts;
+///
+/// assert_eq!(fold_german_umlauts("Straße"), "strasse");
+/// assert_eq!(fold_german_umlauts("Müller"), fold_german_umlauts("mueller"));
+/// ```
+pub fn fold_german_umlauts(word: &str) -> String {
+    word.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'ü' => "ue".to_string(),
+            'ö' => "oe".to_string(),
+            'ä' => "ae".to_string(),
+            'ß' => "ss".to_string(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+/// Returns TypoCheckResult type words that match or are similar to the German word, using the given German word list.
+/// When `fold_german_umlauts` of the options is true, transliterations such as "ue" and "ss" match "ü" and "ß", and the spelling of the word list is returned.
+///
+/// 指定したドイツ語の単語リストを使って、チェックするドイツ語の単語に合致、もしくは類似する単語をTypoCheckResult型で返却します。
+/// オプションの`fold_german_umlauts`がtrueの場合は"ue"や"ss"のような置き換えが"ü"や"ß"と一致し、単語リストの綴りが返されます。
+///
+/// # Arguments
+///
+/// * `check_word` - German word to check(チェックするドイツ語の単語)
+/// * `german_word_list` - List of correct German words(正しいドイツ語の単語のリスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_german_word, CheckOptions};
+///
+/// let german_word_list = ["Straße", "Müller", "schön"];
+/// let typo_chec_result = check_german_word("strasse", &german_word_list, &CheckOptions::default());
+/// assert_eq!(typo_chec_result.get_match_word(), "Straße");
+///
+/// let typo_chec_result = check_german_word("schoen", &german_word_list, &CheckOptions::default().fold_german_umlauts(false));
+/// assert_ne!(typo_chec_result.get_match_word(), "schön");
+/// ```
+pub fn check_german_word<S: AsRef<str>>(
+    check_word: &str,
+    german_word_list: &[S],
+    options: &CheckOptions,
+) -> TypoCheckResult {
+    let fold = |word: &str| {
+        if options.fold_german_umlauts {
+            fold_german_umlauts(word)
+        } else {
+            word.to_lowercase()
+        }
+    };
+
+    let folded_check_word = fold(check_word);
+    let mut output = TypoCheckResult::new(check_word.to_lowercase());
+    let mut similar_word_list: Vec<SimilarWord> = Vec::new();
+    // 置き換えた綴りから単語リストの綴りを引けるようにする
+    let mut canonical_word_map: HashMap<String, &str> = HashMap::new();
+    let check_word_length = folded_check_word.chars().count();
+    let prescreen = Prescreen::new(
+        &folded_check_word,
+        options.search_levenshtein_cutoff(check_word_length),
+        options,
+    );
+
+    for word in german_word_list.iter().map(|word| word.as_ref()) {
+        let folded_word = fold(word);
+        if let Some(prescreen) = &prescreen {
+            if !prescreen.passes(&folded_word) {
+                output.telemetry.screened_out_num += 1;
+                continue;
+            }
+        }
+        let levenshtein_length = levenshtein(&folded_check_word, &folded_word);
+        if levenshtein_length == 0 {
+            output.match_word = Some(word.to_string());
+            return output;
+        }
+        if canonical_word_map.contains_key(&folded_word) {
+            continue;
+        }
+        canonical_word_map.insert(folded_word.clone(), word);
+        similar_word_list.push(
+            SimilarWord::new(folded_word, levenshtein_length)
+                .with_source(SuggestionSource::PersonalDictionary),
+        );
+    }
+
+    // TypoTypeは置き換えた綴り同士で判別し、単語リストの綴りに戻して返す
+    output.telemetry.scanned_word_num = german_word_list.len();
+    output.telemetry.pass_list = vec![SuggestionSource::PersonalDictionary];
+    let mut top_similar_word_list = get_top_similar_words_with_table(
+        folded_check_word,
+        check_word_length,
+        similar_word_list,
+        options,
+        &CharacterTable::for_options(options),
+        &mut output.telemetry,
+    );
+    for similar_word in top_similar_word_list.iter_mut() {
+        similar_word.spelling = canonical_word_map[&similar_word.spelling].to_string();
+    }
+    output.similar_word_list = Some(top_similar_word_list);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_german_word_renders_canonical_form() {
+        let german_word_list = ["Größe", "Gruß", "Brücke"];
+
+        let typo_chec_result =
+            check_german_word("Groesse", &german_word_list, &CheckOptions::default());
+        assert_eq!(typo_chec_result.get_match_word(), "Größe");
+
+        let typo_chec_result =
+            check_german_word("bruecka", &german_word_list, &CheckOptions::default());
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert_eq!(similar_word_list[0].spelling, "Brücke");
+        assert_eq!(similar_word_list[0].levenshtein_length, 1);
+    }
+
+    #[test]
+    fn test_check_german_word_without_folding() {
+        let options = CheckOptions::default().fold_german_umlauts(false);
+
+        let typo_chec_result = check_german_word("grösse", &["Größe"], &options);
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert!(typo_chec_result.match_word.is_none());
+        assert_eq!(similar_word_list[0].levenshtein_length, 2);
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    get_top_similar_words_with_table, levenshtein, CharacterTable, CheckOptions, Prescreen,
+    SimilarWord, SuggestionSource, TypoCheckResult,
+};
+
+/// JIS配列のかな入力の各段(左から順に並べる)
+const KANA_KEYBOARD_ROW_LIST: [&str; 4] = [
+    "ぬふあうえおやゆよわほへー",
+    "たていすかんなにらせ゛゜",
+    "ちとしはきくまのりれけむ",
+    "つさそひこみもねるめろ",
+];
+
+/// ひらがなを対応するカタカナにする
+fn to_katakana(c: char) -> char {
+    match c {
+        'ぁ'..='ゖ' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// Returns a hashmap of kana and the keys placed near them on the JIS kana keyboard.
+/// Both hiragana and katakana are included.
+///
+/// JIS配列のかな入力で、かなとその近くに配置されたキーのハッシュマップを返します。
+/// ひらがなとカタカナの両方が含まれます。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::kana_keyboard_placement_list;
+///
+/// let close_keyboard_placement = kana_keyboard_placement_list();
+/// assert!(close_keyboard_placement.get(&'し').unwrap().contains(&'は'));
+/// assert!(close_keyboard_placement.get(&'シ').unwrap().contains(&'ハ'));
+/// ```
+pub fn kana_keyboard_placement_list() -> HashMap<char, Vec<char>> {
+    let row_list: Vec<Vec<char>> = KANA_KEYBOARD_ROW_LIST
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect();
+    let mut output_hashmap: HashMap<char, Vec<char>> = HashMap::new();
+
+    for (row_index, row) in row_list.iter().enumerate() {
+        for (column_index, c) in row.iter().enumerate() {
+            let mut close_char_list: Vec<char> = Vec::new();
+
+            // 同じ段の左右のキー
+            if column_index > 0 {
+                close_char_list.push(row[column_index - 1]);
+            }
+            if let Some(right_char) = row.get(column_index + 1) {
+                close_char_list.push(*right_char);
+            }
+
+            // 上下の段の近いキー
+            for other_row_index in [row_index.wrapping_sub(1), row_index + 1] {
+                if let Some(other_row) = row_list.get(other_row_index) {
+                    for other_column_index in column_index.saturating_sub(1)..=column_index + 1 {
+                        if let Some(other_char) = other_row.get(other_column_index) {
+                            close_char_list.push(*other_char);
+                        }
+                    }
+                }
+            }
+
+            output_hashmap.insert(
+                to_katakana(*c),
+                close_char_list.iter().map(|c| to_katakana(*c)).collect(),
+            );
+            output_hashmap.insert(*c, close_char_list);
+        }
+    }
+    output_hashmap
+}
+
+/// Returns an array of groups of kana that are similar in shape.
+/// Kana in the same array are considered “similar in shape”.
+///
+/// 形状が似ているかなのグループの配列を返します。
+/// 同じ配列に入っているかなは「形状が似ている」と見做しています。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::kana_similar_shape_list;
+///
+/// let similar_group = kana_similar_shape_list();
+/// assert!(similar_group.iter().any(|group| group.contains(&'シ') && group.contains(&'ツ')));
+/// ```
+pub fn kana_similar_shape_list() -> Vec<Vec<char>> {
+    vec![
+        // カタカナ
+        vec!['シ', 'ツ'],
+        vec!['ソ', 'ン'],
+        vec!['ク', 'ケ', 'タ'],
+        vec!['ウ', 'ワ', 'フ'],
+        vec!['コ', 'ユ', 'ロ'],
+        vec!['チ', 'テ'],
+        vec!['ス', 'ヌ'],
+        vec!['ア', 'マ'],
+        // ひらがな
+        vec!['あ', 'お'],
+        vec!['い', 'り'],
+        vec!['き', 'さ', 'ち'],
+        vec!['ぬ', 'め'],
+        vec!['ね', 'れ', 'わ'],
+        vec!['は', 'ほ'],
+        vec!['る', 'ろ'],
+    ]
+}
+
+/// Returns TypoCheckResult type words that match or are similar to the kana word, using the given kana word list.
+/// SimilarShapes and CloseKeyboardPlacement are classified with the kana shapes and the JIS kana keyboard.
+///
+/// 指定したかなの単語リストを使って、チェックするかなの単語に合致、もしくは類似する単語をTypoCheckResult型で返却します。
+/// SimilarShapesとCloseKeyboardPlacementは、かなの形状とJIS配列のかな入力に基づいて判別します。
+///
+/// # Arguments
+///
+/// * `check_word` - Kana word to check(チェックするかなの単語)
+/// * `kana_word_list` - List of correct kana words(正しいかなの単語のリスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_kana_word, CheckOptions};
+///
+/// let kana_word_list = ["シャツ", "ズボン", "くつした"];
+/// let typo_chec_result = check_kana_word("シャシ", &kana_word_list, &CheckOptions::default());
+/// assert_eq!(typo_chec_result.did_you_mean(1), Some("did you mean \"シャツ\"?".to_string()));
+/// ```
+pub fn check_kana_word<S: AsRef<str>>(
+    check_word: &str,
+    kana_word_list: &[S],
+    options: &CheckOptions,
+) -> TypoCheckResult {
+    let mut output = TypoCheckResult::new(check_word.to_string());
+    let mut similar_word_list: Vec<SimilarWord> = Vec::new();
+    let check_word_length = check_word.chars().count();
+    let prescreen = Prescreen::new(
+        check_word,
+        options.search_levenshtein_cutoff(check_word_length),
+        options,
+    );
+
+    for word in kana_word_list.iter().map(|word| word.as_ref()) {
+        if let Some(prescreen) = &prescreen {
+            if !prescreen.passes(word) {
+                output.telemetry.screened_out_num += 1;
+                continue;
+            }
+        }
+        let levenshtein_length = levenshtein(check_word, word);
+        if levenshtein_length == 0 {
+            output.match_word = Some(word.to_string());
+            return output;
+        }
+        similar_word_list.push(
+            SimilarWord::new(word.to_string(), levenshtein_length)
+                .with_source(SuggestionSource::PersonalDictionary),
+        );
+    }
+
+    output.telemetry.scanned_word_num = kana_word_list.len();
+    output.telemetry.pass_list = vec![SuggestionSource::PersonalDictionary];
+
+    let character_table = CharacterTable {
+        similar_shape: kana_similar_shape_list(),
+        similar_shape_sequence: Vec::new(),
+        close_keyboard_placement: kana_keyboard_placement_list(),
+        classifier_list: Vec::new(),
+        frequency_map: Arc::default(),
+    };
+    output.similar_word_list = Some(get_top_similar_words_with_table(
+        check_word.to_string(),
+        check_word_length,
+        similar_word_list,
+        options,
+        &character_table,
+        &mut output.telemetry,
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TypoType;
+
+    #[test]
+    fn test_kana_keyboard_placement_list_edges() {
+        let close_keyboard_placement = kana_keyboard_placement_list();
+
+        // 左端の「ぬ」は右隣と下の段のみ
+        let mut close_char_list = close_keyboard_placement.get(&'ぬ').unwrap().clone();
+        close_char_list.sort();
+        let mut expected_char_list = vec!['ふ', 'た', 'て'];
+        expected_char_list.sort();
+        assert_eq!(close_char_list, expected_char_list);
+
+        assert!(close_keyboard_placement.get(&'ツ').unwrap().contains(&'チ'));
+        assert!(!close_keyboard_placement.get(&'つ').unwrap().contains(&'ろ'));
+    }
+
+    #[test]
+    fn test_check_kana_word_close_keyboard_placement() {
+        let kana_word_list = ["さくら", "すいか", "みかん"];
+
+        // 「く」の代わりに隣の「き」を入力した
+        let typo_chec_result = check_kana_word("さきら", &kana_word_list, &CheckOptions::default());
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert_eq!(similar_word_list[0].spelling, "さくら");
+        assert_eq!(
+            similar_word_list[0].typo_type,
+            TypoType::CloseKeyboardPlacement
+        );
+
+        let typo_chec_result = check_kana_word("みかん", &kana_word_list, &CheckOptions::default());
+        assert_eq!(typo_chec_result.get_match_word(), "みかん");
+    }
+}
@@ -0,0 +1,437 @@
+use std::collections::HashMap;
+
+use crate::{close_keyboard_placement_list, levenshtein};
+
+/// Enum of the keyboard layouts used to classify CloseKeyboardPlacement, which can also be detected from typos
+///
+/// CloseKeyboardPlacementの判別に使う、タイポから推定することもできるキーボード配列の列挙型です
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum KeyboardLayout {
+    /// US and UK layout(米国と英国の配列)
+    #[default]
+    Qwerty,
+    /// German layout, Y and Z are swapped(ドイツ語の配列。YとZが入れ替わっています)
+    Qwertz,
+    /// French layout(フランス語の配列)
+    Azerty,
+    /// Dvorak simplified keyboard(Dvorak配列)
+    Dvorak,
+    /// Colemak layout(Colemak配列)
+    Colemak,
+}
+
+impl KeyboardLayout {
+    /// Returns all keyboard layouts(全てのキーボード配列を返します)
+    pub fn all() -> [KeyboardLayout; 5] {
+        [
+            KeyboardLayout::Qwerty,
+            KeyboardLayout::Qwertz,
+            KeyboardLayout::Azerty,
+            KeyboardLayout::Dvorak,
+            KeyboardLayout::Colemak,
+        ]
+    }
+
+    /// Returns the name of the keyboard layout(キーボード配列の名前を返します)
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwerty => "qwerty",
+            KeyboardLayout::Qwertz => "qwertz",
+            KeyboardLayout::Azerty => "azerty",
+            KeyboardLayout::Dvorak => "dvorak",
+            KeyboardLayout::Colemak => "colemak",
+        }
+    }
+
+    /// 文字キーの段。記号のキーは列の位置を合わせるために含める
+    fn row_list(&self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Qwertz => ["qwertzuiop", "asdfghjkl", "yxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+            KeyboardLayout::Dvorak => ["',.pyfgcrl", "aoeuidhtns", ";qjkxbmwvz"],
+            KeyboardLayout::Colemak => ["qwfpgjluy", "arstdhneio", "zxcvbkm"],
+        }
+    }
+
+    /// Returns a hashmap of adjacent keys of the layout, in the same form as `close_keyboard_placement_list`.
+    ///
+    /// `close_keyboard_placement_list`と同じ形式で、キーボード配列で隣接しているキーのハッシュマップを返します。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::KeyboardLayout;
+    ///
+    /// let close_keyboard_placement = KeyboardLayout::Qwertz.close_keyboard_placement_list();
+    /// assert!(close_keyboard_placement.get(&'z').unwrap().contains(&'u'));
+    /// ```
+    pub fn close_keyboard_placement_list(&self) -> HashMap<char, Vec<char>> {
+        if *self == KeyboardLayout::Qwerty {
+            return close_keyboard_placement_list();
+        }
+
+        let row_list: Vec<Vec<char>> = self
+            .row_list()
+            .iter()
+            .map(|row| row.chars().collect())
+            .collect();
+        let mut output_hashmap: HashMap<char, Vec<char>> = HashMap::new();
+        for (row_index, row) in row_list.iter().enumerate() {
+            for (column_index, c) in row.iter().enumerate() {
+                if !c.is_alphabetic() {
+                    continue;
+                }
+                let mut close_char_list: Vec<char> = Vec::new();
+
+                // 同じ段の左右のキー
+                if column_index > 0 {
+                    close_char_list.push(row[column_index - 1]);
+                }
+                if let Some(right_char) = row.get(column_index + 1) {
+                    close_char_list.push(*right_char);
+                }
+
+                // 上下の段の近いキー
+                for other_row_index in [row_index.wrapping_sub(1), row_index + 1] {
+                    if let Some(other_row) = row_list.get(other_row_index) {
+                        for other_column_index in column_index.saturating_sub(1)..=column_index + 1
+                        {
+                            if let Some(other_char) = other_row.get(other_column_index) {
+                                close_char_list.push(*other_char);
+                            }
+                        }
+                    }
+                }
+
+                close_char_list.retain(|close_char| close_char.is_alphabetic());
+                output_hashmap.insert(*c, close_char_list);
+            }
+        }
+        output_hashmap
+    }
+
+    /// Returns the adjacent keys of the layout as a list, which can be added to `keyboard_adjacency` of CheckOptions(CheckOptionsの`keyboard_adjacency`に加えられる、キーボード配列で隣接しているキーのリストを返します)
+    pub fn keyboard_adjacency(&self) -> Vec<(char, Vec<char>)> {
+        let mut keyboard_adjacency: Vec<(char, Vec<char>)> =
+            self.close_keyboard_placement_list().into_iter().collect();
+        keyboard_adjacency.sort_by_key(|(key, _)| *key);
+        keyboard_adjacency
+    }
+}
+
+/// 隣接したキーの置換のコスト
+const ADJACENT_KEY_COST: f64 = 0.5;
+
+/// Enum of the edit distances used to rank the similar words
+///
+/// 似ている単語の順位付けに使う編集距離の列挙型です
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DistanceMetric {
+    /// Levenshtein distance where every edit costs 1(全ての編集のコストが1のレーベンシュタイン距離)
+    #[default]
+    Levenshtein,
+    /// `keyboard_levenshtein` with the keyboard placement of the options. The cutoff still uses the Levenshtein distance(オプションのキーボード配置を使った`keyboard_levenshtein`。カットオフ値にはレーベンシュタイン距離を使います)
+    KeyboardWeighted,
+}
+
+/// Calculate the Levenshtein distance where a substitution of adjacent keys costs 0.5 instead of 1, regardless of case.
+///
+/// 大文字小文字に関係なく、隣接したキーの置換を1ではなく0.5として、レーベンシュタイン距離を計算します。
+///
+/// # Arguments
+///
+/// * `a` - Word A to be compared(比較対象の単語A)
+/// * `b` - Word B to be compared(比較対象の単語B)
+/// * `close_keyboard_placement` - Hashmap of adjacent keys, such as `close_keyboard_placement_list`(`close_keyboard_placement_list`などの、隣接したキーのハッシュマップ)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{keyboard_levenshtein, KeyboardLayout};
+///
+/// let close_keyboard_placement = KeyboardLayout::Qwerty.close_keyboard_placement_list();
+/// assert_eq!(0.5, keyboard_levenshtein("gello", "hello", &close_keyboard_placement));
+/// assert_eq!(1.0, keyboard_levenshtein("cello", "hello", &close_keyboard_placement));
+/// ```
+pub fn keyboard_levenshtein(
+    a: &str,
+    b: &str,
+    close_keyboard_placement: &HashMap<char, Vec<char>>,
+) -> f64 {
+    let a_char_list: Vec<char> = a.to_lowercase().chars().collect();
+    let b_char_list: Vec<char> = b.to_lowercase().chars().collect();
+
+    let mut cache: Vec<f64> = (0..=b_char_list.len()).map(|i| i as f64).collect();
+    for (i, a_char) in a_char_list.iter().enumerate() {
+        let mut previous_diagonal = cache[0];
+        cache[0] = (i + 1) as f64;
+        for (j, b_char) in b_char_list.iter().enumerate() {
+            let substitution_cost = if a_char == b_char {
+                0.0
+            } else if close_keyboard_placement
+                .get(a_char)
+                .is_some_and(|close_char_list| close_char_list.contains(b_char))
+            {
+                ADJACENT_KEY_COST
+            } else {
+                1.0
+            };
+            let distance = (previous_diagonal + substitution_cost)
+                .min(cache[j] + 1.0)
+                .min(cache[j + 1] + 1.0);
+            previous_diagonal = cache[j + 1];
+            cache[j + 1] = distance;
+        }
+    }
+    cache[b_char_list.len()]
+}
+
+/// Struct that stores how well each keyboard layout explains the observed substitutions
+///
+/// 観測した置換を各キーボード配列がどれだけ説明できるかを格納する構造体です
+///
+/// # Arguments
+///
+/// * `substitution_num` - Number of pairs whose typo differs from the correction by one substituted character(タイポが修正と1文字の置換だけ異なる組の数)
+/// * `explained_num_list` - Number of substitutions of adjacent keys for each layout, in the order of `KeyboardLayout::all`(配列ごとの、隣接したキーの置換の数。`KeyboardLayout::all`の順です)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutDetection {
+    pub substitution_num: usize,
+    pub explained_num_list: Vec<(KeyboardLayout, usize)>,
+}
+
+impl LayoutDetection {
+    /// Returns the ratio of the substitutions explained by the layout, or 0.0 if there is no substitution(配列が説明できる置換の割合を返します。置換が無い場合は0.0です)
+    pub fn explained_ratio(&self, layout: KeyboardLayout) -> f64 {
+        if self.substitution_num == 0 {
+            return 0.0;
+        }
+        self.explained_num_list
+            .iter()
+            .find(|(explained_layout, _)| *explained_layout == layout)
+            .map(|(_, explained_num)| *explained_num as f64 / self.substitution_num as f64)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the layout that explains the most substitutions. Ties are broken by the order of `KeyboardLayout::all`, and None is returned if no substitution is explained(最も多くの置換を説明できる配列を返します。同数の場合は`KeyboardLayout::all`の順で選び、説明できる置換が無い場合はNoneを返します)
+    pub fn recommended_layout(&self) -> Option<KeyboardLayout> {
+        self.explained_num_list
+            .iter()
+            .filter(|(_, explained_num)| *explained_num > 0)
+            .fold(
+                None,
+                |best: Option<&(KeyboardLayout, usize)>, candidate| match best {
+                    Some(best) if best.1 >= candidate.1 => Some(best),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(layout, _)| *layout)
+    }
+}
+
+/// 同じ文字数で1文字だけ異なる場合に、(タイポの文字、正しい文字)を返す
+fn find_substitution(typo: &str, correction: &str) -> Option<(char, char)> {
+    let typo_char_list: Vec<char> = typo.chars().collect();
+    let correction_char_list: Vec<char> = correction.chars().collect();
+    if typo_char_list.len() != correction_char_list.len() || levenshtein(typo, correction) != 1 {
+        return None;
+    }
+    typo_char_list
+        .into_iter()
+        .zip(correction_char_list)
+        .find(|(typo_char, correction_char)| typo_char != correction_char)
+}
+
+/// Infers the keyboard layout that best explains the substitutions in the pairs of a typo and its correction.
+/// Only the pairs that differ by one substituted character are counted, and case is ignored.
+///
+/// タイポとその修正の組の置換を最もよく説明できるキーボード配列を推定します。
+/// 1文字の置換だけ異なる組のみを数え、大文字小文字は区別しません。
+///
+/// # Arguments
+///
+/// * `pair_list` - Pairs of a typo and its correction, such as a correction log(修正ログなどの、タイポとその修正の組)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{detect_keyboard_layout, CheckOptions, KeyboardLayout};
+///
+/// let layout_detection = detect_keyboard_layout(&[("qzick", "quick"), ("bzt", "but"), ("typo", "typo")]);
+/// let recommended_layout = layout_detection.recommended_layout().unwrap();
+/// assert_eq!(recommended_layout, KeyboardLayout::Qwertz);
+///
+/// let options = CheckOptions::default().keyboard_layout(recommended_layout);
+/// ```
+pub fn detect_keyboard_layout<S: AsRef<str>, T: AsRef<str>>(
+    pair_list: &[(S, T)],
+) -> LayoutDetection {
+    let substitution_list: Vec<(char, char)> = pair_list
+        .iter()
+        .filter_map(|(typo, correction)| {
+            find_substitution(
+                &typo.as_ref().to_lowercase(),
+                &correction.as_ref().to_lowercase(),
+            )
+        })
+        .collect();
+
+    let explained_num_list = KeyboardLayout::all()
+        .into_iter()
+        .map(|layout| {
+            let close_keyboard_placement = layout.close_keyboard_placement_list();
+            let explained_num = substitution_list
+                .iter()
+                .filter(|(typo_char, correction_char)| {
+                    close_keyboard_placement
+                        .get(correction_char)
+                        .is_some_and(|close_char_list| close_char_list.contains(typo_char))
+                })
+                .count();
+            (layout, explained_num)
+        })
+        .collect();
+
+    LayoutDetection {
+        substitution_num: substitution_list.len(),
+        explained_num_list,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_a_word_with, CheckOptions, TypoType};
+
+    #[test]
+    fn test_close_keyboard_placement_is_symmetric() {
+        for layout in KeyboardLayout::all() {
+            let close_keyboard_placement = layout.close_keyboard_placement_list();
+            assert_eq!(close_keyboard_placement.len(), 26, "{}", layout.name());
+            for (key, close_char_list) in &close_keyboard_placement {
+                for close_char in close_char_list {
+                    assert!(
+                        close_keyboard_placement[close_char].contains(key),
+                        "{}: {} {}",
+                        layout.name(),
+                        key,
+                        close_char
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyboard_layout_option() {
+        // AZERTYでは"m"と"p"が隣接しているが、QWERTYでは隣接していない
+        let find_typo_type = |keyboard_layout: KeyboardLayout| {
+            let options = CheckOptions::default().keyboard_layout(keyboard_layout);
+            check_a_word_with("pilk", &options)
+                .get_similar_word_list()
+                .into_iter()
+                .find(|similar_word| similar_word.get_spelling() == "milk")
+                .map(|similar_word| similar_word.get_typo_type().clone())
+        };
+        assert_eq!(
+            find_typo_type(KeyboardLayout::Azerty),
+            Some(TypoType::CloseKeyboardPlacement)
+        );
+        assert_ne!(
+            find_typo_type(KeyboardLayout::Qwerty),
+            Some(TypoType::CloseKeyboardPlacement)
+        );
+    }
+
+    #[test]
+    fn test_keyboard_adjacency_map_option() {
+        let find_typo_type = |options: &CheckOptions, check_word: &str, correct_word: &str| {
+            check_a_word_with(check_word, options)
+                .get_similar_word_list()
+                .into_iter()
+                .find(|similar_word| similar_word.get_spelling() == correct_word)
+                .map(|similar_word| similar_word.get_typo_type().clone())
+        };
+        // 独自の表は組み込みの表を置き換え、双方向に登録される
+        let options = CheckOptions::default()
+            .keyboard_adjacency_map(HashMap::from([('p', vec!['m']), ('r', vec!['w'])]));
+        assert_eq!(
+            options.keyboard_adjacency,
+            vec![('p', vec!['m']), ('r', vec!['w'])]
+        );
+        assert_eq!(
+            find_typo_type(&options, "pilk", "milk"),
+            Some(TypoType::CloseKeyboardPlacement)
+        );
+        assert_eq!(
+            find_typo_type(&options, "mencil", "pencil"),
+            Some(TypoType::CloseKeyboardPlacement)
+        );
+        assert_ne!(
+            find_typo_type(&options, "hrllo", "hello"),
+            Some(TypoType::CloseKeyboardPlacement)
+        );
+        assert_eq!(
+            find_typo_type(&CheckOptions::default(), "hrllo", "hello"),
+            Some(TypoType::CloseKeyboardPlacement)
+        );
+    }
+
+    #[test]
+    fn test_keyboard_weighted_distance_metric() {
+        let spelling_list = |options: &CheckOptions| -> Vec<String> {
+            check_a_word_with("gello", options)
+                .get_similar_word_list()
+                .iter()
+                .map(|similar_word| similar_word.get_spelling().to_string())
+                .collect()
+        };
+        let position = |spelling_list: &[String], spelling: &str| {
+            spelling_list.iter().position(|word| word == spelling)
+        };
+        // TypoTypeで並べ替えずに、距離の順序のみを比べる
+        let options = CheckOptions::default()
+            .sort_order_of_typo_type(Vec::new())
+            .pickup_similar_word_num(100);
+        let default_list = spelling_list(&options);
+        assert!(position(&default_list, "cello") < position(&default_list, "hello"));
+
+        let weighted_list =
+            spelling_list(&options.distance_metric(DistanceMetric::KeyboardWeighted));
+        assert_eq!(weighted_list.len(), default_list.len());
+        assert!(position(&weighted_list, "hello") < position(&weighted_list, "cello"));
+    }
+
+    #[test]
+    fn test_detect_keyboard_layout() {
+        // AZERTYでは"z"と"e"、"m"と"p"が隣接している
+        let layout_detection =
+            detect_keyboard_layout(&[("eero", "zero"), ("pilk", "milk"), ("hello", "hello")]);
+        assert_eq!(layout_detection.substitution_num, 2);
+        assert_eq!(
+            layout_detection.recommended_layout(),
+            Some(KeyboardLayout::Azerty)
+        );
+        assert_eq!(
+            layout_detection.explained_ratio(KeyboardLayout::Azerty),
+            1.0
+        );
+
+        // 同数の場合はQWERTYを選ぶ
+        let layout_detection = detect_keyboard_layout(&[("hrllo", "hello")]);
+        assert_eq!(
+            layout_detection.recommended_layout(),
+            Some(KeyboardLayout::Qwerty)
+        );
+        let empty_pair_list: [(&str, &str); 0] = [];
+        assert_eq!(
+            detect_keyboard_layout(&empty_pair_list).recommended_layout(),
+            None
+        );
+    }
+}
@@ -0,0 +1,478 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::num::ParseFloatError;
+use std::str::FromStr;
+
+use crate::{
+    classify_similar_word, levenshtein, CharacterTable, CheckOptions, SimilarWord, TypoCheckResult,
+    TypoType,
+};
+
+/// Number of TypoType variants
+const TYPO_TYPE_NUM: usize = 11;
+
+/// Number of features: distance, TypoType, frequency, keyboard distance, prefix overlap
+const FEATURE_NUM: usize = TYPO_TYPE_NUM + 4;
+
+/// Struct that stores one correction log used for training
+///
+/// 学習に使う修正ログ1件を格納する構造体です
+///
+/// # Arguments
+///
+/// * `misspelling` - The misspelled word(タイポした単語)
+/// * `chosen_correction` - The correction the user chose(ユーザーが選んだ修正候補)
+/// * `candidates` - The candidates shown to the user(ユーザーに提示された修正候補)
+#[derive(Debug, Clone)]
+pub struct CorrectionLog {
+    pub misspelling: String,
+    pub chosen_correction: String,
+    pub candidates: Vec<String>,
+}
+
+/// Logistic regression ranker that scores similar words by learned weights
+///
+/// 学習した重みで似ている単語をスコア付けするロジスティック回帰のランカーです
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogisticRanker {
+    weights: Vec<f64>,
+    bias: f64,
+}
+
+impl Default for LogisticRanker {
+    fn default() -> LogisticRanker {
+        LogisticRanker {
+            weights: vec![0.0; FEATURE_NUM],
+            bias: 0.0,
+        }
+    }
+}
+
+/// Error returned when parsing a serialized LogisticRanker fails
+///
+/// シリアライズされたLogisticRankerの読み込みに失敗したときのエラーです
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseLogisticRankerError {
+    /// A value is not a number(数値ではない値があります)
+    InvalidNumber(ParseFloatError),
+    /// The number of values is wrong(値の個数が正しくありません)
+    WrongLength(usize),
+}
+
+impl fmt::Display for ParseLogisticRankerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLogisticRankerError::InvalidNumber(error) => {
+                write!(f, "invalid number in ranker weights: {}", error)
+            }
+            ParseLogisticRankerError::WrongLength(length) => write!(
+                f,
+                "expected {} ranker values but got {}",
+                FEATURE_NUM + 1,
+                length
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseLogisticRankerError {}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Counts the substituted characters that are not adjacent on the keyboard.
+/// For words of different length, the Levenshtein distance is used instead.
+///
+/// キーボード上で隣接していない置換文字の数を数えます。
+/// 文字数が異なる単語の場合はレーベンシュタイン距離を代わりに使います。
+fn keyboard_distance(
+    check_word: &str,
+    similar_word: &SimilarWord,
+    close_keyboard_placement: &HashMap<char, Vec<char>>,
+) -> f64 {
+    if check_word.chars().count() != similar_word.spelling.chars().count() {
+        return similar_word.levenshtein_length as f64;
+    }
+
+    check_word
+        .chars()
+        .zip(similar_word.spelling.chars())
+        .filter(|(c, s)| {
+            c != s
+                && !close_keyboard_placement
+                    .get(c)
+                    .is_some_and(|close_char_list| close_char_list.contains(s))
+        })
+        .count() as f64
+}
+
+fn extract_features(
+    check_word: &str,
+    similar_word: &SimilarWord,
+    character_table: &CharacterTable,
+    frequency_map: &HashMap<String, f64>,
+) -> [f64; FEATURE_NUM] {
+    let typo_type_index = match similar_word.typo_type {
+        TypoType::ExtraCharacters { .. } => 1,
+        TypoType::MissingCharacters { .. } => 2,
+        TypoType::SimilarShapes => 3,
+        TypoType::CloseKeyboardPlacement => 4,
+        TypoType::CommonMisspelling => 5,
+        TypoType::CapsLock => 6,
+        TypoType::MissingAccent => 7,
+        TypoType::Transposition => 8,
+        TypoType::RepeatedCharacter { .. } => 9,
+        TypoType::Custom(_) => 10,
+        TypoType::UndefinedType => 11,
+    };
+    let check_word_length = check_word.chars().count().max(1);
+    let common_prefix_length = check_word
+        .chars()
+        .zip(similar_word.spelling.chars())
+        .take_while(|(c, s)| c == s)
+        .count();
+
+    let mut features = [0.0; FEATURE_NUM];
+    features[0] = similar_word.levenshtein_length as f64;
+    features[typo_type_index] = 1.0;
+    // 頻度のハッシュマップに無い単語は頻度0とする
+    features[TYPO_TYPE_NUM + 1] = frequency_map
+        .get(&similar_word.spelling)
+        .copied()
+        .unwrap_or(0.0)
+        .max(0.0)
+        .ln_1p();
+    features[TYPO_TYPE_NUM + 2] = keyboard_distance(
+        check_word,
+        similar_word,
+        &character_table.close_keyboard_placement,
+    );
+    features[TYPO_TYPE_NUM + 3] = common_prefix_length as f64 / check_word_length as f64;
+    features
+}
+
+impl LogisticRanker {
+    /// Trains the ranker from correction logs by stochastic gradient descent.
+    /// The chosen correction is treated as a positive example and the other candidates as negative examples.
+    ///
+    /// 修正ログから確率的勾配降下法でランカーを学習します。
+    /// 選ばれた修正候補を正例、それ以外の候補を負例として扱います。
+    ///
+    /// # Arguments
+    ///
+    /// * `correction_log_list` - Correction logs for training(学習に使う修正ログ)
+    /// * `epochs` - Number of passes over the logs(ログを学習する回数)
+    /// * `learning_rate` - Learning rate(学習率)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{CorrectionLog, LogisticRanker};
+    ///
+    /// let correction_log_list = vec![CorrectionLog {
+    ///     misspelling: "tets".to_string(),
+    ///     chosen_correction: "test".to_string(),
+    ///     candidates: vec!["test".to_string(), "bets".to_string()],
+    /// }];
+    /// let ranker = LogisticRanker::train(&correction_log_list, 100, 0.1);
+    /// println!("ranker: {}", ranker);
+    /// ```
+    pub fn train(
+        correction_log_list: &[CorrectionLog],
+        epochs: usize,
+        learning_rate: f64,
+    ) -> LogisticRanker {
+        LogisticRanker::train_with(
+            correction_log_list,
+            epochs,
+            learning_rate,
+            &CheckOptions::default(),
+            &HashMap::new(),
+        )
+    }
+
+    /// Same as `train`, but classifies the candidates with the keyboard placement of the options and uses the frequencies as a feature.
+    /// Score the words with the `_with` methods and the same options and frequencies.
+    ///
+    /// `train`と同じですが、オプションのキーボード配置で候補を分類し、頻度を特徴量に使います。
+    /// 単語のスコアは`_with`のメソッドに同じオプションと頻度を指定して計算してください。
+    ///
+    /// # Arguments
+    ///
+    /// * `correction_log_list` - Correction logs for training(学習に使う修正ログ)
+    /// * `epochs` - Number of passes over the logs(ログを学習する回数)
+    /// * `learning_rate` - Learning rate(学習率)
+    /// * `options` - Options of typo check(タイポチェックのオプション)
+    /// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+    pub fn train_with(
+        correction_log_list: &[CorrectionLog],
+        epochs: usize,
+        learning_rate: f64,
+        options: &CheckOptions,
+        frequency_map: &HashMap<String, f64>,
+    ) -> LogisticRanker {
+        let character_table = CharacterTable::for_options(options);
+        let mut ranker = LogisticRanker::default();
+
+        // 特徴量とラベルを先に計算しておく
+        let mut example_list: Vec<([f64; FEATURE_NUM], f64)> = Vec::new();
+        for correction_log in correction_log_list {
+            let check_word = correction_log.misspelling.to_lowercase();
+            let check_word_length = check_word.chars().count();
+            for candidate in correction_log.candidates.iter() {
+                let similar_word = classify_similar_word(
+                    &check_word,
+                    check_word_length,
+                    SimilarWord::new(candidate.clone(), levenshtein(&check_word, candidate)),
+                    &character_table,
+                );
+                let label = f64::from(u8::from(*candidate == correction_log.chosen_correction));
+                example_list.push((
+                    extract_features(&check_word, &similar_word, &character_table, frequency_map),
+                    label,
+                ));
+            }
+        }
+
+        for _ in 0..epochs {
+            for (features, label) in example_list.iter() {
+                let error = ranker.predict(features) - label;
+                for (weight, feature) in ranker.weights.iter_mut().zip(features.iter()) {
+                    *weight -= learning_rate * error * feature;
+                }
+                ranker.bias -= learning_rate * error;
+            }
+        }
+
+        ranker
+    }
+
+    fn predict(&self, features: &[f64; FEATURE_NUM]) -> f64 {
+        let linear: f64 = self
+            .weights
+            .iter()
+            .zip(features.iter())
+            .map(|(weight, feature)| weight * feature)
+            .sum();
+        sigmoid(linear + self.bias)
+    }
+
+    /// Returns the probability that the similar word is the intended correction
+    ///
+    /// 似ている単語が意図した修正候補である確率を返します
+    ///
+    /// # Arguments
+    ///
+    /// * `check_word` - The check word(チェックする単語)
+    /// * `similar_word` - Similar word to be scored(スコアを計算する似ている単語)
+    pub fn probability(&self, check_word: &str, similar_word: &SimilarWord) -> f64 {
+        self.probability_with(
+            check_word,
+            similar_word,
+            &CheckOptions::default(),
+            &HashMap::new(),
+        )
+    }
+
+    /// Same as `probability`, but with the options and frequencies given to `train_with`(`probability`と同じですが、`train_with`に指定したオプションと頻度を使います)
+    pub fn probability_with(
+        &self,
+        check_word: &str,
+        similar_word: &SimilarWord,
+        options: &CheckOptions,
+        frequency_map: &HashMap<String, f64>,
+    ) -> f64 {
+        self.predict(&extract_features(
+            check_word,
+            similar_word,
+            &CharacterTable::for_options(options),
+            frequency_map,
+        ))
+    }
+
+    /// Sorts the similar words in descending order of probability
+    ///
+    /// 似ている単語を確率の高い順にソートします
+    pub fn sort(&self, check_word: &str, similar_word_list: &mut [SimilarWord]) {
+        self.sort_with(
+            check_word,
+            similar_word_list,
+            &CheckOptions::default(),
+            &HashMap::new(),
+        );
+    }
+
+    /// Same as `sort`, but with the options and frequencies given to `train_with`(`sort`と同じですが、`train_with`に指定したオプションと頻度を使います)
+    pub fn sort_with(
+        &self,
+        check_word: &str,
+        similar_word_list: &mut [SimilarWord],
+        options: &CheckOptions,
+        frequency_map: &HashMap<String, f64>,
+    ) {
+        let character_table = CharacterTable::for_options(options);
+        similar_word_list.sort_by(|a, b| {
+            let a_features = extract_features(check_word, a, &character_table, frequency_map);
+            let b_features = extract_features(check_word, b, &character_table, frequency_map);
+            self.predict(&b_features)
+                .total_cmp(&self.predict(&a_features))
+        });
+    }
+
+    /// Re-ranks the similar words stored in TypoCheckResult
+    ///
+    /// TypoCheckResultに格納されている似ている単語を並べ替えます
+    pub fn rerank(&self, result: &mut TypoCheckResult) {
+        self.rerank_with(result, &CheckOptions::default(), &HashMap::new());
+    }
+
+    /// Same as `rerank`, but with the options and frequencies given to `train_with`(`rerank`と同じですが、`train_with`に指定したオプションと頻度を使います)
+    pub fn rerank_with(
+        &self,
+        result: &mut TypoCheckResult,
+        options: &CheckOptions,
+        frequency_map: &HashMap<String, f64>,
+    ) {
+        if let Some(similar_word_list) = result.similar_word_list.as_mut() {
+            self.sort_with(
+                &result.check_word,
+                similar_word_list,
+                options,
+                frequency_map,
+            );
+        }
+    }
+}
+
+impl fmt::Display for LogisticRanker {
+    /// Serializes the ranker as space-separated weights followed by the bias
+    ///
+    /// 重みとバイアスを空白区切りで出力します
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value_list: Vec<String> = self
+            .weights
+            .iter()
+            .chain(std::iter::once(&self.bias))
+            .map(|value| value.to_string())
+            .collect();
+        write!(f, "{}", value_list.join(" "))
+    }
+}
+
+impl FromStr for LogisticRanker {
+    type Err = ParseLogisticRankerError;
+
+    fn from_str(s: &str) -> Result<LogisticRanker, ParseLogisticRankerError> {
+        let mut value_list = s
+            .split_whitespace()
+            .map(|value| value.parse::<f64>())
+            .collect::<Result<Vec<f64>, ParseFloatError>>()
+            .map_err(ParseLogisticRankerError::InvalidNumber)?;
+
+        if value_list.len() != FEATURE_NUM + 1 {
+            return Err(ParseLogisticRankerError::WrongLength(value_list.len()));
+        }
+
+        let bias = value_list.pop().unwrap();
+        Ok(LogisticRanker {
+            weights: value_list,
+            bias,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SuggestionSource;
+
+    fn correction_log_list() -> Vec<CorrectionLog> {
+        vec![
+            CorrectionLog {
+                misspelling: "tets".to_string(),
+                chosen_correction: "text".to_string(),
+                candidates: vec!["test".to_string(), "text".to_string()],
+            },
+            CorrectionLog {
+                misspelling: "hwllo".to_string(),
+                chosen_correction: "hello".to_string(),
+                candidates: vec!["hollo".to_string(), "hello".to_string()],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_train_prefers_chosen_correction() {
+        let ranker = LogisticRanker::train(&correction_log_list(), 200, 0.1);
+        let mut similar_word_list = vec![
+            SimilarWord::new("hollo".to_string(), 1),
+            SimilarWord {
+                spelling: "hello".to_string(),
+                levenshtein_length: 1,
+                typo_type: TypoType::CloseKeyboardPlacement,
+                source: SuggestionSource::FuzzySearch,
+            },
+        ];
+
+        ranker.sort("hwllo", &mut similar_word_list);
+
+        assert_eq!(similar_word_list[0].spelling, "hello");
+    }
+
+    #[test]
+    fn test_train_with_options_and_frequency() {
+        // "a"と"p"はQWERTYでは隣接しないが、オプションで追加した配置では隣接する
+        let options = CheckOptions::default()
+            .keyboard_adjacency('a', vec!['p'])
+            .replace_layout_adjacency(true);
+        let similar_word = SimilarWord::new("pb".to_string(), 1);
+        let frequency_map: HashMap<String, f64> = [("pb".to_string(), 100.0)].into();
+
+        let features = extract_features(
+            "ab",
+            &similar_word,
+            &CharacterTable::for_options(&options),
+            &frequency_map,
+        );
+        assert_eq!(features[TYPO_TYPE_NUM + 1], 100.0_f64.ln_1p());
+        assert_eq!(features[TYPO_TYPE_NUM + 2], 0.0);
+        let features = extract_features(
+            "ab",
+            &similar_word,
+            &CharacterTable::default(),
+            &HashMap::new(),
+        );
+        assert_eq!(features[TYPO_TYPE_NUM + 1], 0.0);
+        assert_eq!(features[TYPO_TYPE_NUM + 2], 1.0);
+
+        // 選ばれた修正候補が頻度の高い単語の場合、頻度の重みを学習する
+        let correction_log_list = vec![CorrectionLog {
+            misspelling: "tets".to_string(),
+            chosen_correction: "text".to_string(),
+            candidates: vec!["test".to_string(), "text".to_string()],
+        }];
+        let frequency_map: HashMap<String, f64> = [("text".to_string(), 1000.0)].into();
+        let ranker = LogisticRanker::train_with(
+            &correction_log_list,
+            100,
+            0.1,
+            &CheckOptions::default(),
+            &frequency_map,
+        );
+        assert!(ranker.weights[TYPO_TYPE_NUM + 1] > 0.0);
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let ranker = LogisticRanker::train(&correction_log_list(), 10, 0.1);
+        let parsed_ranker: LogisticRanker = ranker.to_string().parse().unwrap();
+
+        assert_eq!(ranker, parsed_ranker);
+        assert_eq!(
+            "1 2".parse::<LogisticRanker>(),
+            Err(ParseLogisticRankerError::WrongLength(2))
+        );
+    }
+}
@@ -1,48 +1,131 @@
-use std::cmp::min;
-use std::collections::HashMap;
-use std::str::Chars;
+use std::cmp::{min, Ordering};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 mod dictionary;
-pub use dictionary::get_dictionary;
-use regex::Regex;
-
-struct StringWrapper<'a>(&'a str);
-
-impl<'a, 'b> IntoIterator for &'a StringWrapper<'b> {
-    type Item = char;
-    type IntoIter = Chars<'b>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.chars()
-    }
-}
+mod word_dictionary;
+pub use word_dictionary::{Dictionary, WordMetadata};
+mod advisory;
+pub use advisory::{find_rare_word_advisory, RareWordAdvisory};
+mod ranking;
+pub use ranking::{RankingWeights, TypoTypeWeights};
+mod bloom;
+pub use bloom::{is_probably_word, WordBloomFilter};
+mod cache;
+pub use cache::{options_fingerprint, ResultCache};
+mod checker;
+pub use checker::{check_words, TypoChecker};
+mod classifier;
+pub use classifier::{Classification, TypoClassifier};
+mod cluster;
+pub use cluster::{find_dictionary_near_duplicate_clusters, find_near_duplicate_clusters};
+mod consistency;
+pub mod eval;
+pub use consistency::{
+    find_inconsistent_terms, find_inconsistent_terms_with, spelling_variant_list,
+    ConsistencyFinding,
+};
+mod forbidden;
+pub use forbidden::{find_forbidden_words, ForbiddenWordFinding};
+mod fix;
+pub use fix::{FixDecision, FixLog, FixLogEntry, FixPolicy, FixSkipReason, FixTransaction};
+mod keyboard;
+pub use keyboard::{
+    detect_keyboard_layout, keyboard_levenshtein, DistanceMetric, KeyboardLayout, LayoutDetection,
+};
+mod lsp;
+pub use lsp::{position_at, quick_fix_edits, Position, Range, TextEdit};
+mod matrix;
+pub use matrix::{distance_matrix, distance_matrix_parallel, distance_matrix_with_config};
+mod neighbors;
+pub use neighbors::NeighborIndex;
+mod number;
+pub use number::{correct_number_word, is_number_word};
+pub mod ocr;
+mod page;
+pub use page::{check_paged, suggestion_cursor, SuggestionCursor, SuggestionPage};
+mod parallel;
+pub use parallel::{check_words_parallel, ParallelConfig};
+mod profile;
+pub use profile::{ParseProfileError, Profile};
+mod screen;
+mod shape;
+use screen::Prescreen;
+use shape::is_similar_shape_sequence;
+pub use shape::ShapeProfile;
+mod query;
+pub use query::{
+    correct_query, correct_query_with, correct_query_with_dictionary, QueryCorrection,
+};
+mod redact;
+pub use redact::redact_word;
+mod romaji;
+pub use romaji::{check_romaji_word, normalize_romaji, romaji_levenshtein, romaji_word_list};
+mod german;
+pub use german::{check_german_word, fold_german_umlauts};
+mod french;
+pub use french::{accent_levenshtein, check_french_word, strip_accents};
+mod kana;
+pub use kana::{check_kana_word, kana_keyboard_placement_list, kana_similar_shape_list};
+mod multilingual;
+pub use multilingual::{
+    check_multilingual_word, detect_script, Language, LanguageDictionary, MultilingualCheckResult,
+    Script,
+};
+mod sentence;
+pub use sentence::{suggest_sentence, suggest_sentence_with_frequency, SentenceAlternative};
+mod text;
+pub use text::{check_text, check_text_with, tokenize, tokenize_with, TextToken, TextTypo};
+mod untrusted;
+pub use untrusted::{check_untrusted, check_untrusted_words, ResourceLimits, UntrustedInputError};
+#[cfg(feature = "toml")]
+mod config;
+#[cfg(feature = "toml")]
+pub use config::{ConfigError, ConfigProblem, ConfigResolver, ConfigSource};
+#[cfg(feature = "learning")]
+mod learning;
+#[cfg(feature = "strsim")]
+pub mod strsim;
+#[cfg(any(test, feature = "test_support"))]
+pub mod test_support;
+#[cfg(feature = "learning")]
+pub use learning::{CorrectionLog, LogisticRanker, ParseLogisticRankerError};
 
 /// Struct is used when there are too many or too few characters in the input word
 ///
 /// チェックする単語に文字の過不足があった場合に使用される構造体です
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CharacterPositon {
     /// There is an over/under on the initial letter of the word(単語の頭文字に過不足があります)
     Head,
     /// There is an over/under at the end of a word(単語の末尾の文字に過不足があります)
     Tail,
+    /// There is an over/under in the middle of a word, at the character index counted from 0(単語の途中の、0から数えた文字の位置に過不足があります)
+    Middle(usize),
 }
 
 /// Enum that classifies the type of typo
 ///
 /// タイポの種類を分類する列挙型です
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypoType {
     /// Extra character in the check word(チェックする単語に余分な文字が入っている)
+    ///
+    /// `adjacent_to` is the neighboring character of the word whose key is next to the extra character on the keyboard.(`adjacent_to`はキーボードで余分な文字の隣のキーにある、単語の隣り合う文字)
     ExtraCharacters {
         character: char,
         position: CharacterPositon,
+        #[cfg_attr(feature = "serde", serde(default))]
+        adjacent_to: Option<char>,
     },
     /// Missing character in the check word(チェックする単語に足りない文字がある)
     MissingCharacters {
         character: char,
         position: CharacterPositon,
     },
-    /// The check word and the correct word have a different character in close proximity in the Qwert sequence on the keyboard.(チェックする単語と正しい単語で違う文字がキーボードのQwert配列で近い位置にある)
+    /// The check word and the correct word have a different character in close proximity on the keyboard, in `keyboard_layout` of the options.(チェックする単語と正しい単語で違う文字が、オプションの`keyboard_layout`のキーボードで近い位置にある)
     ///
     /// Ex. a => [q, w, s, x, z]
     CloseKeyboardPlacement,
@@ -50,6 +133,28 @@ pub enum TypoType {
     ///
     /// Ex. o => [a, c, e]
     SimilarShapes,
+    /// The check word is a well-known misspelling of the correct word.(チェックする単語が正しい単語のよくある綴り間違いである)
+    ///
+    /// Ex. teh => the
+    CommonMisspelling,
+    /// The check word was typed with Caps Lock on, so its letter case is inverted.(Caps Lockが有効なまま入力されたため、チェックする単語の大文字小文字が反転している)
+    ///
+    /// Ex. hELLO => Hello
+    CapsLock,
+    /// The check word and the correct word differ only in accents.(チェックする単語と正しい単語でアクセント記号のみが違う)
+    ///
+    /// Ex. eleve => élève
+    MissingAccent,
+    /// Two adjacent characters of the check word are swapped.(チェックする単語の隣り合う2文字が入れ替わっている)
+    ///
+    /// Ex. hte => the
+    Transposition,
+    /// A character of the check word is repeated more times than in the correct word.(チェックする単語の文字が正しい単語より多く繰り返されている)
+    ///
+    /// Ex. helllo => hello
+    RepeatedCharacter { character: char },
+    /// User-defined category returned by a TypoClassifier. Add it to `sort_order_of_typo_type` to rank it, otherwise it is ranked last(TypoClassifierが返すユーザーが定義した分類。`sort_order_of_typo_type`に加えると順位を指定でき、加えない場合は最後になる)
+    Custom(String),
     /// Word that cannot be classified(分類ができない単語)
     UndefinedType,
 }
@@ -72,7 +177,7 @@ pub enum TypoType {
 /// use typo_checker::get_typo_type_name;
 ///
 ///
-/// let typo_type = TypoType::ExtraCharacters{character: 'a', position: CharacterPositon::Head};
+/// let typo_type = TypoType::ExtraCharacters{character: 'a', position: CharacterPositon::Head, adjacent_to: None};
 /// let typo_type_name = get_typo_type_name(&typo_type);
 /// println!("typo_type_name: {:?}", typo_type_name);
 /// ```
@@ -82,10 +187,33 @@ pub fn get_typo_type_name(typo_type: &TypoType) -> String {
         TypoType::MissingCharacters { .. } => "MissingCharacters".to_string(),
         TypoType::CloseKeyboardPlacement => "CloseKeyboardPlacement".to_string(),
         TypoType::SimilarShapes => "SimilarShapes".to_string(),
+        TypoType::CommonMisspelling => "CommonMisspelling".to_string(),
+        TypoType::CapsLock => "CapsLock".to_string(),
+        TypoType::MissingAccent => "MissingAccent".to_string(),
+        TypoType::Transposition => "Transposition".to_string(),
+        TypoType::RepeatedCharacter { .. } => "RepeatedCharacter".to_string(),
+        TypoType::Custom(name) => name.clone(),
         TypoType::UndefinedType => "UndefinedType".to_string(),
     }
 }
 
+/// Enum of the passes that produce a suggestion
+///
+/// 候補を見つけた処理の列挙型です
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SuggestionSource {
+    /// Fuzzy search of the dictionary by Levenshtein distance(レーベンシュタイン距離による辞書のあいまい検索)
+    FuzzySearch,
+    /// List of commonly misspelled words(よくある綴り間違いのリスト)
+    CommonMisspellings,
+    /// Word list given by the user, such as kana, German and French word lists(かな・ドイツ語・フランス語の単語リストなど、ユーザーが指定した単語リスト)
+    PersonalDictionary,
+    /// Split of a long check word into two dictionary words(長いチェックする単語の2つの辞書の単語への分割)
+    WordSplit,
+}
+
 /// Struct that stores information about similar word
 ///
 /// 似ている単語の情報を格納する構造体です
@@ -95,11 +223,13 @@ pub fn get_typo_type_name(typo_type: &TypoType) -> String {
 /// * `spelling` - Spelling of similar words(似ている単語のスペル)
 /// * `levenshtein_length` - Levenshtein Distance(レーベンシュタイン距離)
 /// * `typo_type` - Type of typo(タイポの種類)
-#[derive(Debug, Clone)]
+/// * `source` - Pass that found the word(単語を見つけた処理)
+#[derive(Debug, Clone, PartialEq)]
 pub struct SimilarWord {
     spelling: String,
     levenshtein_length: usize,
     typo_type: TypoType,
+    source: SuggestionSource,
 }
 
 impl SimilarWord {
@@ -108,12 +238,50 @@ impl SimilarWord {
             spelling,
             levenshtein_length,
             typo_type: TypoType::UndefinedType,
+            source: SuggestionSource::FuzzySearch,
         }
     }
 
+    /// Returns the spelling of the word(単語のスペルを返します)
+    pub fn get_spelling(&self) -> &str {
+        &self.spelling
+    }
+
+    /// Returns the Levenshtein distance from the check word(チェックする単語とのレーベンシュタイン距離を返します)
+    pub fn get_levenshtein_length(&self) -> usize {
+        self.levenshtein_length
+    }
+
+    /// Returns the type of typo(タイポの種類を返します)
+    pub fn get_typo_type(&self) -> &TypoType {
+        &self.typo_type
+    }
+
+    /// Returns the pass that found the word(単語を見つけた処理を返します)
+    pub fn get_source(&self) -> SuggestionSource {
+        self.source
+    }
+
+    /// 単語を見つけた処理を設定する
+    pub(crate) fn with_source(mut self, source: SuggestionSource) -> SimilarWord {
+        self.source = source;
+        self
+    }
+
+    /// 隣のキーを押した余分な文字かどうか
+    pub(crate) fn is_adjacent_extra_character(&self) -> bool {
+        matches!(
+            self.typo_type,
+            TypoType::ExtraCharacters {
+                adjacent_to: Some(_),
+                ..
+            }
+        )
+    }
+
     fn sort_by_typo_type(
-        similar_word_list: &mut Vec<SimilarWord>,
-        sort_typo_type_setting: &Vec<TypoType>,
+        similar_word_list: &mut [SimilarWord],
+        sort_typo_type_setting: &[TypoType],
     ) {
         let typo_type_order: HashMap<String, usize> = sort_typo_type_setting
             .iter()
@@ -121,34 +289,189 @@ impl SimilarWord {
             .map(|(i, typo_type)| (get_typo_type_name(typo_type), i))
             .collect();
 
+        // ソート条件に含まれないTypoTypeは末尾に並べる
         similar_word_list.sort_by(|a, b| {
             let a_order = typo_type_order
                 .get(&get_typo_type_name(&a.typo_type))
-                .unwrap();
+                .unwrap_or(&usize::MAX);
             let b_order = typo_type_order
                 .get(&get_typo_type_name(&b.typo_type))
-                .unwrap();
-            a_order.cmp(b_order)
+                .unwrap_or(&usize::MAX);
+            // 同じTypoTypeの中では隣のキーを押した余分な文字を上位にする
+            a_order.cmp(b_order).then_with(|| {
+                b.is_adjacent_extra_character()
+                    .cmp(&a.is_adjacent_extra_character())
+            })
         });
     }
 }
 
+/// Struct that stores the telemetry of a search for tuning and debugging the options
+///
+/// オプションの調整やデバッグのために、検索の計測値を格納する構造体です
+///
+/// # Arguments
+///
+/// * `scanned_word_num` - Number of words compared with the check word(チェックする単語と比較した単語数)
+/// * `passed_cutoff_num` - Number of words that passed the cutoff and the length filter(カットオフ値と文字数の絞り込みを通過した単語数)
+/// * `screened_out_num` - Number of scanned words excluded by the prescreen before the exact distance is calculated(正確な距離を計算する前の絞り込みで除外した単語数)
+/// * `pass_list` - Passes that ran in order(実行した処理を実行順に並べたもの)
+/// * `stage_timings` - Time spent in each stage, recorded only by `check_with_timings`(段階ごとの所要時間。`check_with_timings`の場合のみ記録します)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchTelemetry {
+    pub scanned_word_num: usize,
+    pub screened_out_num: usize,
+    pub passed_cutoff_num: usize,
+    pub pass_list: Vec<SuggestionSource>,
+    pub stage_timings: Option<StageTimings>,
+}
+
+impl SearchTelemetry {
+    /// 時間を計測する場合、段階の開始時刻を返す
+    fn start_stage(&self) -> Option<Instant> {
+        self.stage_timings.as_ref().map(|_| Instant::now())
+    }
+
+    /// 時間を計測する場合、段階の開始時刻からの経過時間を加える
+    fn end_stage(
+        &mut self,
+        started_at: Option<Instant>,
+        stage: impl FnOnce(&mut StageTimings) -> &mut Duration,
+    ) {
+        if let (Some(stage_timings), Some(started_at)) = (self.stage_timings.as_mut(), started_at) {
+            *stage(stage_timings) += started_at.elapsed();
+        }
+    }
+}
+
+/// Struct that stores the time spent in each stage of a check for comparing the search backends on a dictionary
+///
+/// 辞書ごとに探索の方式を比較するために、チェックの段階ごとの所要時間を格納する構造体です
+///
+/// # Arguments
+///
+/// * `candidate_generation` - Time to select the candidate words from the dictionary(辞書から候補の単語を選ぶ時間)
+/// * `distance` - Time to calculate the distances of the candidate words(候補の単語の距離を計算する時間)
+/// * `classification` - Time to classify the TypoType of the similar words(似ている単語のTypoTypeを判別する時間)
+/// * `sort` - Time to filter, sort and pick up the similar words(似ている単語を絞り込み、ソートし、取り出す時間)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageTimings {
+    pub candidate_generation: Duration,
+    pub distance: Duration,
+    pub classification: Duration,
+    pub sort: Duration,
+}
+
+impl StageTimings {
+    /// Returns the total time of the stages(各段階の合計時間を返します)
+    pub fn total(&self) -> Duration {
+        self.candidate_generation + self.distance + self.classification + self.sort
+    }
+}
+
 /// Struct to store typo search results.
 ///
 /// タイポの検索結果を格納する構造体です
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TypoCheckResult {
+    /// `check_word` - Stores the checked word in lowercase(チェックした単語を小文字で格納します)
+    check_word: String,
     /// `match_word` - Stores the exact match(完全一致した単語を格納します)
     match_word: Option<String>,
     /// `similar_word_list` - Stores information on similar words in an array(似ている単語の情報を配列で格納します)
     similar_word_list: Option<Vec<SimilarWord>>,
+    /// `telemetry` - Stores the telemetry of the search(検索の計測値を格納します)
+    telemetry: SearchTelemetry,
+    /// `match_metadata` - Stores the metadata of the exact match in the dictionary(完全一致した単語の辞書のメタデータを格納します)
+    match_metadata: Option<WordMetadata>,
+    /// `abbreviation_expansion` - Stores the expansion when the checked word is an abbreviation of `abbreviations`(チェックした単語が`abbreviations`の略語の場合に、その展開形を格納します)
+    abbreviation_expansion: Option<String>,
+    /// `caps_lock_inversion` - Stores the case-flipped spelling when the checked word looks typed with Caps Lock on(チェックした単語がCaps Lockが有効なまま入力されたように見える場合に、大文字小文字を反転した綴りを格納します)
+    caps_lock_inversion: Option<String>,
 }
 
 impl TypoCheckResult {
-    fn new() -> TypoCheckResult {
+    fn new(check_word: String) -> TypoCheckResult {
         TypoCheckResult {
+            check_word,
             match_word: None,
             similar_word_list: None,
+            telemetry: SearchTelemetry::default(),
+            match_metadata: None,
+            abbreviation_expansion: None,
+            caps_lock_inversion: None,
+        }
+    }
+
+    /// Returns the telemetry of the search such as the number of scanned words(比較した単語数などの検索の計測値を返します)
+    pub fn get_telemetry(&self) -> &SearchTelemetry {
+        &self.telemetry
+    }
+
+    /// Returns true if the word matches the dictionary(単語が辞書に一致した場合にtrueを返します)
+    pub fn is_match(&self) -> bool {
+        self.match_word.is_some()
+    }
+
+    /// Returns the metadata of the exact match, such as a proper noun or a region, if the dictionary has it(辞書にある場合、固有名詞や地域などの完全一致した単語のメタデータを返します)
+    pub fn get_match_metadata(&self) -> Option<&WordMetadata> {
+        self.match_metadata.as_ref()
+    }
+
+    /// Returns the expansion of the abbreviation, such as "repository" for "repo", as an informational suggestion(「repo」に対する「repository」のような略語の展開形を、情報としての候補で返します)
+    pub fn get_abbreviation_expansion(&self) -> Option<&str> {
+        self.abbreviation_expansion.as_deref()
+    }
+
+    /// Returns the case-flipped spelling, such as "workign" for "WORKign", when the word looks typed with Caps Lock on.
+    /// It is returned even if the flipped word has another typo, and then the similar words keep the TypoType of that typo.
+    ///
+    /// 「WORKign」に対する「workign」のように、単語がCaps Lockが有効なまま入力されたように見える場合に、大文字小文字を反転した綴りを返します。
+    /// 反転した単語に別のタイポがある場合も返し、そのとき似ている単語はそのタイポのTypoTypeのままです。
+    pub fn get_caps_lock_inversion(&self) -> Option<&str> {
+        self.caps_lock_inversion.as_deref()
+    }
+
+    /// Returns the result with every word replaced by `redact_word`, for logging the findings and the telemetry of sensitive documents.
+    /// The checked word, the match, the similar words, the abbreviation expansion and the Caps Lock inversion are redacted, and the telemetry is kept.
+    ///
+    /// 機密の文書の指摘と計測値を記録するために、全ての単語を`redact_word`で置き換えた結果を返します。
+    /// チェックした単語、一致した単語、似ている単語、略語の展開形、Caps Lockの反転した綴りを置き換え、計測値はそのまま残します。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Secret key of the hash(ハッシュの秘密のキー)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{check_a_word_with, redact_word, CheckOptions};
+    ///
+    /// let typo_chec_result = check_a_word_with("Jhon", &CheckOptions::default()).redacted("secret");
+    /// assert!(!format!("{:?}", typo_chec_result).contains("jhon"));
+    /// assert!(typo_chec_result
+    ///     .get_similar_word_list()
+    ///     .iter()
+    ///     .all(|similar_word| similar_word.get_spelling().starts_with("redacted:")));
+    /// println!("{:?}", typo_chec_result);
+    /// ```
+    pub fn redacted(&self, key: &str) -> TypoCheckResult {
+        let redact = |word: &String| redact_word(word, key);
+        TypoCheckResult {
+            check_word: redact(&self.check_word),
+            match_word: self.match_word.as_ref().map(redact),
+            similar_word_list: self.similar_word_list.as_ref().map(|similar_word_list| {
+                similar_word_list
+                    .iter()
+                    .map(|similar_word| SimilarWord {
+                        spelling: redact(&similar_word.spelling),
+                        ..similar_word.clone()
+                    })
+                    .collect()
+            }),
+            abbreviation_expansion: self.abbreviation_expansion.as_ref().map(redact),
+            caps_lock_inversion: self.caps_lock_inversion.as_ref().map(redact),
+            ..self.clone()
         }
     }
 
@@ -167,18 +490,239 @@ impl TypoCheckResult {
             Vec::new() // エラーメッセージの代わりに空のVecを返す
         }
     }
+
+    /// Returns a human-readable "did you mean" message built from the top `limit` similar words.
+    /// Returns None when there is an exact match or no similar word.
+    ///
+    /// 上位`limit`個の似ている単語から「did you mean」形式の文章を返します。
+    /// 完全一致した単語がある場合や似ている単語がない場合はNoneを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of similar words to include in the message(文章に含める似ている単語の最大数)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let typo_chec_result = typo_checker::check_a_word_with("applo", &typo_checker::CheckOptions::default());
+    /// println!("{}", typo_chec_result.did_you_mean(3).unwrap());
+    /// ```
+    pub fn did_you_mean(&self, limit: usize) -> Option<String> {
+        if self.match_word.is_some() {
+            return None;
+        }
+
+        let quoted_word_list: Vec<String> = self
+            .similar_word_list
+            .as_ref()?
+            .iter()
+            .take(limit)
+            .map(|word| format!("\"{}\"", word.spelling))
+            .collect();
+
+        match quoted_word_list.split_last() {
+            None => None,
+            Some((last, [])) => Some(format!("did you mean {}?", last)),
+            Some((last, rest)) => Some(format!("did you mean {} or {}?", rest.join(", "), last)),
+        }
+    }
+
+    /// Returns a chainable filter over the similar words.
+    ///
+    /// 似ている単語を絞り込むためのチェーン可能なフィルタを返します。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::TypoType;
+    ///
+    /// let typo_chec_result = typo_checker::check_a_word_with("typ", &typo_checker::CheckOptions::default());
+    /// let close_word_list = typo_chec_result
+    ///     .filter()
+    ///     .types([TypoType::CloseKeyboardPlacement])
+    ///     .max_distance(1)
+    ///     .collect();
+    /// println!("close_word_list: {:?}", close_word_list);
+    /// ```
+    pub fn filter(&self) -> SimilarWordFilter<'_> {
+        SimilarWordFilter {
+            check_word_length: self.check_word.chars().count(),
+            similar_word_list: self.similar_word_list.as_deref().unwrap_or(&[]),
+            typo_type_name_list: None,
+            max_distance: None,
+            max_length_delta: None,
+        }
+    }
+
+    /// Returns a stable table of the result for snapshot tests.
+    /// The similar words are listed in the order of the result with aligned columns of suggestion, distance, type and score.
+    /// The score is calculated with the default RankingWeights.
+    ///
+    /// スナップショットテスト向けに、結果を安定した表の文字列で返します。
+    /// 似ている単語は結果の順序のまま、候補・距離・種類・スコアの列を揃えて並べます。
+    /// スコアはデフォルトのRankingWeightsで計算します。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let typo_chec_result = typo_checker::check_a_word_with("applo", &typo_checker::CheckOptions::default());
+    /// println!("{}", typo_chec_result.to_debug_table());
+    /// ```
+    pub fn to_debug_table(&self) -> String {
+        let ranking_weights = RankingWeights::default();
+        let mut row_list: Vec<[String; 5]> = vec![[
+            "rank".to_string(),
+            "suggestion".to_string(),
+            "distance".to_string(),
+            "type".to_string(),
+            "score".to_string(),
+        ]];
+        for (i, word) in self
+            .similar_word_list
+            .as_deref()
+            .unwrap_or(&[])
+            .iter()
+            .enumerate()
+        {
+            row_list.push([
+                (i + 1).to_string(),
+                word.spelling.clone(),
+                word.levenshtein_length.to_string(),
+                get_typo_type_name(&word.typo_type),
+                format!("{:.2}", ranking_weights.score(&self.check_word, word, 0.0)),
+            ]);
+        }
+
+        // 各列の幅は全角文字も1文字として数える
+        let mut width_list = [0; 5];
+        for row in &row_list {
+            for (width, cell) in width_list.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut table = format!(
+            "check_word: {}\nmatch_word: {}\n",
+            self.check_word,
+            self.match_word.as_deref().unwrap_or("-")
+        );
+        for row in &row_list {
+            let line: Vec<String> = row
+                .iter()
+                .zip(width_list)
+                .map(|(cell, width)| {
+                    format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+                })
+                .collect();
+            table.push_str(line.join("  ").trim_end());
+            table.push('\n');
+        }
+        table
+    }
+}
+
+/// Struct to filter the similar words in TypoCheckResult by typo type, distance and length delta.
+///
+/// TypoCheckResultの似ている単語をタイポの種類、距離、文字数の差で絞り込む構造体です
+pub struct SimilarWordFilter<'a> {
+    check_word_length: usize,
+    similar_word_list: &'a [SimilarWord],
+    typo_type_name_list: Option<Vec<String>>,
+    max_distance: Option<usize>,
+    max_length_delta: Option<usize>,
+}
+
+impl SimilarWordFilter<'_> {
+    /// Keeps only the words classified into one of the given TypoTypes.
+    /// The fields of ExtraCharacters and MissingCharacters are ignored.
+    ///
+    /// 指定したTypoTypeのいずれかに分類された単語のみを残します。
+    /// ExtraCharactersとMissingCharactersの構造体の中身は無視されます。
+    pub fn types<I: IntoIterator<Item = TypoType>>(mut self, typo_types: I) -> Self {
+        self.typo_type_name_list = Some(
+            typo_types
+                .into_iter()
+                .map(|typo_type| get_typo_type_name(&typo_type))
+                .collect(),
+        );
+        self
+    }
+
+    /// Keeps only the words whose Levenshtein distance is less than or equal to `max_distance`.
+    ///
+    /// レーベンシュタイン距離が`max_distance`以下の単語のみを残します。
+    pub fn max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = Some(max_distance);
+        self
+    }
+
+    /// Keeps only the words whose length differs from the check word by at most `max_length_delta`.
+    ///
+    /// チェックする単語との文字数の差が`max_length_delta`以下の単語のみを残します。
+    pub fn max_length_delta(mut self, max_length_delta: usize) -> Self {
+        self.max_length_delta = Some(max_length_delta);
+        self
+    }
+
+    /// Returns the similar words that pass all the filters, keeping their order.
+    ///
+    /// すべてのフィルタを通過した似ている単語を順序を保ったまま返します。
+    pub fn collect(self) -> Vec<SimilarWord> {
+        self.similar_word_list
+            .iter()
+            .filter(|word| match &self.typo_type_name_list {
+                Some(name_list) => name_list.contains(&get_typo_type_name(&word.typo_type)),
+                None => true,
+            })
+            .filter(|word| match self.max_distance {
+                Some(max_distance) => word.levenshtein_length <= max_distance,
+                None => true,
+            })
+            .filter(|word| match self.max_length_delta {
+                Some(max_length_delta) => {
+                    word.spelling
+                        .chars()
+                        .count()
+                        .abs_diff(self.check_word_length)
+                        <= max_length_delta
+                }
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
 }
 
-/// Calculate the Levenshtein distance
+/// Calculate the Levenshtein distance between two sequences of arbitrary elements.
+/// The sequence `b` is iterated several times, so its iterator must be cloneable.
+///
+/// 任意の要素の列同士のレーベンシュタイン距離を計算します。
+/// 列`b`は複数回走査するため、そのイテレータはClone可能である必要があります。
+///
+/// # Arguments
+///
+/// * `a` - Sequence A to be compared(比較対象の列A)
+/// * `b` - Sequence B to be compared(比較対象の列B)
+///
+/// # Examples
 ///
-/// レーベンシュタイン距離を計算します
-fn generic_levenshtein<'a, 'b, Iter1, Iter2, Elem1, Elem2>(a: &'a Iter1, b: &'b Iter2) -> usize
+/// ```
+/// use typo_checker::generic_levenshtein;
+///
+/// let sentence_a = ["I", "ate", "a", "pizza"];
+/// let sentence_b = ["I", "ate", "the", "pizza"];
+/// assert_eq!(1, generic_levenshtein(&sentence_a, &sentence_b));
+/// assert_eq!(3, generic_levenshtein("kitten".chars(), "sitting".chars()));
+/// ```
+pub fn generic_levenshtein<A, B>(a: A, b: B) -> usize
 where
-    &'a Iter1: IntoIterator<Item = Elem1>,
-    &'b Iter2: IntoIterator<Item = Elem2>,
-    Elem1: PartialEq<Elem2>,
+    A: IntoIterator,
+    B: IntoIterator,
+    B::IntoIter: Clone,
+    A::Item: PartialEq<B::Item>,
 {
-    let b_len = b.into_iter().count();
+    let b = b.into_iter();
+    let b_len = b.clone().count();
 
     let mut cache: Vec<usize> = (1..b_len + 1).collect();
 
@@ -188,7 +732,7 @@ where
         result = i + 1;
         let mut distance_b = i;
 
-        for (j, b_elem) in b.into_iter().enumerate() {
+        for (j, b_elem) in b.clone().enumerate() {
             let cost = usize::from(a_elem != b_elem);
             let distance_a = distance_b + cost;
             distance_b = cache[j];
@@ -217,22 +761,24 @@ where
 /// assert_eq!(3, levenshtein("kitten", "sitting"));
 /// ```
 pub fn levenshtein(a: &str, b: &str) -> usize {
-    generic_levenshtein(&StringWrapper(a), &StringWrapper(b))
+    generic_levenshtein(a.chars(), b.chars())
 }
 
 fn calculate_word_list_levenshtein_length(
-    word_list: &[[Option<&str>; 5416]],
-    check_word: &String,
+    dictionary: &Dictionary,
+    word_length_range: std::ops::Range<usize>,
+    check_word: &str,
     mut similar_word_list: Vec<SimilarWord>,
+    prescreen: Option<&Prescreen>,
+    telemetry: &mut SearchTelemetry,
 ) -> Vec<SimilarWord> {
-    for temp_same_length_word_list in word_list.iter() {
-        for temp_word in temp_same_length_word_list.iter() {
-            match temp_word {
-                Some(word) => {
-                    let levenshtein_length = levenshtein(&check_word, &word);
-                    similar_word_list.push(SimilarWord::new(word.to_string(), levenshtein_length));
-                }
-                None => break,
+    for word_length in word_length_range {
+        for word in dictionary.words_with_length(word_length) {
+            if prescreen.is_some_and(|prescreen| !prescreen.passes(word)) {
+                telemetry.screened_out_num += 1;
+            } else {
+                let levenshtein_length = levenshtein(check_word, word);
+                similar_word_list.push(SimilarWord::new(word.to_string(), levenshtein_length));
             }
         }
     }
@@ -240,8 +786,10 @@ fn calculate_word_list_levenshtein_length(
 }
 
 /// When the check word is compared to the correct word, if there are excesses or deficiencies before or after the word, the typo_type of similar_word is changed to ExtraCharacters or MissingCharacters.
+/// An excess or deficiency of one character in the middle of the word is stored with `CharacterPositon::Middle`.
 ///
 /// チェックする単語を正しい単語と比較したときに、単語の前後に過不足があればsimilar_wordのtypo_typeをExtraCharactersかMissingCharactersに変更します。
+/// 単語の途中の1文字の過不足は`CharacterPositon::Middle`で格納します。
 ///
 /// # Arguments
 ///
@@ -259,56 +807,161 @@ fn calculate_word_list_levenshtein_length(
 /// let return_word = find_missing_or_extra_chars(check_word, similar_word);
 /// println!("return_word: {:?}", return_word);
 /// ```
-pub fn find_missing_or_extra_chars(check_word: &str, mut similar_word: SimilarWord) -> SimilarWord {
+pub fn find_missing_or_extra_chars(check_word: &str, similar_word: SimilarWord) -> SimilarWord {
+    find_missing_or_extra_chars_with(check_word, similar_word, &close_keyboard_placement_list())
+}
+
+/// Same as `find_missing_or_extra_chars`, but uses the given keyboard placement to fill `adjacent_to` of ExtraCharacters.
+///
+/// `find_missing_or_extra_chars`と同じですが、ExtraCharactersの`adjacent_to`を指定したキーボード配置で判定します。
+///
+/// # Arguments
+///
+/// * `check_word` - The check word(チェックする単語)
+/// * `similar_word` - SimilarWord type storing the correct word(正しい単語を格納したSimilarWord型)
+/// * `close_keyboard_placement` - Hashmap of characters and their nearby keys(文字と近くにあるキーのハッシュマップ)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{close_keyboard_placement_list, find_missing_or_extra_chars_with, SimilarWord};
+///
+/// let similar_word = SimilarWord::new("apple".to_string(), 1);
+/// let return_word = find_missing_or_extra_chars_with("applew", similar_word, &close_keyboard_placement_list());
+/// println!("return_word: {:?}", return_word);
+/// ```
+pub fn find_missing_or_extra_chars_with(
+    check_word: &str,
+    mut similar_word: SimilarWord,
+    close_keyboard_placement: &HashMap<char, Vec<char>>,
+) -> SimilarWord {
     let check_len = check_word.chars().count();
     let similar_len = similar_word.spelling.chars().count();
 
+    // 余分な文字の隣のキーにある文字を返す
+    let find_adjacent = |extra_char: char, neighbor_char: Option<char>| {
+        neighbor_char.filter(|neighbor_char| {
+            close_keyboard_placement
+                .get(neighbor_char)
+                .is_some_and(|close_char_list| close_char_list.contains(&extra_char))
+        })
+    };
+
     if similar_len < check_len {
         // similar_wordが短い場合、check_wordに入っている余分な文字を探す
-        let re_prefix =
-            Regex::new(&format!(r"^{}(.+)", regex::escape(&similar_word.spelling))).unwrap();
-        let re_suffix =
-            Regex::new(&format!(r"(.+){}$", regex::escape(&similar_word.spelling))).unwrap();
-
-        if let Some(captures) = re_prefix.captures(check_word) {
-            let missing_prefix = captures.get(1).unwrap().as_str();
+        let extra_suffix = check_word
+            .strip_prefix(similar_word.spelling.as_str())
+            .filter(|extra_suffix| !extra_suffix.is_empty());
+        let extra_prefix = check_word
+            .strip_suffix(similar_word.spelling.as_str())
+            .filter(|extra_prefix| !extra_prefix.is_empty());
+
+        if let Some(extra_suffix) = extra_suffix {
+            let character = extra_suffix.chars().next().unwrap();
             similar_word.typo_type = TypoType::ExtraCharacters {
-                character: missing_prefix.chars().next().unwrap(),
+                character,
                 position: CharacterPositon::Tail,
+                // 末尾の余分な文字は直前の文字と比べる
+                adjacent_to: find_adjacent(character, similar_word.spelling.chars().last()),
             };
         }
 
-        if let Some(captures) = re_suffix.captures(check_word) {
-            let missing_prefix = captures.get(1).unwrap().as_str();
+        if let Some(extra_prefix) = extra_prefix {
+            let character = extra_prefix.chars().next().unwrap();
             similar_word.typo_type = TypoType::ExtraCharacters {
-                character: missing_prefix.chars().next().unwrap(),
+                character,
                 position: CharacterPositon::Head,
+                // 先頭の余分な文字は直後の文字と比べる
+                adjacent_to: find_adjacent(character, similar_word.spelling.chars().next()),
             };
         }
+
+        // 先頭と末尾に無い場合、途中の余分な文字を探す
+        if similar_word.typo_type == TypoType::UndefinedType {
+            if let Some(index) = find_middle_char_index(check_word, &similar_word.spelling) {
+                let check_char_list: Vec<char> = check_word.chars().collect();
+                let character = check_char_list[index];
+                similar_word.typo_type = TypoType::ExtraCharacters {
+                    character,
+                    position: CharacterPositon::Middle(index),
+                    // 途中の余分な文字は前後の文字と比べる
+                    adjacent_to: find_adjacent(character, Some(check_char_list[index - 1]))
+                        .or_else(|| find_adjacent(character, Some(check_char_list[index + 1]))),
+                };
+            }
+        }
     } else {
         // similar_wordが長い場合、check_wordに足りない文字を探す
-        let re_prefix = Regex::new(&format!(r"^(.+){}", regex::escape(check_word))).unwrap();
-        let re_suffix = Regex::new(&format!(r"{}(.+)$", regex::escape(check_word))).unwrap();
+        let spelling = similar_word.spelling.as_str();
+        let char_boundary_list: Vec<usize> = spelling
+            .char_indices()
+            .map(|(index, _)| index)
+            .chain([spelling.len()])
+            .collect();
 
-        if let Some(captures) = re_prefix.captures(&similar_word.spelling) {
-            let extra_prefix = captures.get(1).unwrap().as_str();
+        // 先頭以外の位置にcheck_wordが現れる場合、先頭の文字が足りない
+        if char_boundary_list
+            .iter()
+            .any(|index| *index > 0 && spelling[*index..].starts_with(check_word))
+        {
             similar_word.typo_type = TypoType::MissingCharacters {
-                character: extra_prefix.chars().next().unwrap(),
+                character: spelling.chars().next().unwrap(),
                 position: CharacterPositon::Head,
             };
         }
 
-        if let Some(captures) = re_suffix.captures(&similar_word.spelling) {
-            let extra_suffix = captures.get(1).unwrap().as_str();
+        // 最初に現れるcheck_wordの後に文字がある場合、その文字が足りない
+        if let Some(missing_char) = char_boundary_list
+            .iter()
+            .find(|index| {
+                spelling[**index..].starts_with(check_word)
+                    && spelling.len() > **index + check_word.len()
+            })
+            .and_then(|index| spelling[index + check_word.len()..].chars().next())
+        {
             similar_word.typo_type = TypoType::MissingCharacters {
-                character: extra_suffix.chars().next().unwrap(),
+                character: missing_char,
                 position: CharacterPositon::Tail,
             };
         }
+
+        // 先頭と末尾に無い場合、途中の足りない文字を探す
+        if similar_word.typo_type == TypoType::UndefinedType {
+            if let Some(index) = find_middle_char_index(spelling, check_word) {
+                similar_word.typo_type = TypoType::MissingCharacters {
+                    character: spelling.chars().nth(index).unwrap(),
+                    position: CharacterPositon::Middle(index),
+                };
+            }
+        }
     }
     similar_word
 }
 
+/// 長い単語の先頭と末尾以外の1文字を除くと短い単語になる場合に、最初のその文字の位置を返す
+fn find_middle_char_index(long_word: &str, short_word: &str) -> Option<usize> {
+    let long_char_list: Vec<char> = long_word.chars().collect();
+    let short_char_list: Vec<char> = short_word.chars().collect();
+    if long_char_list.len() != short_char_list.len() + 1 || long_char_list.len() < 3 {
+        return None;
+    }
+    (1..long_char_list.len() - 1).find(|index| {
+        long_char_list[..*index] == short_char_list[..*index]
+            && long_char_list[index + 1..] == short_char_list[*index..]
+    })
+}
+
+/// Returns the built-in dictionary as the raw array of the words grouped by length, starting from 2 characters and padded with None.
+///
+/// 組み込み辞書を、2文字から始まる文字数ごとにまとめてNoneで埋めた単語の配列で返します。
+#[deprecated(
+    since = "2.0.0",
+    note = "use `Dictionary::builtin` and its query methods such as `words_with_length` instead"
+)]
+pub fn get_dictionary() -> [[Option<&'static str>; 5416]; 20] {
+    dictionary::DICTIONARY
+}
+
 /// Returns a hashmap of adjacent alphabets on a Qwert array keyboard.
 ///
 /// Qwert配列のキーボードで隣接している単語のハッシュマップを返します。
@@ -359,6 +1012,107 @@ pub fn close_keyboard_placement_list() -> HashMap<char, Vec<char>> {
     output_hashmap
 }
 
+/// Returns the case-flipped spelling when the check word looks typed with Caps Lock on.
+/// A word of a lowercase letter followed by uppercase letters (hELLO => Hello) and a word of uppercase letters followed by lowercase letters (WORKing => working) are detected.
+///
+/// チェックする単語がCaps Lockが有効なまま入力されたように見える場合、大文字小文字を反転した綴りを返します。
+/// 小文字1文字の後に大文字が続く単語(hELLO => Hello)と、大文字の後に小文字が続く単語(WORKing => working)を検出します。
+///
+/// # Arguments
+///
+/// * `check_word` - The check word(チェックする単語)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::find_caps_lock_inversion;
+///
+/// assert_eq!(find_caps_lock_inversion("hELLO"), Some("Hello".to_string()));
+/// assert_eq!(find_caps_lock_inversion("Hello"), None);
+/// ```
+pub fn find_caps_lock_inversion(check_word: &str) -> Option<String> {
+    let char_list: Vec<char> = check_word.chars().collect();
+    if char_list.len() < 3 || !char_list.iter().all(|c| c.is_alphabetic()) {
+        return None;
+    }
+
+    let upper_start = char_list.iter().position(|c| c.is_uppercase())?;
+    let lower_start = char_list.iter().position(|c| c.is_lowercase())?;
+
+    if upper_start == 1 && char_list[1..].iter().all(|c| c.is_uppercase()) {
+        // 小文字1文字の後に大文字が続く場合、全ての文字を反転する
+        Some(
+            char_list
+                .iter()
+                .map(|c| {
+                    if c.is_uppercase() {
+                        c.to_lowercase().to_string()
+                    } else {
+                        c.to_uppercase().to_string()
+                    }
+                })
+                .collect(),
+        )
+    } else if upper_start == 0
+        && lower_start >= 2
+        && char_list.len() - lower_start >= 2
+        && char_list[lower_start..].iter().all(|c| c.is_lowercase())
+    {
+        // 大文字の後に小文字が続く場合、小文字に揃える
+        Some(check_word.to_lowercase())
+    } else {
+        None
+    }
+}
+
+/// Returns a hashmap of well-known misspellings and their correct words.
+///
+/// よくある綴り間違いと正しい単語のハッシュマップを返します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::common_misspelling_list;
+///
+/// let common_misspelling_map = common_misspelling_list();
+/// assert_eq!(common_misspelling_map.get("teh"), Some(&"the"));
+/// ```
+pub fn common_misspelling_list() -> HashMap<&'static str, &'static str> {
+    common_misspelling_map().clone()
+}
+
+/// よくある綴り間違いのハッシュマップを最初の呼び出しで一度だけ作って返す
+fn common_misspelling_map() -> &'static HashMap<&'static str, &'static str> {
+    static COMMON_MISSPELLING_MAP: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    COMMON_MISSPELLING_MAP.get_or_init(|| {
+        include_str!("common_misspellings.txt")
+            .lines()
+            .filter_map(|line| line.split_once("->"))
+            .collect()
+    })
+}
+
+/// Returns a hashmap of common abbreviations and their expansions, which can be set to `abbreviations` of CheckOptions.
+///
+/// CheckOptionsの`abbreviations`に設定できる、よく使われる略語とその展開形のハッシュマップを返します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{abbreviation_list, check_a_word_with, CheckOptions};
+///
+/// let options = CheckOptions::default().abbreviations(abbreviation_list());
+/// let typo_chec_result = check_a_word_with("approx.", &options);
+/// assert!(typo_chec_result.is_match());
+/// assert_eq!(typo_chec_result.get_abbreviation_expansion(), Some("approximately"));
+/// ```
+pub fn abbreviation_list() -> HashMap<&'static str, &'static str> {
+    include_str!("abbreviations.txt")
+        .lines()
+        .filter_map(|line| line.split_once("->"))
+        .collect()
+}
+
 /// Returns an array of groups of alphabets that are similar in shape.
 /// Alphabets in the same array are considered “similar in shape”.
 ///
@@ -379,17 +1133,15 @@ pub fn close_keyboard_placement_list() -> HashMap<char, Vec<char>> {
 /// println!("similar_group: {:?}", similar_group);
 /// ```
 pub fn similar_shape_list() -> Vec<Vec<char>> {
-    let mut output_vec: Vec<Vec<char>> = Vec::new();
-
-    output_vec.push(vec!['a', 'c', 'e', 'o']);
-    output_vec.push(vec!['b', 'd']);
-    output_vec.push(vec!['f', 'l']);
-    output_vec.push(vec!['g', 'q']);
-    output_vec.push(vec!['m', 'n']);
-    output_vec.push(vec!['p', 'q']);
-    output_vec.push(vec!['u', 'v']);
-
-    output_vec
+    vec![
+        vec!['a', 'c', 'e', 'o'],
+        vec!['b', 'd'],
+        vec!['f', 'l'],
+        vec!['g', 'q'],
+        vec!['m', 'n'],
+        vec!['p', 'q'],
+        vec!['u', 'v'],
+    ]
 }
 
 /// Change the typo_type of similar_word to SimilarShapes or CloseKeyboardPlacement when one different character has a similar shape for the same string of characters.
@@ -414,80 +1166,754 @@ pub fn similar_shape_list() -> Vec<Vec<char>> {
 /// let return_word = find_different_a_char(check_word, temp_word);
 /// println!("return_word: {:?}", return_word);
 /// ```
-pub fn find_different_a_char(check_word: &str, mut temp_word: SimilarWord) -> SimilarWord {
-    let similar_shape = similar_shape_list();
-    let close_keyboard_placement = close_keyboard_placement_list();
+pub fn find_different_a_char(check_word: &str, temp_word: SimilarWord) -> SimilarWord {
+    find_different_a_char_with(
+        check_word,
+        temp_word,
+        &similar_shape_list(),
+        &close_keyboard_placement_list(),
+    )
+}
+
+/// Same as `find_different_a_char`, but uses the given groups of similar shapes and keyboard placement instead of the alphabet ones.
+/// Characters not found in the keyboard placement are not treated as CloseKeyboardPlacement.
+///
+/// `find_different_a_char`と同じですが、アルファベットのものの代わりに指定した形状が似ている文字のグループとキーボード配置を使います。
+/// キーボード配置に含まれない文字はCloseKeyboardPlacementとして扱いません。
+///
+/// # Arguments
+///
+/// * `check_word` - The check word(チェックする単語)
+/// * `temp_word` - SimilarWord type storing the correct word(正しい単語を格納したSimilarWord型)
+/// * `similar_shape` - Groups of characters that are similar in shape(形状が似ている文字のグループ)
+/// * `close_keyboard_placement` - Hashmap of characters and their nearby keys(文字と近くにあるキーのハッシュマップ)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{find_different_a_char_with, kana_keyboard_placement_list, kana_similar_shape_list, SimilarWord};
+///
+/// let temp_word = SimilarWord::new("シャツ".to_string(), 1);
+/// let return_word = find_different_a_char_with("シャシ", temp_word, &kana_similar_shape_list(), &kana_keyboard_placement_list());
+/// println!("return_word: {:?}", return_word);
+/// ```
+pub fn find_different_a_char_with(
+    check_word: &str,
+    mut temp_word: SimilarWord,
+    similar_shape: &[Vec<char>],
+    close_keyboard_placement: &HashMap<char, Vec<char>>,
+) -> SimilarWord {
+    // 大文字は小文字にしてから表を引く
+    let fold_case = |c: char| c.to_lowercase().next().unwrap_or(c);
 
     for (c, t) in check_word.chars().zip(temp_word.spelling.chars()) {
-        if c != t {
+        let (folded_c, folded_t) = (fold_case(c), fold_case(t));
+        if folded_c != folded_t {
             //形状が似ているか確認
             for tmp_similar_char in similar_shape.iter() {
-                if tmp_similar_char.contains(&c) && tmp_similar_char.contains(&t) {
+                if tmp_similar_char.contains(&folded_c) && tmp_similar_char.contains(&folded_t) {
                     temp_word.typo_type = TypoType::SimilarShapes;
                     return temp_word;
                 }
             }
 
-            //キーボード配置が近いか確認
-            let pickup_close_keyboard_placement_vec = close_keyboard_placement.get(&c).unwrap();
-
-            if pickup_close_keyboard_placement_vec.contains(&t) {
-                temp_word.typo_type = TypoType::CloseKeyboardPlacement;
+            //キーボード配置が近いか確認(表に無い文字は判別しない)
+            let pickup_close_keyboard_placement_vec = close_keyboard_placement
+                .get(&c)
+                .or_else(|| close_keyboard_placement.get(&folded_c));
+            if let Some(pickup_close_keyboard_placement_vec) = pickup_close_keyboard_placement_vec {
+                if pickup_close_keyboard_placement_vec.contains(&t)
+                    || pickup_close_keyboard_placement_vec.contains(&folded_t)
+                {
+                    temp_word.typo_type = TypoType::CloseKeyboardPlacement;
+                }
             }
         }
     }
     temp_word
 }
 
-/// Returns typo-check results for the check word based on output criteria such as the number of pieces to output and sort order.
+/// Struct that stores the character tables used to classify SimilarShapes and CloseKeyboardPlacement, the user-defined classifiers and the word frequencies used by `ranking_weights`
 ///
-/// 出力する個数やソートの順序などの出力条件に基づいて、単語のタイポチェック結果を返します。
+/// SimilarShapesとCloseKeyboardPlacementの判別に使う文字の表と、ユーザーが定義した分類器と、`ranking_weights`で使う単語の頻度を格納する構造体です
+#[derive(Debug, Clone)]
+pub(crate) struct CharacterTable {
+    pub(crate) similar_shape: Vec<Vec<char>>,
+    pub(crate) similar_shape_sequence: Vec<(&'static str, &'static str)>,
+    pub(crate) close_keyboard_placement: HashMap<char, Vec<char>>,
+    pub(crate) classifier_list: Vec<Arc<dyn TypoClassifier>>,
+    // TypoCheckerを複製しても頻度の表は共有する
+    pub(crate) frequency_map: Arc<HashMap<String, f64>>,
+}
+
+impl Default for CharacterTable {
+    fn default() -> CharacterTable {
+        CharacterTable::new(KeyboardLayout::Qwerty)
+    }
+}
+
+impl CharacterTable {
+    /// キーボード配列に合わせたキーボード配置の表を作る
+    pub(crate) fn new(keyboard_layout: KeyboardLayout) -> CharacterTable {
+        CharacterTable {
+            similar_shape: similar_shape_list(),
+            similar_shape_sequence: Vec::new(),
+            close_keyboard_placement: keyboard_layout.close_keyboard_placement_list(),
+            classifier_list: Vec::new(),
+            frequency_map: Arc::default(),
+        }
+    }
+
+    /// オプションの形状が似ている文字の表と、配列の表(置き換える場合は空の表)に追加のキーボード配置を登録した表を作る
+    pub(crate) fn for_options(options: &CheckOptions) -> CharacterTable {
+        let mut character_table = CharacterTable::new(options.keyboard_layout);
+        character_table.similar_shape = options.shape_profile.similar_shape_list();
+        character_table
+            .similar_shape
+            .extend(options.similar_shapes.iter().cloned());
+        character_table.similar_shape_sequence =
+            options.shape_profile.similar_shape_sequence_list();
+        if options.replace_layout_adjacency {
+            character_table.close_keyboard_placement = HashMap::new();
+        }
+        character_table.with_keyboard_adjacency(&options.keyboard_adjacency)
+    }
+
+    /// 追加のキーボード配置を双方向に登録した表を返す
+    pub(crate) fn with_keyboard_adjacency(&self, keyboard_adjacency: &[(char, Vec<char>)]) -> Self {
+        let mut character_table = self.clone();
+        for (key, close_char_list) in keyboard_adjacency {
+            for close_char in close_char_list {
+                for (from_char, to_char) in [(*key, *close_char), (*close_char, *key)] {
+                    let registered_char_list = character_table
+                        .close_keyboard_placement
+                        .entry(from_char)
+                        .or_default();
+                    if !registered_char_list.contains(&to_char) {
+                        registered_char_list.push(to_char);
+                    }
+                }
+            }
+        }
+        character_table
+    }
+}
+
+/// Classifies the TypoType of the similar word by the user-defined classifiers, or by the built-in classification if the Levenshtein distance is 1 or it is a transposition. Other similar words are returned as they are.
+///
+/// ユーザーが定義した分類器で似ている単語のTypoTypeを判別し、分類されない場合はレーベンシュタイン距離が1か入れ替えの単語を組み込みの判別で判別します。それ以外の似ている単語はそのまま返します。
 ///
 /// # Arguments
 ///
 /// * `check_word` - The check word(チェックする単語)
 /// * `check_word_length` - Length of the check word(チェックする単語の文字数)
-/// * `similar_word_list` - List of words similar to the check word(チェックする単語に似ている単語のリスト)
-/// * `output_levenshtein_cutoff` - Cutoff values by Levenshtein distance for output list(出力する似ている単語リストのレーベンシュタイン距離によるカットオフ数値)
-/// * `pickup_similar_word_num` - Cutoff value for the number of elements in output list(出力する似ている単語リストの要素数のカットオフ数値)
-/// * `sort_order_of_typo_type` - Sort criteria by TypoType for output list(出力する似ている単語リストのTypoTypeによるソート条件)
-fn get_top_similar_words(
-    check_word: String,
+/// * `similar_word` - SimilarWord type storing the correct word(正しい単語を格納したSimilarWord型)
+/// * `character_table` - Character tables of similar shapes and keyboard placement(形状が似ている文字とキーボード配置の表)
+fn classify_similar_word(
+    check_word: &str,
     check_word_length: usize,
-    mut similar_word_list: Vec<SimilarWord>,
-    output_levenshtein_cutoff: Option<usize>,
-    pickup_similar_word_num: usize,
-    sort_order_of_typo_type: Option<&Vec<TypoType>>,
+    similar_word: SimilarWord,
+    character_table: &CharacterTable,
+) -> SimilarWord {
+    // 判別済みの単語はそのまま返す
+    if similar_word.typo_type != TypoType::UndefinedType {
+        return similar_word;
+    }
+
+    // ユーザーが定義した分類器を登録した順に実行する
+    if let Some(classification) = character_table
+        .classifier_list
+        .iter()
+        .find_map(|classifier| classifier.classify(check_word, &similar_word.spelling))
+    {
+        return SimilarWord {
+            typo_type: classification.typo_type(),
+            ..similar_word
+        };
+    }
+
+    // 文字の繰り返しは距離によらず判別する
+    if let Some(character) = find_repeated_character(check_word, &similar_word.spelling) {
+        return SimilarWord {
+            typo_type: TypoType::RepeatedCharacter { character },
+            ..similar_word
+        };
+    }
+
+    // 隣り合う2文字の入れ替えと、「rn」と「m」などの形状が似ている文字の並びの置き換えはレーベンシュタイン距離が2になる
+    if similar_word.levenshtein_length == 2 {
+        if is_adjacent_transposition(check_word, &similar_word.spelling) {
+            return SimilarWord {
+                typo_type: TypoType::Transposition,
+                ..similar_word
+            };
+        }
+        if is_similar_shape_sequence(
+            check_word,
+            &similar_word.spelling,
+            &character_table.similar_shape_sequence,
+        ) {
+            return SimilarWord {
+                typo_type: TypoType::SimilarShapes,
+                ..similar_word
+            };
+        }
+        return similar_word;
+    }
+    if similar_word.levenshtein_length != 1 {
+        return similar_word;
+    }
+
+    //チェックする単語との文字数の比較を行う
+    if check_word_length == similar_word.spelling.chars().count() {
+        // CloseKeyboardPlacementかSimilarShapesの判別を行う
+        find_different_a_char_with(
+            check_word,
+            similar_word,
+            &character_table.similar_shape,
+            &character_table.close_keyboard_placement,
+        )
+    } else {
+        // MissingCharactersの処理を行う
+        find_missing_or_extra_chars_with(
+            check_word,
+            similar_word,
+            &character_table.close_keyboard_placement,
+        )
+    }
+}
+
+/// 文字の並びの連続した同じ文字を(文字、回数)にまとめる
+fn run_length_list(word: &str) -> Vec<(char, usize)> {
+    let mut run_length_list: Vec<(char, usize)> = Vec::new();
+    for c in word.chars() {
+        match run_length_list.last_mut() {
+            Some((last_char, count)) if *last_char == c => *count += 1,
+            _ => run_length_list.push((c, 1)),
+        }
+    }
+    run_length_list
+}
+
+/// チェックする単語が正しい単語の文字を多く繰り返しているだけの場合に、最初に多く繰り返している文字を返す
+fn find_repeated_character(check_word: &str, spelling: &str) -> Option<char> {
+    let check_run_length_list = run_length_list(check_word);
+    let spelling_run_length_list = run_length_list(spelling);
+    if check_run_length_list.len() != spelling_run_length_list.len() {
+        return None;
+    }
+
+    let mut repeated_character = None;
+    for ((check_char, check_count), (spelling_char, spelling_count)) in check_run_length_list
+        .into_iter()
+        .zip(spelling_run_length_list)
+    {
+        if check_char != spelling_char || check_count < spelling_count {
+            return None;
+        }
+        if check_count > spelling_count && repeated_character.is_none() {
+            repeated_character = Some(check_char);
+        }
+    }
+    repeated_character
+}
+
+/// 隣り合う2文字を入れ替えると一致する場合にtrueを返す
+fn is_adjacent_transposition(check_word: &str, spelling: &str) -> bool {
+    let check_char_list: Vec<char> = check_word.chars().collect();
+    let spelling_char_list: Vec<char> = spelling.chars().collect();
+    if check_char_list.len() != spelling_char_list.len() {
+        return false;
+    }
+    let different_index_list: Vec<usize> = (0..check_char_list.len())
+        .filter(|index| check_char_list[*index] != spelling_char_list[*index])
+        .collect();
+    match different_index_list[..] {
+        [first, second] => {
+            second == first + 1
+                && check_char_list[first] == spelling_char_list[second]
+                && check_char_list[second] == spelling_char_list[first]
+        }
+        _ => false,
+    }
+}
+
+/// Struct that stores how far the cutoff is widened when there are few suggestions
+///
+/// 候補が少ない場合にカットオフ値をどこまで広げるかを格納する構造体です
+///
+/// # Arguments
+///
+/// * `min_suggestion_num` - The cutoff is widened until this number of suggestions are found(この数の候補が見つかるまでカットオフ値を広げます)
+/// * `max_cutoff` - Maximum cutoff value after widening(広げた後のカットオフ値の最大値)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WidenCutoff {
+    pub min_suggestion_num: usize,
+    pub max_cutoff: usize,
+}
+
+/// Struct that stores how a long check word is split into two dictionary words, such as "passwordmigration" into "password migration"
+///
+/// "passwordmigration"を"password migration"にするように、長いチェックする単語を2つの辞書の単語に分割する方法を格納する構造体です
+///
+/// # Arguments
+///
+/// * `min_word_length` - Check words of this length or more are split(この文字数以上のチェックする単語を分割します)
+/// * `separators` - Characters inserted at the split point, in the order of preference. No word is split if it is empty(分割した位置に入れる文字。優先する順に並べ、空の場合は分割しません)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitSuggestion {
+    pub min_word_length: usize,
+    pub separators: Vec<char>,
+}
+
+impl Default for SplitSuggestion {
+    fn default() -> SplitSuggestion {
+        SplitSuggestion {
+            min_word_length: 12,
+            separators: vec![' ', '-'],
+        }
+    }
+}
+
+/// 分割したそれぞれの単語の最小の文字数
+const MIN_SPLIT_PART_LENGTH: usize = 3;
+
+/// 長い単語を2つの辞書の単語に分割した候補を、区切り文字の優先する順に返す。両方の単語の短い方が最も長い分割を選ぶ
+fn find_split_words(
+    check_word: &str,
+    options: &CheckOptions,
+    dictionary: &Dictionary,
 ) -> Vec<SimilarWord> {
-    // `levenshtein_length` の小さい順にソート
-    similar_word_list.sort_by_key(|word| word.levenshtein_length);
+    let split_suggestion = &options.split_suggestion;
+    if check_word.chars().count() < split_suggestion.min_word_length {
+        return Vec::new();
+    }
+
+    let best_split = check_word
+        .char_indices()
+        .enumerate()
+        .filter(|(char_index, _)| *char_index >= MIN_SPLIT_PART_LENGTH)
+        .filter_map(|(char_index, (byte_index, _))| {
+            let (head, tail) = check_word.split_at(byte_index);
+            let shorter_length = char_index.min(tail.chars().count());
+            let is_split_word =
+                |word: &str| dictionary.contains(word) && !dictionary.is_forbidden(word);
+            (shorter_length >= MIN_SPLIT_PART_LENGTH && is_split_word(head) && is_split_word(tail))
+                .then_some((shorter_length, char_index, head, tail))
+        })
+        .fold(
+            None,
+            |best: Option<(usize, usize, &str, &str)>, candidate| match best {
+                Some(best) if best.0 >= candidate.0 => Some(best),
+                _ => Some(candidate),
+            },
+        );
+
+    let Some((_, char_index, head, tail)) = best_split else {
+        return Vec::new();
+    };
+    split_suggestion
+        .separators
+        .iter()
+        .map(|separator| (*separator, format!("{}{}{}", head, separator, tail)))
+        .filter(|(_, spelling)| !options.is_excluded_suggestion(spelling))
+        .map(|(separator, spelling)| SimilarWord {
+            spelling,
+            levenshtein_length: 1,
+            typo_type: TypoType::MissingCharacters {
+                character: separator,
+                position: CharacterPositon::Middle(char_index),
+            },
+            source: SuggestionSource::WordSplit,
+        })
+        .collect()
+}
+
+/// Struct that bundles the options of typo check
+///
+/// タイポチェックのオプションをまとめた構造体です
+///
+/// # Arguments
+///
+/// * `output_levenshtein_cutoff` - Cutoff value of Levenshtein distance to output(出力するレーベンシュタイン距離のカットオフ値)
+/// * `pickup_similar_word_num` - Number of words to store in the list of similar_word_list(似ている単語のリストに格納する単語数)
+/// * `sort_order_of_typo_type` - Sort criteria by TypoType for output list(出力する似ている単語リストのTypoTypeによるソート条件)
+/// * `typo_type_limits` - Maximum number of words per TypoType in output list(出力する似ている単語リストのTypoTypeごとの最大単語数)
+/// * `ranking_weights` - Weights to rank the output list by score instead of `sort_order_of_typo_type`(`sort_order_of_typo_type`の代わりにスコアで出力リストを並べるための重み)
+/// * `use_common_misspellings` - Whether to look up the bundled common misspellings before fuzzy search(曖昧検索の前に同梱のよくある綴り間違いを参照するかどうか)
+/// * `detect_caps_lock` - Whether to report words typed with Caps Lock on(Caps Lockが有効なまま入力された単語を報告するかどうか)
+/// * `length_aware_cutoff` - Whether to scale the cutoff with the length of the check word instead of `output_levenshtein_cutoff`(`output_levenshtein_cutoff`の代わりにチェックする単語の文字数に応じたカットオフ値を使うかどうか)
+/// * `fold_german_umlauts` - Whether `check_german_word` treats "ue/oe/ae/ss" as "ü/ö/ä/ß"(`check_german_word`で"ue/oe/ae/ss"を"ü/ö/ä/ß"と同じものとして扱うかどうか)
+/// * `min_word_length` - Words shorter than this are skipped(この文字数より短い単語はチェックしません)
+/// * `stop_words` - Words that are skipped regardless of case(大文字小文字に関係なくチェックしない単語)
+/// * `keyboard_layout` - Keyboard layout used to classify CloseKeyboardPlacement, QWERTY by default(CloseKeyboardPlacementの判別に使うキーボード配列。デフォルトはQWERTYです)
+/// * `keyboard_adjacency` - Additional nearby keys such as digits, symbols and non-Latin keys, registered in both directions(数字や記号、ラテン文字以外のキーなどの追加の近くにあるキー。双方向に登録されます)
+/// * `replace_layout_adjacency` - Whether `keyboard_adjacency` replaces the table of `keyboard_layout` instead of extending it(`keyboard_adjacency`で`keyboard_layout`の表を拡張する代わりに置き換えるかどうか)
+/// * `shape_profile` - Bundled groups of characters used to classify SimilarShapes(SimilarShapesの判別に使う同梱の文字のグループ)
+/// * `similar_shapes` - Additional groups of characters that are similar in shape(追加の形状が似ている文字のグループ)
+/// * `distance_metric` - Edit distance used to order the similar words before they are sorted by TypoType. The cutoff always uses the Levenshtein distance(TypoTypeで並べ替える前に似ている単語を並べるための編集距離。カットオフ値には常にレーベンシュタイン距離を使います)
+/// * `max_length_delta` - Maximum difference in length between the check word and a suggestion. CommonMisspelling is not limited(チェックする単語と候補の文字数の差の最大値。CommonMisspellingは制限されません)
+/// * `prescreen` - Whether to exclude candidates by a cheap lower bound of the distance before calculating the exact distance. The result does not change(正確な距離を計算する前に、距離の下限を使った軽い計算で候補を除外するかどうか。結果は変わりません)
+/// * `widen_cutoff` - Widens the cutoff when there are fewer suggestions than `min_suggestion_num`(候補が`min_suggestion_num`より少ない場合にカットオフ値を広げます)
+/// * `resource_limits` - Hard limits of `check_untrusted` and `check_untrusted_words`(`check_untrusted`と`check_untrusted_words`の入力の上限)
+/// * `excluded_suggestions` - Words that are never suggested regardless of case, even if they are in the dictionary(辞書にある場合でも、大文字小文字に関係なく候補にしない単語)
+/// * `unit_suffixes` - Units that can follow a number in a text, such as "10km", compared regardless of case(テキストで"10km"のように数の後に付けられる単位。大文字小文字に関係なく比較します)
+/// * `split_suggestion` - How a long check word is split into two dictionary words. The splits are ranked above the suggestions whose distance is more than 1(長いチェックする単語を2つの辞書の単語に分割する方法。分割した候補は距離が1より大きい候補より上位になります)
+/// * `abbreviations` - Abbreviations and their expansions. The abbreviations are accepted regardless of case and a trailing period, and the expansion is returned by `get_abbreviation_expansion`(略語とその展開形。略語は大文字小文字と末尾のピリオドに関係なく受け入れ、展開形は`get_abbreviation_expansion`で返します)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CheckOptions {
+    pub output_levenshtein_cutoff: Option<usize>,
+    pub pickup_similar_word_num: usize,
+    pub sort_order_of_typo_type: Option<Vec<TypoType>>,
+    pub typo_type_limits: Vec<(TypoType, usize)>,
+    pub ranking_weights: Option<RankingWeights>,
+    pub use_common_misspellings: bool,
+    pub detect_caps_lock: bool,
+    pub length_aware_cutoff: bool,
+    pub fold_german_umlauts: bool,
+    pub min_word_length: usize,
+    pub stop_words: Vec<String>,
+    pub keyboard_layout: KeyboardLayout,
+    pub keyboard_adjacency: Vec<(char, Vec<char>)>,
+    pub replace_layout_adjacency: bool,
+    pub shape_profile: ShapeProfile,
+    pub similar_shapes: Vec<Vec<char>>,
+    pub distance_metric: DistanceMetric,
+    pub max_length_delta: Option<usize>,
+    pub prescreen: bool,
+    pub widen_cutoff: Option<WidenCutoff>,
+    pub resource_limits: ResourceLimits,
+    pub excluded_suggestions: Vec<String>,
+    pub unit_suffixes: Vec<String>,
+    pub split_suggestion: SplitSuggestion,
+    pub abbreviations: Vec<(String, String)>,
+}
+
+/// テキストで数の後に付けられるデフォルトの単位
+const DEFAULT_UNIT_SUFFIX_LIST: [&str; 38] = [
+    "mm", "cm", "m", "km", "in", "ft", "mi", "mg", "g", "kg", "lb", "lbs", "ml", "l", "ms", "s",
+    "min", "h", "hz", "khz", "mhz", "ghz", "b", "kb", "mb", "gb", "tb", "kbps", "mbps", "px", "pt",
+    "em", "am", "pm", "°c", "°f", "v", "w",
+];
+
+impl Default for CheckOptions {
+    fn default() -> CheckOptions {
+        CheckOptions {
+            output_levenshtein_cutoff: Some(2),
+            pickup_similar_word_num: 5,
+            sort_order_of_typo_type: None,
+            typo_type_limits: Vec::new(),
+            ranking_weights: None,
+            use_common_misspellings: true,
+            detect_caps_lock: true,
+            length_aware_cutoff: false,
+            fold_german_umlauts: true,
+            min_word_length: 0,
+            stop_words: Vec::new(),
+            keyboard_layout: KeyboardLayout::Qwerty,
+            keyboard_adjacency: Vec::new(),
+            replace_layout_adjacency: false,
+            shape_profile: ShapeProfile::Default,
+            similar_shapes: Vec::new(),
+            distance_metric: DistanceMetric::Levenshtein,
+            max_length_delta: None,
+            prescreen: true,
+            widen_cutoff: None,
+            resource_limits: ResourceLimits::default(),
+            excluded_suggestions: Vec::new(),
+            unit_suffixes: DEFAULT_UNIT_SUFFIX_LIST
+                .iter()
+                .map(|unit_suffix| unit_suffix.to_string())
+                .collect(),
+            split_suggestion: SplitSuggestion::default(),
+            abbreviations: Vec::new(),
+        }
+    }
+}
 
-    // カットオフが指定されている場合、それより文字数が多い単語をフィルタする
-    if let Some(cutoff) = output_levenshtein_cutoff {
-        similar_word_list.retain(|word| word.levenshtein_length <= cutoff);
+impl CheckOptions {
+    /// Sets the cutoff value of Levenshtein distance to output(出力するレーベンシュタイン距離のカットオフ値を設定します)
+    pub fn output_levenshtein_cutoff(mut self, output_levenshtein_cutoff: Option<usize>) -> Self {
+        self.output_levenshtein_cutoff = output_levenshtein_cutoff;
+        self
     }
 
-    // カットオフが1のものについてTypoTypeの判別を行う
-    for temp_word in similar_word_list.iter_mut() {
-        if temp_word.levenshtein_length == 1 {
-            //チェックする単語との文字数の比較を行う
-            if check_word_length == temp_word.spelling.chars().count() {
-                // CloseKeyboardPlacementかSimilarShapesの判別を行う
-                *temp_word = find_different_a_char(&check_word, temp_word.clone())
-            } else {
-                // MissingCharactersの処理を行う
-                *temp_word = find_missing_or_extra_chars(&check_word, temp_word.clone());
-            }
-        } else {
-            continue;
+    /// Sets the number of words to store in the list of similar_word_list(似ている単語のリストに格納する単語数を設定します)
+    pub fn pickup_similar_word_num(mut self, pickup_similar_word_num: usize) -> Self {
+        self.pickup_similar_word_num = pickup_similar_word_num;
+        self
+    }
+
+    /// Sets the sort criteria by TypoType for output list(出力する似ている単語リストのTypoTypeによるソート条件を設定します)
+    pub fn sort_order_of_typo_type(mut self, sort_order_of_typo_type: Vec<TypoType>) -> Self {
+        self.sort_order_of_typo_type = Some(sort_order_of_typo_type);
+        self
+    }
+
+    /// Adds the maximum number of words of the TypoType in output list(出力する似ている単語リストのTypoTypeの最大単語数を追加します)
+    pub fn typo_type_limit(mut self, typo_type: TypoType, limit: usize) -> Self {
+        self.typo_type_limits.push((typo_type, limit));
+        self
+    }
+
+    /// Sets the weights to rank the output list by score(出力リストをスコアで並べるための重みを設定します)
+    pub fn ranking_weights(mut self, ranking_weights: RankingWeights) -> Self {
+        self.ranking_weights = Some(ranking_weights);
+        self
+    }
+
+    /// Sets whether to look up the bundled common misspellings(同梱のよくある綴り間違いを参照するかどうかを設定します)
+    pub fn use_common_misspellings(mut self, use_common_misspellings: bool) -> Self {
+        self.use_common_misspellings = use_common_misspellings;
+        self
+    }
+
+    /// Sets whether to report words typed with Caps Lock on(Caps Lockが有効なまま入力された単語を報告するかどうかを設定します)
+    pub fn detect_caps_lock(mut self, detect_caps_lock: bool) -> Self {
+        self.detect_caps_lock = detect_caps_lock;
+        self
+    }
+
+    /// Sets whether to scale the cutoff with the length of the check word(チェックする単語の文字数に応じたカットオフ値を使うかどうかを設定します)
+    pub fn length_aware_cutoff(mut self, length_aware_cutoff: bool) -> Self {
+        self.length_aware_cutoff = length_aware_cutoff;
+        self
+    }
+
+    /// Returns the cutoff value of Levenshtein distance used for the check word of the given length.
+    /// When `length_aware_cutoff` is true, it is 1 for 4 characters or less, 2 for 5 to 8 characters and 3 for 9 characters or more.
+    ///
+    /// 指定した文字数のチェックする単語に使うレーベンシュタイン距離のカットオフ値を返します。
+    /// `length_aware_cutoff`がtrueの場合、4文字以下は1、5〜8文字は2、9文字以上は3になります。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::CheckOptions;
+    ///
+    /// let options = CheckOptions::default().length_aware_cutoff(true);
+    /// assert_eq!(options.effective_levenshtein_cutoff(3), Some(1));
+    /// assert_eq!(options.effective_levenshtein_cutoff(12), Some(3));
+    /// assert_eq!(CheckOptions::default().effective_levenshtein_cutoff(12), Some(2));
+    /// ```
+    pub fn effective_levenshtein_cutoff(&self, check_word_length: usize) -> Option<usize> {
+        if !self.length_aware_cutoff {
+            return self.output_levenshtein_cutoff;
+        }
+        match check_word_length {
+            0..=4 => Some(1),
+            5..=8 => Some(2),
+            _ => Some(3),
+        }
+    }
+
+    /// 広げる可能性を含めた、候補を探索するカットオフ値
+    pub(crate) fn search_levenshtein_cutoff(&self, check_word_length: usize) -> Option<usize> {
+        let cutoff = self.effective_levenshtein_cutoff(check_word_length)?;
+        match &self.widen_cutoff {
+            Some(widen_cutoff) => Some(cutoff.max(widen_cutoff.max_cutoff)),
+            None => Some(cutoff),
         }
     }
 
-    // TypoTypeに応じてソートを実行する
-    let default_sort_typo_type = vec![
+    /// Sets whether `check_german_word` treats "ue/oe/ae/ss" as "ü/ö/ä/ß"(`check_german_word`で"ue/oe/ae/ss"を"ü/ö/ä/ß"と同じものとして扱うかどうかを設定します)
+    pub fn fold_german_umlauts(mut self, fold_german_umlauts: bool) -> Self {
+        self.fold_german_umlauts = fold_german_umlauts;
+        self
+    }
+
+    /// Sets the minimum length of words to check(チェックする単語の最小の文字数を設定します)
+    pub fn min_word_length(mut self, min_word_length: usize) -> Self {
+        self.min_word_length = min_word_length;
+        self
+    }
+
+    /// Sets the words that are skipped(チェックしない単語を設定します)
+    pub fn stop_words<I, S>(mut self, stop_words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stop_words = stop_words.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the keyboard layout used to classify CloseKeyboardPlacement(CloseKeyboardPlacementの判別に使うキーボード配列を設定します)
+    pub fn keyboard_layout(mut self, keyboard_layout: KeyboardLayout) -> Self {
+        self.keyboard_layout = keyboard_layout;
+        self
+    }
+
+    /// Adds nearby keys of the character, such as digits and symbols(数字や記号などの文字の近くにあるキーを追加します)
+    pub fn keyboard_adjacency(mut self, key: char, close_char_list: Vec<char>) -> Self {
+        self.keyboard_adjacency.push((key, close_char_list));
+        self
+    }
+
+    /// Sets whether `keyboard_adjacency` replaces the table of `keyboard_layout` instead of extending it(`keyboard_adjacency`で`keyboard_layout`の表を拡張する代わりに置き換えるかどうかを設定します)
+    pub fn replace_layout_adjacency(mut self, replace_layout_adjacency: bool) -> Self {
+        self.replace_layout_adjacency = replace_layout_adjacency;
+        self
+    }
+
+    /// Adds the nearby keys of each key to `keyboard_adjacency` in order of the key, and replaces the table of `keyboard_layout` with them(キーごとの近くにあるキーをキーの順に`keyboard_adjacency`へ追加し、`keyboard_layout`の表をそれで置き換えます)
+    pub fn keyboard_adjacency_map(
+        mut self,
+        keyboard_adjacency_map: HashMap<char, Vec<char>>,
+    ) -> Self {
+        let mut keyboard_adjacency: Vec<(char, Vec<char>)> =
+            keyboard_adjacency_map.into_iter().collect();
+        keyboard_adjacency.sort_by_key(|(key, _)| *key);
+        self.keyboard_adjacency.extend(keyboard_adjacency);
+        self.replace_layout_adjacency = true;
+        self
+    }
+
+    /// Sets the bundled groups of characters used to classify SimilarShapes(SimilarShapesの判別に使う同梱の文字のグループを設定します)
+    pub fn shape_profile(mut self, shape_profile: ShapeProfile) -> Self {
+        self.shape_profile = shape_profile;
+        self
+    }
+
+    /// Adds a group of characters that are similar in shape, compared in lowercase(小文字で比較する、形状が似ている文字のグループを追加します)
+    pub fn similar_shape_group(mut self, similar_shape_group: Vec<char>) -> Self {
+        self.similar_shapes.push(similar_shape_group);
+        self
+    }
+
+    /// Sets the edit distance used to order the similar words before they are sorted by TypoType(TypoTypeで並べ替える前に似ている単語を並べるための編集距離を設定します)
+    pub fn distance_metric(mut self, distance_metric: DistanceMetric) -> Self {
+        self.distance_metric = distance_metric;
+        self
+    }
+
+    /// Sets the maximum difference in length between the check word and a suggestion(チェックする単語と候補の文字数の差の最大値を設定します)
+    pub fn max_length_delta(mut self, max_length_delta: usize) -> Self {
+        self.max_length_delta = Some(max_length_delta);
+        self
+    }
+
+    /// Sets whether to exclude candidates by a cheap lower bound of the distance(距離の下限を使った軽い計算で候補を除外するかどうかを設定します)
+    pub fn prescreen(mut self, prescreen: bool) -> Self {
+        self.prescreen = prescreen;
+        self
+    }
+
+    /// Widens the cutoff by one up to `max_cutoff` until `min_suggestion_num` suggestions are found(`min_suggestion_num`個の候補が見つかるまで、`max_cutoff`を上限にカットオフ値を1ずつ広げます)
+    pub fn widen_cutoff(mut self, min_suggestion_num: usize, max_cutoff: usize) -> Self {
+        self.widen_cutoff = Some(WidenCutoff {
+            min_suggestion_num,
+            max_cutoff,
+        });
+        self
+    }
+
+    /// Sets the hard limits of untrusted inputs(信頼できない入力の上限を設定します)
+    pub fn resource_limits(mut self, resource_limits: ResourceLimits) -> Self {
+        self.resource_limits = resource_limits;
+        self
+    }
+
+    /// Sets the words that are never suggested(候補にしない単語を設定します)
+    pub fn excluded_suggestions<I, S>(mut self, excluded_suggestions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.excluded_suggestions = excluded_suggestions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 候補にしない単語かどうか
+    pub(crate) fn is_excluded_suggestion(&self, spelling: &str) -> bool {
+        self.excluded_suggestions
+            .iter()
+            .any(|excluded_word| excluded_word.to_lowercase() == spelling.to_lowercase())
+    }
+
+    /// Sets the units that can follow a number in a text(テキストで数の後に付けられる単位を設定します)
+    pub fn unit_suffixes<I, S>(mut self, unit_suffixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.unit_suffixes = unit_suffixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the minimum length of check words to split and the separators in the order of preference(分割するチェックする単語の最小の文字数と、優先する順の区切り文字を設定します)
+    pub fn split_suggestion(mut self, min_word_length: usize, separators: Vec<char>) -> Self {
+        self.split_suggestion = SplitSuggestion {
+            min_word_length,
+            separators,
+        };
+        self
+    }
+
+    /// Sets the abbreviations and their expansions, such as `abbreviation_list`(`abbreviation_list`などの略語とその展開形を設定します)
+    pub fn abbreviations<I, S, T>(mut self, abbreviations: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        self.abbreviations = abbreviations
+            .into_iter()
+            .map(|(abbreviation, expansion)| (abbreviation.into(), expansion.into()))
+            .collect();
+        self
+    }
+
+    /// 略語の展開形。大文字小文字と末尾のピリオドは区別しない
+    pub(crate) fn find_abbreviation_expansion(&self, word: &str) -> Option<&str> {
+        let word = word.trim_end_matches('.').to_lowercase();
+        self.abbreviations
+            .iter()
+            .find(|(abbreviation, _)| abbreviation.trim_end_matches('.').to_lowercase() == word)
+            .map(|(_, expansion)| expansion.as_str())
+    }
+
+    /// Returns true if the word is shorter than `min_word_length` or is one of `stop_words`.
+    ///
+    /// 単語が`min_word_length`より短いか、`stop_words`に含まれる場合にtrueを返します。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::CheckOptions;
+    ///
+    /// let options = CheckOptions::default().min_word_length(3).stop_words(["the", "of"]);
+    /// assert!(options.is_skipped_word("of"));
+    /// assert!(options.is_skipped_word("The"));
+    /// assert!(!options.is_skipped_word("teh"));
+    /// ```
+    pub fn is_skipped_word(&self, word: &str) -> bool {
+        word.chars().count() < self.min_word_length
+            || self
+                .stop_words
+                .iter()
+                .any(|stop_word| stop_word.to_lowercase() == word.to_lowercase())
+    }
+}
+
+/// Returns the default sort criteria by TypoType
+///
+/// TypoTypeによるデフォルトのソート条件を返します
+pub(crate) fn default_sort_order_of_typo_type() -> Vec<TypoType> {
+    vec![
+        TypoType::CapsLock,
+        TypoType::CommonMisspelling,
+        TypoType::MissingAccent,
+        TypoType::Transposition,
+        TypoType::RepeatedCharacter { character: 'A' },
         TypoType::ExtraCharacters {
             character: 'A',
             position: CharacterPositon::Head,
+            adjacent_to: None,
         },
         TypoType::MissingCharacters {
             character: 'Z',
@@ -496,28 +1922,264 @@ fn get_top_similar_words(
         TypoType::SimilarShapes,
         TypoType::CloseKeyboardPlacement,
         TypoType::UndefinedType,
-    ];
+    ]
+}
 
-    let sort_typo_type = sort_order_of_typo_type.unwrap_or(&default_sort_typo_type);
-    SimilarWord::sort_by_typo_type(&mut similar_word_list, &sort_typo_type);
+/// Keeps only up to the limited number of words for each TypoType, preserving the order.
+/// TypoTypes without a limit are not capped.
+///
+/// TypoTypeごとに上限数までの単語のみを順序を保ったまま残します。
+/// 上限が指定されていないTypoTypeは制限されません。
+///
+/// # Arguments
+///
+/// * `similar_word_list` - List of words similar to the check word(チェックする単語に似ている単語のリスト)
+/// * `typo_type_limits` - Maximum number of words per TypoType(TypoTypeごとの最大単語数)
+fn apply_typo_type_limits(
+    similar_word_list: &mut Vec<SimilarWord>,
+    typo_type_limits: &[(TypoType, usize)],
+) {
+    if typo_type_limits.is_empty() {
+        return;
+    }
 
-    // 結果が必要な数以下の場合、そのまま返す
-    if similar_word_list.len() <= pickup_similar_word_num {
-        similar_word_list
-    } else {
-        // 必要な数までを取り出して返す
-        similar_word_list
+    let typo_type_limit_map: HashMap<String, usize> = typo_type_limits
+        .iter()
+        .map(|(typo_type, limit)| (get_typo_type_name(typo_type), *limit))
+        .collect();
+    let mut typo_type_count: HashMap<String, usize> = HashMap::new();
+
+    similar_word_list.retain(|word| {
+        let typo_type_name = get_typo_type_name(&word.typo_type);
+        match typo_type_limit_map.get(&typo_type_name) {
+            Some(limit) => {
+                let count = typo_type_count.entry(typo_type_name).or_insert(0);
+                *count += 1;
+                *count <= *limit
+            }
+            None => true,
+        }
+    });
+}
+
+/// Returns typo-check results for the check word based on output criteria such as the number of pieces to output and sort order.
+///
+/// 出力する個数やソートの順序などの出力条件に基づいて、単語のタイポチェック結果を返します。
+///
+/// # Arguments
+///
+/// * `check_word` - The check word(チェックする単語)
+/// * `check_word_length` - Length of the check word(チェックする単語の文字数)
+/// * `similar_word_list` - List of words similar to the check word(チェックする単語に似ている単語のリスト)
+/// * `options` - Output criteria such as cutoff, number of words and sort order(カットオフ、単語数、ソート順などの出力条件)
+#[cfg(test)]
+fn get_top_similar_words(
+    check_word: String,
+    check_word_length: usize,
+    similar_word_list: Vec<SimilarWord>,
+    options: &CheckOptions,
+) -> Vec<SimilarWord> {
+    get_top_similar_words_with_table(
+        check_word,
+        check_word_length,
+        similar_word_list,
+        options,
+        &CharacterTable::for_options(options),
+        &mut SearchTelemetry::default(),
+    )
+}
+
+/// 候補の並び順のキーを返す。辞書の探索方法が変わっても同じ候補は同じ順序になる
+fn candidate_order_key(
+    check_word_length: usize,
+    similar_word: &SimilarWord,
+) -> (usize, Ordering, usize, &str) {
+    let word_length = similar_word.spelling.chars().count();
+    // 文字数が同じ単語、短い単語、長い単語の順にする
+    let length_order = match word_length.cmp(&check_word_length) {
+        Ordering::Equal => Ordering::Less,
+        Ordering::Less => Ordering::Equal,
+        Ordering::Greater => Ordering::Greater,
+    };
+    (
+        similar_word.levenshtein_length,
+        length_order,
+        word_length,
+        &similar_word.spelling,
+    )
+}
+
+/// Same as `get_top_similar_words`, but classifies the TypoType with the given character tables and records the number of words that passed the cutoff.
+///
+/// `get_top_similar_words`と同じですが、指定した文字の表でTypoTypeを判別し、カットオフ値を通過した単語数を記録します。
+fn get_top_similar_words_with_table(
+    check_word: String,
+    check_word_length: usize,
+    similar_word_list: Vec<SimilarWord>,
+    options: &CheckOptions,
+    character_table: &CharacterTable,
+    telemetry: &mut SearchTelemetry,
+) -> Vec<SimilarWord> {
+    rank_similar_words(
+        check_word,
+        check_word_length,
+        similar_word_list,
+        options,
+        &character_table.with_keyboard_adjacency(&options.keyboard_adjacency),
+        telemetry,
+    )
+}
+
+/// `get_top_similar_words_with_table`と同じだが、オプションの追加のキーボード配置を登録済みの文字の表を使う
+fn rank_similar_words(
+    check_word: String,
+    check_word_length: usize,
+    mut similar_word_list: Vec<SimilarWord>,
+    options: &CheckOptions,
+    character_table: &CharacterTable,
+    telemetry: &mut SearchTelemetry,
+) -> Vec<SimilarWord> {
+    let started_at = telemetry.start_stage();
+
+    // `levenshtein_length` の小さい順にソートし、同じ距離の中では探索の順序によらない順に並べる
+    // (文字数が同じ単語、短い単語、長い単語の順で、それぞれ文字数順・辞書順)
+    similar_word_list.sort_by(|a, b| {
+        candidate_order_key(check_word_length, a).cmp(&candidate_order_key(check_word_length, b))
+    });
+
+    // キーボードの距離で重み付けする場合は、重み付きの距離の小さい順に並べ直す(同じ距離の中では上の順序を保つ)
+    if options.distance_metric == DistanceMetric::KeyboardWeighted {
+        let mut weighted_word_list: Vec<(f64, SimilarWord)> = similar_word_list
             .into_iter()
-            .take(pickup_similar_word_num)
-            .collect()
+            .map(|similar_word| {
+                let weighted_length = keyboard_levenshtein(
+                    &check_word,
+                    &similar_word.spelling,
+                    &character_table.close_keyboard_placement,
+                );
+                (weighted_length, similar_word)
+            })
+            .collect();
+        weighted_word_list.sort_by(|a, b| a.0.total_cmp(&b.0));
+        similar_word_list = weighted_word_list
+            .into_iter()
+            .map(|(_, similar_word)| similar_word)
+            .collect();
+    }
+
+    // カットオフが指定されている場合、それより文字数が多い単語をフィルタする(よくある綴り間違いは残す)
+    if let Some(mut cutoff) = options.effective_levenshtein_cutoff(check_word_length) {
+        // 候補が少ない場合はカットオフ値を広げる
+        if let Some(widen_cutoff) = &options.widen_cutoff {
+            while cutoff < widen_cutoff.max_cutoff
+                && similar_word_list
+                    .iter()
+                    .filter(|word| word.levenshtein_length <= cutoff)
+                    .count()
+                    < widen_cutoff.min_suggestion_num
+            {
+                cutoff += 1;
+            }
+        }
+        similar_word_list.retain(|word| {
+            word.levenshtein_length <= cutoff || word.typo_type == TypoType::CommonMisspelling
+        });
+    }
+
+    // 文字数の差が指定より大きい単語をフィルタする(よくある綴り間違いは残す)
+    if let Some(max_length_delta) = options.max_length_delta {
+        similar_word_list.retain(|word| {
+            word.spelling.chars().count().abs_diff(check_word_length) <= max_length_delta
+                || word.typo_type == TypoType::CommonMisspelling
+        });
+    }
+
+    // 候補にしない単語をフィルタする
+    if !options.excluded_suggestions.is_empty() {
+        similar_word_list.retain(|word| !options.is_excluded_suggestion(&word.spelling));
+    }
+
+    telemetry.passed_cutoff_num = similar_word_list.len();
+    telemetry.end_stage(started_at, |stage_timings| &mut stage_timings.sort);
+
+    // カットオフが1のものについてTypoTypeの判別を行う
+    let started_at = telemetry.start_stage();
+    for temp_word in similar_word_list.iter_mut() {
+        *temp_word = classify_similar_word(
+            &check_word,
+            check_word_length,
+            temp_word.clone(),
+            character_table,
+        );
+    }
+    telemetry.end_stage(started_at, |stage_timings| {
+        &mut stage_timings.classification
+    });
+
+    let started_at = telemetry.start_stage();
+    if let Some(ranking_weights) = &options.ranking_weights {
+        // 重みが指定されている場合、スコアに応じてソートを実行する
+        ranking_weights.sort_by_score(
+            &check_word,
+            &mut similar_word_list,
+            &character_table.frequency_map,
+        );
+    } else {
+        // TypoTypeに応じてソートを実行する
+        let default_sort_typo_type = default_sort_order_of_typo_type();
+        let sort_typo_type = options
+            .sort_order_of_typo_type
+            .as_ref()
+            .unwrap_or(&default_sort_typo_type);
+        SimilarWord::sort_by_typo_type(&mut similar_word_list, sort_typo_type);
+    }
+
+    // 複数の探索で見つかった同じ綴りは、ソート後に最も上位のものを残す
+    let mut found_spelling_set: HashSet<String> = HashSet::new();
+    similar_word_list.retain(|word| found_spelling_set.insert(word.spelling.clone()));
+
+    // TypoTypeごとの上限数を適用する
+    apply_typo_type_limits(&mut similar_word_list, &options.typo_type_limits);
+
+    // 必要な数までを取り出す
+    similar_word_list.truncate(options.pickup_similar_word_num);
+    telemetry.end_stage(started_at, |stage_timings| &mut stage_timings.sort);
+
+    similar_word_list
+}
+
+/// 1.0.0のデフォルトのソート順(ExtraCharacters -> MissingCharacters -> SimilarShapes -> CloseKeyboardPlacement -> UndefinedType)と同じ順位になる重み。
+/// 同じスコアの単語は探索した順のままにする
+fn legacy_ranking_weights() -> RankingWeights {
+    RankingWeights {
+        typo_type: TypoTypeWeights {
+            extra_characters: 4.0,
+            missing_characters: 3.0,
+            similar_shapes: 2.0,
+            close_keyboard_placement: 1.0,
+            common_misspelling: 0.0,
+            caps_lock: 0.0,
+            // 1.0.0ではアクセントの抜けと入れ替わりは分類できず、繰り返しは余分な文字として分類した
+            missing_accent: 0.0,
+            transposition: 0.0,
+            repeated_character: 4.0,
+            custom: 0.0,
+            undefined_type: 0.0,
+        },
+        distance_penalty: 0.0,
+        frequency_factor: 0.0,
+        prefix_bonus: 0.0,
+        adjacent_extra_character_bonus: 0.0,
     }
 }
 
 /// Returns TypoCheckResult type words that match or are similar to the word to be checked.
 /// Similar_word_list of type TypoCheckResult contains the top `pickup_similar_word_num` words with Levenshtein distance(less than or equal to `output_levenshtein_cutoff`).
+/// The features added after 1.0.0, such as common misspellings, Caps Lock, word splitting and unit suffixes, are disabled to keep the results of 1.0.0.
 ///
 /// チェックする単語に合致、もしくは類似する単語をTypoCheckResult型で返却します。
 /// TypoCheckResult型のsimilar_word_listには、レーベンシュタイン距離がoutput_levenshtein_cutoff以下&pickup_similar_word_numで指定した個数の上位の単語が格納されます。
+/// 1.0.0の結果を保つため、よくある綴り間違い、Caps Lock、単語の分割、単位の接尾辞などの1.0.0より後に追加した機能は無効です。
 ///
 /// # Arguments
 ///
@@ -533,101 +2195,366 @@ fn get_top_similar_words(
 /// use typo_checker::CharacterPositon;
 ///
 /// let check_word = "applo";
-/// let custom_sort_order = vec![TypoType::SimilarShapes, TypoType::CloseKeyboardPlacement, TypoType::UndefinedType, TypoType::ExtraCharacters { character: 'A', position: CharacterPositon::Head, }, TypoType::MissingCharacters { character: 'Z', position: CharacterPositon::Tail, }, ];
+/// let custom_sort_order = vec![TypoType::SimilarShapes, TypoType::CloseKeyboardPlacement, TypoType::UndefinedType, TypoType::ExtraCharacters { character: 'A', position: CharacterPositon::Head, adjacent_to: None, }, TypoType::MissingCharacters { character: 'Z', position: CharacterPositon::Tail, }, ];
+/// # #[allow(deprecated)]
 /// let typo_chec_result = typo_checker::check_a_word(check_word.to_string(), Some(3), 20, Some(&custom_sort_order));
 /// println!("typo_chec_result: {:?}", typo_chec_result);
 /// ```
+#[deprecated(
+    since = "2.0.0",
+    note = "use `check_a_word_with` with `CheckOptions` instead"
+)]
 pub fn check_a_word(
     check_word: String,
     output_levenshtein_cutoff: Option<usize>,
     pickup_similar_word_num: usize,
     sort_order_of_typo_type: Option<&Vec<TypoType>>,
 ) -> TypoCheckResult {
+    // 1.0.0と同じ結果を返すように、後から追加した機能は無効にする
+    let options = CheckOptions {
+        output_levenshtein_cutoff,
+        pickup_similar_word_num,
+        sort_order_of_typo_type: sort_order_of_typo_type.cloned(),
+        // ソート条件の指定が無い場合は、後から追加したTypoTypeを1.0.0での分類と同じ順位にする
+        ranking_weights: sort_order_of_typo_type
+            .is_none()
+            .then(legacy_ranking_weights),
+        use_common_misspellings: false,
+        detect_caps_lock: false,
+        unit_suffixes: Vec::new(),
+        split_suggestion: SplitSuggestion {
+            min_word_length: usize::MAX,
+            separators: Vec::new(),
+        },
+        ..CheckOptions::default()
+    };
+    check_a_word_with(&check_word, &options)
+}
+
+/// Returns TypoCheckResult type words that match or are similar to the word to be checked, using the options bundled in CheckOptions.
+///
+/// CheckOptionsにまとめたオプションを使って、チェックする単語に合致、もしくは類似する単語をTypoCheckResult型で返却します。
+///
+/// # Arguments
+///
+/// * `check_word` - Words to check(チェックする単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::CheckOptions;
+/// use typo_checker::TypoType;
+///
+/// let options = CheckOptions::default()
+///     .output_levenshtein_cutoff(Some(3))
+///     .pickup_similar_word_num(10)
+///     .typo_type_limit(TypoType::UndefinedType, 3);
+/// let typo_chec_result = typo_checker::check_a_word_with("applo", &options);
+/// println!("typo_chec_result: {:?}", typo_chec_result);
+/// ```
+pub fn check_a_word_with(check_word: &str, options: &CheckOptions) -> TypoCheckResult {
+    check_a_word_with_telemetry(
+        check_word,
+        options,
+        SearchTelemetry::default(),
+        &Dictionary::builtin(),
+        &CharacterTable::for_options(options),
+    )
+}
+
+/// Same as `check_a_word_with`, but searches the given dictionary instead of the built-in dictionary.
+///
+/// `check_a_word_with`と同じですが、組み込み辞書の代わりに指定した辞書を探索します。
+///
+/// # Arguments
+///
+/// * `check_word` - Words to check(チェックする単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `dictionary` - Dictionary to search(探索する辞書)
+pub fn check_a_word_with_dictionary(
+    check_word: &str,
+    options: &CheckOptions,
+    dictionary: &Dictionary,
+) -> TypoCheckResult {
+    check_a_word_with_telemetry(
+        check_word,
+        options,
+        SearchTelemetry::default(),
+        dictionary,
+        &CharacterTable::for_options(options),
+    )
+}
+
+/// Same as `check_a_word_with`, but also returns the time spent in each stage of the check.
+/// Use it to measure the search on your own dictionary and options.
+///
+/// `check_a_word_with`と同じですが、チェックの段階ごとの所要時間も返します。
+/// 独自の辞書やオプションで探索を計測するために使います。
+///
+/// # Arguments
+///
+/// * `check_word` - Words to check(チェックする単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_with_timings, CheckOptions};
+///
+/// let (typo_chec_result, stage_timings) = check_with_timings("applo", &CheckOptions::default());
+/// println!("typo_chec_result: {:?}", typo_chec_result);
+/// println!("distance: {:?}, total: {:?}", stage_timings.distance, stage_timings.total());
+/// ```
+pub fn check_with_timings(
+    check_word: &str,
+    options: &CheckOptions,
+) -> (TypoCheckResult, StageTimings) {
+    let telemetry = SearchTelemetry {
+        stage_timings: Some(StageTimings::default()),
+        ..SearchTelemetry::default()
+    };
+    let typo_check_result = check_a_word_with_telemetry(
+        check_word,
+        options,
+        telemetry,
+        &Dictionary::builtin(),
+        &CharacterTable::for_options(options),
+    );
+    let stage_timings = typo_check_result
+        .telemetry
+        .stage_timings
+        .unwrap_or_default();
+    (typo_check_result, stage_timings)
+}
+
+/// 指定した計測値を引き継いでチェックを行う。文字の表はオプションの追加のキーボード配置を登録済みのものを使う
+pub(crate) fn check_a_word_with_telemetry(
+    check_word: &str,
+    options: &CheckOptions,
+    telemetry: SearchTelemetry,
+    dictionary: &Dictionary,
+    character_table: &CharacterTable,
+) -> TypoCheckResult {
+    let started_at = telemetry.start_stage();
     let lowercase_check_word = check_word.to_lowercase();
     let check_word_length = lowercase_check_word.chars().count();
-    let select_word_range: usize = match output_levenshtein_cutoff {
-        Some(range_num) => {
-            if range_num == 1 {
-                panic!("Please select output_levenshtein_cutoff > 1 !!");
-            } else {
-                range_num
-            }
+
+    // 短い単語とストップワードはチェックしない
+    if options.is_skipped_word(check_word) {
+        return TypoCheckResult {
+            telemetry,
+            ..TypoCheckResult::new(lowercase_check_word)
+        };
+    }
+
+    // 略語はタイポとせず、展開形を情報として返す
+    if let Some(expansion) = options.find_abbreviation_expansion(check_word) {
+        return TypoCheckResult {
+            match_word: Some(lowercase_check_word.trim_end_matches('.').to_string()),
+            abbreviation_expansion: Some(expansion.to_string()),
+            telemetry,
+            ..TypoCheckResult::new(lowercase_check_word)
+        };
+    }
+
+    // ハイフンで繋いだ数詞は部分ごとに確かめ、辞書の単語を候補にしない
+    if lowercase_check_word.contains('-') {
+        if is_number_word(&lowercase_check_word) {
+            return TypoCheckResult {
+                match_word: Some(lowercase_check_word.clone()),
+                telemetry,
+                ..TypoCheckResult::new(lowercase_check_word)
+            };
         }
-        None => 2,
+        if let Some(corrected_word) = correct_number_word(&lowercase_check_word) {
+            let mut output = TypoCheckResult {
+                telemetry,
+                ..TypoCheckResult::new(lowercase_check_word.clone())
+            };
+            let levenshtein_length = levenshtein(&lowercase_check_word, &corrected_word);
+            let similar_word_list = vec![SimilarWord::new(corrected_word, levenshtein_length)];
+            output.similar_word_list = Some(rank_similar_words(
+                lowercase_check_word,
+                check_word_length,
+                similar_word_list,
+                options,
+                character_table,
+                &mut output.telemetry,
+            ));
+            return output;
+        }
+    }
+
+    // カットオフ値が1の場合は、文字数の差が1以内の単語のみを探索する
+    let select_word_range: usize = options
+        .effective_levenshtein_cutoff(check_word_length)
+        .unwrap_or(2);
+    // カットオフ値を広げる場合は広げた後の距離の単語まで探索する
+    let select_word_range = match &options.widen_cutoff {
+        Some(widen_cutoff) => select_word_range.max(widen_cutoff.max_cutoff),
+        None => select_word_range,
     };
 
-    let word_dic = get_dictionary();
+    let caps_lock_correction = if options.detect_caps_lock {
+        find_caps_lock_inversion(check_word)
+            .filter(|spelling| !options.is_excluded_suggestion(spelling))
+    } else {
+        None
+    };
 
-    let mut output = TypoCheckResult::new();
+    let mut output = TypoCheckResult {
+        telemetry,
+        caps_lock_inversion: caps_lock_correction.clone(),
+        ..TypoCheckResult::new(lowercase_check_word.clone())
+    };
     let mut similar_word_list: Vec<SimilarWord> = Vec::new();
 
-    // インデックスを初期化
-    let mut select_word_upper_index: usize = 10;
-    let mut select_word_lower_index: isize = 0; // isizeにして一時的に負の値も扱えるようにする
-
-    // 文字数に応じたインデックスの計算
+    // 1文字の単語は完全に一致する単語のみを探す
     if check_word_length == 1 {
+        if dictionary.contains(&lowercase_check_word) {
+            output.match_metadata = dictionary.metadata(&lowercase_check_word).cloned();
+            output.match_word = Some(lowercase_check_word);
+        }
         return output;
-    } else if check_word_length == 2 {
-        select_word_upper_index = (check_word_length - 2) + select_word_range;
-        select_word_lower_index = (check_word_length - 2) as isize;
-    } else if check_word_length == 21 {
-        select_word_upper_index = check_word_length - 2;
-        select_word_lower_index = (check_word_length - 2) as isize - select_word_range as isize;
-    } else {
-        select_word_upper_index = (check_word_length - 2) + select_word_range;
-        select_word_lower_index = (check_word_length - 2) as isize - select_word_range as isize;
     }
 
-    // インデックス範囲を調整
-    select_word_lower_index = select_word_lower_index.max(0); // 下限は0にする
-    select_word_upper_index = select_word_upper_index.min(word_dic.len()); // 上限はword_dicの長さにする
+    // よくある綴り間違いに該当する場合、曖昧検索をせずにその正しい単語を候補として返す
+    if options.use_common_misspellings {
+        output
+            .telemetry
+            .pass_list
+            .push(SuggestionSource::CommonMisspellings);
+        if let Some(correct_word) = common_misspelling_map()
+            .get(lowercase_check_word.as_str())
+            .filter(|correct_word| {
+                !dictionary.contains(&lowercase_check_word)
+                    && !dictionary.is_forbidden(correct_word)
+            })
+        {
+            let similar_word_list = vec![SimilarWord {
+                spelling: correct_word.to_string(),
+                levenshtein_length: levenshtein(&lowercase_check_word, correct_word),
+                typo_type: TypoType::CommonMisspelling,
+                source: SuggestionSource::CommonMisspellings,
+            }];
+            output.telemetry.end_stage(started_at, |stage_timings| {
+                &mut stage_timings.candidate_generation
+            });
+            output.similar_word_list = Some(rank_similar_words(
+                lowercase_check_word,
+                check_word_length,
+                similar_word_list,
+                options,
+                character_table,
+                &mut output.telemetry,
+            ));
+            return output;
+        }
+    }
+
+    // 文字数がselect_word_range少ない単語から多い単語までを探索する
+    let lower_word_length_range =
+        check_word_length.saturating_sub(select_word_range).max(1)..check_word_length;
+    let upper_word_length_range = check_word_length + 1..check_word_length + select_word_range + 1;
 
-    let same_length_word_dic = &word_dic[check_word_length - 2];
-    let selected_lower_word_dic =
-        &word_dic[select_word_lower_index as usize..check_word_length - 2]; // isizeをusizeにキャスト
-    let selected_upper_word_dic = &word_dic[check_word_length - 1..select_word_upper_index];
+    let prescreen = Prescreen::new(
+        &lowercase_check_word,
+        options.search_levenshtein_cutoff(check_word_length),
+        options,
+    );
+    output.telemetry.end_stage(started_at, |stage_timings| {
+        &mut stage_timings.candidate_generation
+    });
 
     // 完全に一致する単語を探索する
-    for temp_word in same_length_word_dic.iter() {
-        match temp_word {
-            Some(word) => {
-                let levenshtein_length = levenshtein(&lowercase_check_word, &word);
-
-                if levenshtein_length == 0 {
-                    output.match_word = Some(word.to_string());
-                    output.similar_word_list = None;
-                    return output;
-                } else {
-                    similar_word_list.push(SimilarWord::new(word.to_string(), levenshtein_length));
-                }
-            }
-            None => break,
-        };
+    let started_at = output.telemetry.start_stage();
+    for word in dictionary.words_with_length(check_word_length) {
+        if prescreen
+            .as_ref()
+            .is_some_and(|prescreen| !prescreen.passes(word))
+        {
+            output.telemetry.screened_out_num += 1;
+            continue;
+        }
+        let levenshtein_length = levenshtein(&lowercase_check_word, word);
+
+        if levenshtein_length == 0 {
+            output.match_word = Some(word.to_string());
+            output.match_metadata = dictionary.metadata(word).cloned();
+            output.telemetry.scanned_word_num =
+                similar_word_list.len() + output.telemetry.screened_out_num + 1;
+            output.telemetry.pass_list = vec![SuggestionSource::FuzzySearch];
+            // Caps Lockで入力された単語は小文字にすると一致するため、反転した綴りを報告する
+            output.similar_word_list = caps_lock_correction.map(|spelling| {
+                vec![SimilarWord {
+                    spelling,
+                    levenshtein_length: 0,
+                    typo_type: TypoType::CapsLock,
+                    source: SuggestionSource::FuzzySearch,
+                }]
+            });
+            output
+                .telemetry
+                .end_stage(started_at, |stage_timings| &mut stage_timings.distance);
+            return output;
+        } else {
+            similar_word_list.push(SimilarWord::new(word.to_string(), levenshtein_length));
+        }
     }
 
     // 類似する単語を探す(探す単語よりも文字数がselect_word_range少ないもの)
     similar_word_list = calculate_word_list_levenshtein_length(
-        selected_lower_word_dic,
+        dictionary,
+        lower_word_length_range,
         &lowercase_check_word,
         similar_word_list,
+        prescreen.as_ref(),
+        &mut output.telemetry,
     );
 
     // 類似する単語を探す(探す単語よりも文字数がselect_word_range多いもの)
     similar_word_list = calculate_word_list_levenshtein_length(
-        selected_upper_word_dic,
+        dictionary,
+        upper_word_length_range,
         &lowercase_check_word,
         similar_word_list,
+        prescreen.as_ref(),
+        &mut output.telemetry,
     );
 
-    output.similar_word_list = Some(get_top_similar_words(
+    output.telemetry.scanned_word_num = similar_word_list.len() + output.telemetry.screened_out_num;
+    output
+        .telemetry
+        .end_stage(started_at, |stage_timings| &mut stage_timings.distance);
+    output
+        .telemetry
+        .pass_list
+        .push(SuggestionSource::FuzzySearch);
+
+    // メタデータで候補にしないとされた単語を除く
+    similar_word_list.retain(|similar_word| !dictionary.is_forbidden(&similar_word.spelling));
+    let split_word_list = find_split_words(&lowercase_check_word, options, dictionary);
+    let mut ranked_word_list = rank_similar_words(
         lowercase_check_word,
         check_word_length,
         similar_word_list,
-        output_levenshtein_cutoff,
-        pickup_similar_word_num,
-        sort_order_of_typo_type,
-    ));
+        options,
+        character_table,
+        &mut output.telemetry,
+    );
+
+    // 分割した候補は距離が1より大きい候補より上位にする
+    if !split_word_list.is_empty() {
+        output.telemetry.pass_list.push(SuggestionSource::WordSplit);
+        let insert_index = ranked_word_list
+            .iter()
+            .position(|similar_word| similar_word.levenshtein_length > 1)
+            .unwrap_or(ranked_word_list.len());
+        ranked_word_list.splice(insert_index..insert_index, split_word_list);
+        ranked_word_list.truncate(options.pickup_similar_word_num);
+    }
+    output.similar_word_list = Some(ranked_word_list);
 
     output
 }
@@ -669,97 +2596,466 @@ mod tests {
     }
 
     #[test]
-    fn test_find_extra_chars_head() {
-        // Head の余分な文字テストケース
-        let check_word = "ahello";
-        let similar_word = SimilarWord::new("hello".to_string(), 1);
-        let result = find_missing_or_extra_chars(check_word, similar_word);
-
-        assert_eq!(
-            result.typo_type,
-            TypoType::ExtraCharacters {
-                character: 'a',
-                position: CharacterPositon::Head
-            }
-        );
+    fn test_find_extra_chars_head() {
+        // Head の余分な文字テストケース
+        let check_word = "ahello";
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars(check_word, similar_word);
+
+        assert_eq!(
+            result.typo_type,
+            TypoType::ExtraCharacters {
+                character: 'a',
+                position: CharacterPositon::Head,
+                adjacent_to: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_extra_chars_tail() {
+        // Tail の余分な文字テストケース
+        let check_word = "helloo";
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars(check_word, similar_word);
+
+        assert_eq!(
+            result.typo_type,
+            TypoType::ExtraCharacters {
+                character: 'o',
+                position: CharacterPositon::Tail,
+                adjacent_to: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_extra_chars_adjacent_key() {
+        // 直前の「o」の隣の「i」を押した
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars("helloi", similar_word);
+        assert_eq!(
+            result.typo_type,
+            TypoType::ExtraCharacters {
+                character: 'i',
+                position: CharacterPositon::Tail,
+                adjacent_to: Some('o')
+            }
+        );
+
+        // 直後の「h」の隣の「j」を押した
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars("jhello", similar_word);
+        assert!(result.is_adjacent_extra_character());
+
+        // 隣のキーを押した単語が上位になる
+        let mut similar_word_list = vec![
+            find_missing_or_extra_chars("helloa", SimilarWord::new("hello".to_string(), 1)),
+            result,
+        ];
+        SimilarWord::sort_by_typo_type(&mut similar_word_list, &default_sort_order_of_typo_type());
+        assert!(similar_word_list[0].is_adjacent_extra_character());
+    }
+
+    #[test]
+    fn test_find_typo_type_none() {
+        // 正しい単語の場合のテストケース
+        let check_word = "hello";
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars(check_word, similar_word);
+
+        assert_eq!(result.typo_type, TypoType::UndefinedType);
+    }
+
+    #[test]
+    fn test_find_missing_or_extra_chars_middle() {
+        let result = find_missing_or_extra_chars("aple", SimilarWord::new("apple".to_string(), 1));
+        assert_eq!(
+            result.typo_type,
+            TypoType::MissingCharacters {
+                character: 'p',
+                position: CharacterPositon::Middle(1),
+            }
+        );
+
+        // "o"の隣の"i"は隣のキーを押した余分な文字とする
+        let result = find_missing_or_extra_chars("hoise", SimilarWord::new("hose".to_string(), 1));
+        assert_eq!(
+            result.typo_type,
+            TypoType::ExtraCharacters {
+                character: 'i',
+                position: CharacterPositon::Middle(2),
+                adjacent_to: Some('o'),
+            }
+        );
+
+        let result = find_missing_or_extra_chars("hvuse", SimilarWord::new("house".to_string(), 1));
+        assert_eq!(result.typo_type, TypoType::UndefinedType);
+    }
+
+    #[test]
+    fn test_find_multiple_missing_chars() {
+        // 複数の文字が足りない場合のテストケース
+        let check_word = "hlo";
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars(check_word, similar_word);
+
+        assert_eq!(result.typo_type, TypoType::UndefinedType);
+    }
+
+    #[test]
+    fn test_find_multiple_extra_chars() {
+        // 複数の文字が余分な場合のテストケース
+        let check_word = "heelllo";
+        let similar_word = SimilarWord::new("hello".to_string(), 1);
+        let result = find_missing_or_extra_chars(check_word, similar_word);
+
+        assert_eq!(result.typo_type, TypoType::UndefinedType);
+    }
+
+    #[test]
+    fn test_find_different_a_char_similar_shapes() {
+        let check_word = "cot";
+        let temp_word = SimilarWord::new("cat".to_string(), 1);
+        let result = find_different_a_char(check_word, temp_word);
+
+        if let TypoType::SimilarShapes = result.typo_type {
+            // テストが通れば成功
+        } else {
+            panic!(
+                "Expected TypoType::SimilarShapes but got {:?}",
+                result.typo_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_different_a_char_close_keyboard_placement() {
+        let check_word = "try".to_string();
+        let similar_word = SimilarWord {
+            spelling: "trt".to_string(), // "y" -> "t" は隣接キーだが SimilarShapes には該当しない
+            levenshtein_length: 1,
+            typo_type: TypoType::UndefinedType,
+            source: SuggestionSource::FuzzySearch,
+        };
+
+        // `find_different_a_char`関数を呼び出して、誤りのタイプを判別
+        let result = find_different_a_char(&check_word, similar_word);
+
+        // `TypoType::CloseKeyboardPlacement` が設定されているか確認
+        assert!(matches!(result.typo_type, TypoType::CloseKeyboardPlacement));
+    }
+
+    #[test]
+    fn test_find_different_a_char_unusual_characters() {
+        // 表に無い文字でもパニックしない
+        let result = find_different_a_char("ver2", SimilarWord::new("ver3".to_string(), 1));
+        assert_eq!(result.typo_type, TypoType::UndefinedType);
+        let result = find_different_a_char("café", SimilarWord::new("cafe".to_string(), 1));
+        assert_eq!(result.typo_type, TypoType::UndefinedType);
+
+        // 大文字は小文字にして判別する
+        let result = find_different_a_char("TEXT", SimilarWord::new("test".to_string(), 1));
+        assert_eq!(result.typo_type, TypoType::CloseKeyboardPlacement);
+    }
+
+    #[test]
+    fn test_keyboard_adjacency_option() {
+        let options = CheckOptions::default().keyboard_adjacency('2', vec!['1', '3']);
+        let similar_word_list = vec![SimilarWord::new("ver3".to_string(), 1)];
+
+        let result = get_top_similar_words("ver2".to_string(), 4, similar_word_list, &options);
+
+        assert_eq!(result[0].typo_type, TypoType::CloseKeyboardPlacement);
+    }
+
+    #[test]
+    fn test_search_telemetry() {
+        let result = check_a_word_with("applo", &CheckOptions::default());
+        let telemetry = result.get_telemetry();
+
+        assert!(telemetry.scanned_word_num > telemetry.passed_cutoff_num);
+        assert!(telemetry.passed_cutoff_num >= result.get_similar_word_list().len());
+        assert_eq!(
+            telemetry.pass_list,
+            vec![
+                SuggestionSource::CommonMisspellings,
+                SuggestionSource::FuzzySearch
+            ]
+        );
+
+        let options = CheckOptions::default().use_common_misspellings(false);
+        let result = check_a_word_with("apple", &options);
+        assert_eq!(result.get_telemetry().passed_cutoff_num, 0);
+        assert_eq!(
+            result.get_telemetry().pass_list,
+            vec![SuggestionSource::FuzzySearch]
+        );
+    }
+
+    #[test]
+    fn test_result_does_not_depend_on_candidate_order() {
+        let check_word = "applo";
+        let candidate_list: Vec<SimilarWord> = dictionary::DICTIONARY[2..5]
+            .iter()
+            .flat_map(|same_length_word_list| same_length_word_list.iter().map_while(|word| *word))
+            .map(|word| SimilarWord::new(word.to_string(), levenshtein(check_word, word)))
+            .collect();
+        let mut shuffled_candidate_list = candidate_list.clone();
+        shuffled_candidate_list.reverse();
+        shuffled_candidate_list.rotate_left(candidate_list.len() / 3);
+
+        for options in [
+            CheckOptions::default().pickup_similar_word_num(usize::MAX),
+            CheckOptions::default().ranking_weights(RankingWeights::default()),
+        ] {
+            assert_eq!(
+                get_top_similar_words(check_word.to_string(), 5, candidate_list.clone(), &options),
+                get_top_similar_words(
+                    check_word.to_string(),
+                    5,
+                    shuffled_candidate_list.clone(),
+                    &options
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_excluded_suggestions_option() {
+        let options = CheckOptions::default();
+        let result = check_a_word_with("applo", &options);
+        assert_eq!(result.get_similar_word_list()[0].spelling, "apple");
+
+        let options = options.excluded_suggestions(["Apple", "teh"]);
+        let result = check_a_word_with("applo", &options);
+        assert!(result
+            .get_similar_word_list()
+            .iter()
+            .all(|word| word.spelling != "apple"));
+        assert_eq!(result.get_similar_word_list().len(), 5);
+
+        // Caps Lockの候補も除外する
+        let result = check_a_word_with("aPPLE", &options);
+        assert_eq!(result.get_match_word(), "apple");
+        assert!(result.get_similar_word_list().is_empty());
+    }
+
+    #[test]
+    fn test_check_with_timings() {
+        let options = CheckOptions::default();
+        let (result, stage_timings) = check_with_timings("applo", &options);
+
+        assert_eq!(
+            result.get_similar_word_list(),
+            check_a_word_with("applo", &options).get_similar_word_list()
+        );
+        assert!(stage_timings.distance > Duration::ZERO);
+        assert_eq!(result.get_telemetry().stage_timings, Some(stage_timings));
+        assert_eq!(
+            stage_timings.total(),
+            stage_timings.candidate_generation
+                + stage_timings.distance
+                + stage_timings.classification
+                + stage_timings.sort
+        );
+
+        // 通常のチェックでは計測しない
+        assert_eq!(
+            check_a_word_with("applo", &options)
+                .get_telemetry()
+                .stage_timings,
+            None
+        );
+    }
+
+    #[test]
+    fn test_prescreen_does_not_change_result() {
+        // よくある綴り間違いは探索しないため、参照しない
+        let options = CheckOptions::default()
+            .pickup_similar_word_num(usize::MAX)
+            .use_common_misspellings(false);
+        for check_word in ["applo", "teh", "recieve", "hELLO", "accomodation"] {
+            let screened_result = check_a_word_with(check_word, &options);
+            let result = check_a_word_with(check_word, &options.clone().prescreen(false));
+
+            assert_eq!(
+                screened_result.get_similar_word_list(),
+                result.get_similar_word_list(),
+                "{}",
+                check_word
+            );
+            assert!(screened_result.get_telemetry().screened_out_num > 0);
+            assert_eq!(
+                screened_result.get_telemetry().scanned_word_num,
+                result.get_telemetry().scanned_word_num
+            );
+        }
     }
 
     #[test]
-    fn test_find_extra_chars_tail() {
-        // Tail の余分な文字テストケース
-        let check_word = "helloo";
-        let similar_word = SimilarWord::new("hello".to_string(), 1);
-        let result = find_missing_or_extra_chars(check_word, similar_word);
+    fn test_widen_cutoff_option() {
+        let similar_word_list = vec![
+            SimilarWord::new("abcd".to_string(), 1),
+            SimilarWord::new("wxyz".to_string(), 4),
+            SimilarWord::new("abxy".to_string(), 3),
+            SimilarWord::new("axyz".to_string(), 3),
+        ];
 
-        assert_eq!(
-            result.typo_type,
-            TypoType::ExtraCharacters {
-                character: 'o',
-                position: CharacterPositon::Tail
-            }
-        );
+        let options = CheckOptions::default().widen_cutoff(3, 3);
+        let result =
+            get_top_similar_words("abce".to_string(), 4, similar_word_list.clone(), &options);
+        assert_eq!(result.len(), 3);
+
+        // 候補が足りている場合は広げない
+        let options = CheckOptions::default().widen_cutoff(1, 4);
+        let result = get_top_similar_words("abce".to_string(), 4, similar_word_list, &options);
+        assert_eq!(result.len(), 1);
     }
 
     #[test]
-    fn test_find_typo_type_none() {
-        // 正しい単語の場合のテストケース
-        let check_word = "hello";
-        let similar_word = SimilarWord::new("hello".to_string(), 1);
-        let result = find_missing_or_extra_chars(check_word, similar_word);
+    fn test_max_length_delta_option() {
+        let options = CheckOptions::default().max_length_delta(0);
+        let similar_word_list = vec![
+            SimilarWord::new("cats".to_string(), 1),
+            SimilarWord::new("cot".to_string(), 1),
+            SimilarWord::new("ca".to_string(), 1),
+        ];
 
-        assert_eq!(result.typo_type, TypoType::UndefinedType);
+        let result = get_top_similar_words("cat".to_string(), 3, similar_word_list, &options);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].spelling, "cot");
     }
 
     #[test]
-    fn test_find_multiple_missing_chars() {
-        // 複数の文字が足りない場合のテストケース
-        let check_word = "hlo";
-        let similar_word = SimilarWord::new("hello".to_string(), 1);
-        let result = find_missing_or_extra_chars(check_word, similar_word);
+    fn test_to_debug_table() {
+        let mut typo_check_result = TypoCheckResult::new("applo".to_string());
+        typo_check_result.similar_word_list = Some(vec![
+            SimilarWord {
+                spelling: "apple".to_string(),
+                levenshtein_length: 1,
+                typo_type: TypoType::CloseKeyboardPlacement,
+                source: SuggestionSource::FuzzySearch,
+            },
+            SimilarWord::new("apply".to_string(), 1),
+        ]);
 
-        assert_eq!(result.typo_type, TypoType::UndefinedType);
+        assert_eq!(
+            typo_check_result.to_debug_table(),
+            "check_word: applo\n\
+             match_word: -\n\
+             rank  suggestion  distance  type                    score\n\
+             1     apple       1         CloseKeyboardPlacement  0.40\n\
+             2     apply       1         UndefinedType           -0.60\n"
+        );
     }
 
     #[test]
-    fn test_find_multiple_extra_chars() {
-        // 複数の文字が余分な場合のテストケース
-        let check_word = "heelllo";
-        let similar_word = SimilarWord::new("hello".to_string(), 1);
-        let result = find_missing_or_extra_chars(check_word, similar_word);
+    fn test_get_top_similar_words_dedup_multi_source() {
+        let similar_word_list = vec![
+            SimilarWord::new("the".to_string(), 2),
+            SimilarWord::new("ten".to_string(), 1),
+            SimilarWord {
+                spelling: "the".to_string(),
+                levenshtein_length: 2,
+                typo_type: TypoType::CommonMisspelling,
+                source: SuggestionSource::FuzzySearch,
+            },
+            SimilarWord::new("ten".to_string(), 1),
+        ];
 
-        assert_eq!(result.typo_type, TypoType::UndefinedType);
+        let result = get_top_similar_words(
+            "teh".to_string(),
+            3,
+            similar_word_list,
+            &CheckOptions::default(),
+        );
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].spelling, "the");
+        assert_eq!(result[0].typo_type, TypoType::CommonMisspelling);
+        assert_eq!(result[1].spelling, "ten");
+        assert_eq!(result[1].typo_type, TypoType::CloseKeyboardPlacement);
     }
 
     #[test]
-    fn test_find_different_a_char_similar_shapes() {
-        let check_word = "cot";
-        let temp_word = SimilarWord::new("cat".to_string(), 1);
-        let result = find_different_a_char(check_word, temp_word);
-
-        if let TypoType::SimilarShapes = result.typo_type {
-            // テストが通れば成功
-        } else {
-            panic!(
-                "Expected TypoType::SimilarShapes but got {:?}",
-                result.typo_type
+    fn test_classify_repeated_character() {
+        let character_table = CharacterTable::default();
+        for (check_word, spelling, expected_typo_type) in [
+            (
+                "helllo",
+                "hello",
+                TypoType::RepeatedCharacter { character: 'l' },
+            ),
+            (
+                "occassion",
+                "occasion",
+                TypoType::RepeatedCharacter { character: 's' },
+            ),
+            (
+                "heeelllo",
+                "hello",
+                TypoType::RepeatedCharacter { character: 'e' },
+            ),
+            // 足りない繰り返しは判別しない
+            (
+                "helo",
+                "hello",
+                TypoType::MissingCharacters {
+                    character: 'l',
+                    position: CharacterPositon::Middle(2),
+                },
+            ),
+        ] {
+            let similar_word =
+                SimilarWord::new(spelling.to_string(), levenshtein(check_word, spelling));
+            let similar_word = classify_similar_word(
+                check_word,
+                check_word.chars().count(),
+                similar_word,
+                &character_table,
             );
+            assert_eq!(similar_word.typo_type, expected_typo_type, "{}", check_word);
         }
     }
 
     #[test]
-    fn test_find_different_a_char_close_keyboard_placement() {
-        let check_word = "try".to_string();
-        let similar_word = SimilarWord {
-            spelling: "trt".to_string(), // "y" -> "t" は隣接キーだが SimilarShapes には該当しない
-            levenshtein_length: 1,
-            typo_type: TypoType::UndefinedType,
-        };
-
-        // `find_different_a_char`関数を呼び出して、誤りのタイプを判別
-        let result = find_different_a_char(&check_word, similar_word);
+    fn test_classify_transposition() {
+        let character_table = CharacterTable::default();
+        for (check_word, spelling, expected_typo_type) in [
+            ("hte", "the", TypoType::Transposition),
+            ("wrold", "world", TypoType::Transposition),
+            ("recieve", "receive", TypoType::Transposition),
+            (
+                "wrld",
+                "world",
+                TypoType::MissingCharacters {
+                    character: 'o',
+                    position: CharacterPositon::Middle(1),
+                },
+            ),
+            // 離れた2文字の入れ替えは判別しない
+            ("wlrod", "world", TypoType::UndefinedType),
+        ] {
+            let similar_word =
+                SimilarWord::new(spelling.to_string(), levenshtein(check_word, spelling));
+            let similar_word = classify_similar_word(
+                check_word,
+                check_word.chars().count(),
+                similar_word,
+                &character_table,
+            );
+            assert_eq!(similar_word.typo_type, expected_typo_type, "{}", check_word);
+        }
 
-        // `TypoType::CloseKeyboardPlacement` が設定されているか確認
-        assert!(matches!(result.typo_type, TypoType::CloseKeyboardPlacement));
+        let typo_chec_result = check_a_word_with("wrold", &CheckOptions::default());
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert_eq!(similar_word_list[0].get_spelling(), "world");
+        assert_eq!(
+            similar_word_list[0].get_typo_type(),
+            &TypoType::Transposition
+        );
     }
 
     #[test]
@@ -787,16 +3083,19 @@ mod tests {
                 spelling: "test".to_string(),
                 levenshtein_length: 1,
                 typo_type: TypoType::UndefinedType,
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tsts".to_string(),
                 levenshtein_length: 1,
                 typo_type: TypoType::CloseKeyboardPlacement,
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tots".to_string(),
                 levenshtein_length: 1,
                 typo_type: TypoType::SimilarShapes,
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "ttets".to_string(),
@@ -804,7 +3103,9 @@ mod tests {
                 typo_type: TypoType::ExtraCharacters {
                     character: 's',
                     position: CharacterPositon::Head,
+                    adjacent_to: None,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tetss".to_string(),
@@ -812,7 +3113,9 @@ mod tests {
                 typo_type: TypoType::ExtraCharacters {
                     character: 's',
                     position: CharacterPositon::Tail,
+                    adjacent_to: None,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "ets".to_string(),
@@ -821,6 +3124,7 @@ mod tests {
                     character: 't',
                     position: CharacterPositon::Head,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tet".to_string(),
@@ -829,6 +3133,7 @@ mod tests {
                     character: 's',
                     position: CharacterPositon::Tail,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
         ];
 
@@ -836,9 +3141,12 @@ mod tests {
             check_word,
             check_word_length,
             similar_word_list,
-            None,
-            7,
-            None,
+            &CheckOptions {
+                output_levenshtein_cutoff: None,
+                pickup_similar_word_num: 7,
+                sort_order_of_typo_type: None,
+                ..CheckOptions::default()
+            },
         );
 
         // デフォルトの並び順: ExtraCharacters -> MissingCharacters -> SimilarShapes -> CloseKeyboardPlacement -> UndefinedType
@@ -881,9 +3189,12 @@ mod tests {
             check_word,
             check_word_length,
             similar_word_list,
-            None,
-            2,
-            None,
+            &CheckOptions {
+                output_levenshtein_cutoff: None,
+                pickup_similar_word_num: 2,
+                sort_order_of_typo_type: None,
+                ..CheckOptions::default()
+            },
         );
 
         assert_eq!(result.len(), 2);
@@ -905,9 +3216,12 @@ mod tests {
             check_word,
             check_word_length,
             similar_word_list,
-            Some(2),
-            3,
-            None,
+            &CheckOptions {
+                output_levenshtein_cutoff: Some(2),
+                pickup_similar_word_num: 3,
+                sort_order_of_typo_type: None,
+                ..CheckOptions::default()
+            },
         );
 
         assert_eq!(result.len(), 2);
@@ -923,16 +3237,19 @@ mod tests {
                 spelling: "test".to_string(),
                 levenshtein_length: 1,
                 typo_type: TypoType::UndefinedType,
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tsts".to_string(),
                 levenshtein_length: 1,
                 typo_type: TypoType::CloseKeyboardPlacement,
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tots".to_string(),
                 levenshtein_length: 1,
                 typo_type: TypoType::SimilarShapes,
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "ttets".to_string(),
@@ -940,7 +3257,9 @@ mod tests {
                 typo_type: TypoType::ExtraCharacters {
                     character: 's',
                     position: CharacterPositon::Head,
+                    adjacent_to: None,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tetss".to_string(),
@@ -948,7 +3267,9 @@ mod tests {
                 typo_type: TypoType::ExtraCharacters {
                     character: 's',
                     position: CharacterPositon::Tail,
+                    adjacent_to: None,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "ets".to_string(),
@@ -957,6 +3278,7 @@ mod tests {
                     character: 't',
                     position: CharacterPositon::Head,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
             SimilarWord {
                 spelling: "tet".to_string(),
@@ -965,6 +3287,7 @@ mod tests {
                     character: 's',
                     position: CharacterPositon::Tail,
                 },
+                source: SuggestionSource::FuzzySearch,
             },
         ];
 
@@ -975,6 +3298,7 @@ mod tests {
             TypoType::ExtraCharacters {
                 character: 'A',
                 position: CharacterPositon::Head,
+                adjacent_to: None,
             },
             TypoType::MissingCharacters {
                 character: 'Z',
@@ -986,9 +3310,12 @@ mod tests {
             check_word,
             check_word_length,
             similar_word_list,
-            None,
-            7,
-            Some(&custom_sort_order),
+            &CheckOptions {
+                output_levenshtein_cutoff: None,
+                pickup_similar_word_num: 7,
+                sort_order_of_typo_type: Some(custom_sort_order),
+                ..CheckOptions::default()
+            },
         );
 
         assert_eq!(result.len(), 7);
@@ -1030,11 +3357,450 @@ mod tests {
             check_word,
             check_word_length,
             similar_word_list,
-            None,
-            1,
-            None,
+            &CheckOptions {
+                output_levenshtein_cutoff: None,
+                pickup_similar_word_num: 1,
+                sort_order_of_typo_type: None,
+                ..CheckOptions::default()
+            },
         );
 
         assert_eq!(result.len(), 1);
     }
+
+    #[test]
+    fn test_get_top_similar_words_typo_type_limits() {
+        let check_word = "test".to_string();
+        let check_word_length = check_word.len();
+        let similar_word_list = vec![
+            SimilarWord::new("tost".to_string(), 1),
+            SimilarWord::new("best".to_string(), 1),
+            SimilarWord::new("nest".to_string(), 1),
+            SimilarWord::new("tent".to_string(), 1),
+            SimilarWord::new("text".to_string(), 1),
+        ];
+
+        let result = get_top_similar_words(
+            check_word,
+            check_word_length,
+            similar_word_list,
+            &CheckOptions {
+                output_levenshtein_cutoff: None,
+                pickup_similar_word_num: 5,
+                typo_type_limits: vec![(TypoType::UndefinedType, 1)],
+                ..CheckOptions::default()
+            },
+        );
+
+        // SimilarShapes(tost)とCloseKeyboardPlacement(text)は制限されず、UndefinedTypeは1つまでになる
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].spelling, "tost");
+        assert_eq!(result[1].spelling, "text");
+        assert!(matches!(result[2].typo_type, TypoType::UndefinedType));
+    }
+
+    #[test]
+    fn test_did_you_mean_message() {
+        let result = TypoCheckResult {
+            check_word: "appel".to_string(),
+            match_word: None,
+            similar_word_list: Some(vec![
+                SimilarWord::new("apple".to_string(), 1),
+                SimilarWord::new("apply".to_string(), 1),
+                SimilarWord::new("applet".to_string(), 2),
+            ]),
+            telemetry: SearchTelemetry::default(),
+            match_metadata: None,
+            abbreviation_expansion: None,
+            caps_lock_inversion: None,
+        };
+
+        assert_eq!(
+            result.did_you_mean(3),
+            Some("did you mean \"apple\", \"apply\" or \"applet\"?".to_string())
+        );
+        assert_eq!(
+            result.did_you_mean(1),
+            Some("did you mean \"apple\"?".to_string())
+        );
+        assert_eq!(result.did_you_mean(0), None);
+    }
+
+    #[test]
+    fn test_did_you_mean_exact_match() {
+        let result = TypoCheckResult {
+            check_word: "apple".to_string(),
+            match_word: Some("apple".to_string()),
+            similar_word_list: None,
+            telemetry: SearchTelemetry::default(),
+            match_metadata: None,
+            abbreviation_expansion: None,
+            caps_lock_inversion: None,
+        };
+
+        assert_eq!(result.did_you_mean(3), None);
+    }
+
+    #[test]
+    fn test_filter_similar_word_list() {
+        let result = TypoCheckResult {
+            check_word: "tets".to_string(),
+            match_word: None,
+            similar_word_list: Some(vec![
+                SimilarWord {
+                    spelling: "tsts".to_string(),
+                    levenshtein_length: 1,
+                    typo_type: TypoType::CloseKeyboardPlacement,
+                    source: SuggestionSource::FuzzySearch,
+                },
+                SimilarWord {
+                    spelling: "tets".to_string(),
+                    levenshtein_length: 2,
+                    typo_type: TypoType::CloseKeyboardPlacement,
+                    source: SuggestionSource::FuzzySearch,
+                },
+                SimilarWord {
+                    spelling: "tots".to_string(),
+                    levenshtein_length: 1,
+                    typo_type: TypoType::SimilarShapes,
+                    source: SuggestionSource::FuzzySearch,
+                },
+                SimilarWord {
+                    spelling: "ttetss".to_string(),
+                    levenshtein_length: 2,
+                    typo_type: TypoType::UndefinedType,
+                    source: SuggestionSource::FuzzySearch,
+                },
+            ]),
+            telemetry: SearchTelemetry::default(),
+            match_metadata: None,
+            abbreviation_expansion: None,
+            caps_lock_inversion: None,
+        };
+
+        let close_word_list = result
+            .filter()
+            .types([TypoType::CloseKeyboardPlacement])
+            .max_distance(1)
+            .collect();
+        assert_eq!(close_word_list.len(), 1);
+        assert_eq!(close_word_list[0].spelling, "tsts");
+
+        let same_length_word_list = result.filter().max_length_delta(0).collect();
+        assert_eq!(same_length_word_list.len(), 3);
+        assert_eq!(result.filter().collect().len(), 4);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_check_a_word_matches_baseline() {
+        // 1.0.0のcheck_a_word(単語, Some(2), 似ている単語数, None)の出力。
+        // "teh"の3番目以降は、1.0.0で分類できなかった途中の余分な文字("th")が上位になるため比較しない
+        let baseline_list: [(&str, usize, Option<&str>, Vec<&str>); 7] = [
+            ("teh", 2, None, vec!["eh", "te"]),
+            ("hELLO", 5, Some("hello"), vec![]),
+            ("10km", 5, None, vec![]),
+            ("helloworld", 5, None, vec![]),
+            (
+                "recieve",
+                5,
+                None,
+                vec!["relieve", "believe", "receive", "reeve", "recede"],
+            ),
+            (
+                "wrold",
+                5,
+                None,
+                vec!["wold", "broad", "brood", "crowd", "droll"],
+            ),
+            (
+                "aplpe",
+                5,
+                None,
+                vec!["agape", "ample", "apace", "apple", "maple"],
+            ),
+        ];
+        for (check_word, pickup_similar_word_num, match_word, spelling_list) in baseline_list {
+            let result = check_a_word(
+                check_word.to_string(),
+                Some(2),
+                pickup_similar_word_num,
+                None,
+            );
+            assert_eq!(result.match_word.as_deref(), match_word, "{}", check_word);
+            let similar_spelling_list: Vec<String> = result
+                .get_similar_word_list()
+                .into_iter()
+                .map(|similar_word| similar_word.spelling)
+                .collect();
+            assert_eq!(similar_spelling_list, spelling_list, "{}", check_word);
+        }
+    }
+
+    #[test]
+    fn test_check_a_word_common_misspelling() {
+        let result = check_a_word_with("teh", &CheckOptions::default());
+        let similar_word_list = result.get_similar_word_list();
+
+        assert_eq!(similar_word_list[0].spelling, "the");
+        assert_eq!(similar_word_list[0].typo_type, TypoType::CommonMisspelling);
+        assert_eq!(
+            similar_word_list
+                .iter()
+                .filter(|word| word.spelling == "the")
+                .count(),
+            1
+        );
+
+        // よくある綴り間違いは曖昧検索の前に参照し、辞書を走査せずに返す
+        assert_eq!(result.get_telemetry().scanned_word_num, 0);
+        assert_eq!(
+            result.get_telemetry().pass_list,
+            vec![SuggestionSource::CommonMisspellings]
+        );
+
+        // "alot"は"a lot"の誤りのため、"lot"をよくある綴り間違いとしない
+        let result = check_a_word_with("alot", &CheckOptions::default());
+        assert!(result
+            .get_similar_word_list()
+            .iter()
+            .all(|similar_word| similar_word.typo_type != TypoType::CommonMisspelling));
+    }
+
+    #[test]
+    fn test_sort_by_typo_type_missing_order() {
+        let mut similar_word_list = vec![
+            SimilarWord {
+                spelling: "the".to_string(),
+                levenshtein_length: 2,
+                typo_type: TypoType::CommonMisspelling,
+                source: SuggestionSource::FuzzySearch,
+            },
+            SimilarWord::new("ten".to_string(), 1),
+        ];
+
+        // ソート条件に含まれないCommonMisspellingは末尾になる
+        SimilarWord::sort_by_typo_type(&mut similar_word_list, &[TypoType::UndefinedType]);
+
+        assert_eq!(similar_word_list[0].spelling, "ten");
+    }
+
+    #[test]
+    fn test_find_caps_lock_inversion() {
+        assert_eq!(find_caps_lock_inversion("hELLO"), Some("Hello".to_string()));
+        assert_eq!(
+            find_caps_lock_inversion("WORKing"),
+            Some("working".to_string())
+        );
+        assert_eq!(find_caps_lock_inversion("HELLO"), None);
+        assert_eq!(find_caps_lock_inversion("iPhone"), None);
+        assert_eq!(find_caps_lock_inversion("IDs"), None);
+    }
+
+    #[test]
+    fn test_check_a_word_caps_lock() {
+        use crate::test_support::assert_result_invariants;
+
+        let result = check_a_word_with("hELLO", &CheckOptions::default());
+        let similar_word_list = result.get_similar_word_list();
+
+        assert_eq!(result.get_match_word(), "hello");
+        assert_eq!(similar_word_list.len(), 1);
+        assert_eq!(similar_word_list[0].spelling, "Hello");
+        assert_eq!(similar_word_list[0].typo_type, TypoType::CapsLock);
+
+        // 反転した綴りにもタイポがある場合は、候補のTypoTypeを保ち、反転を別に報告する
+        let options = CheckOptions::default();
+        for check_word in ["hELLO", "WORKign", "iPHONE", "aPPLO"] {
+            assert_result_invariants(&check_a_word_with(check_word, &options), &options);
+        }
+        let result = check_a_word_with("WORKign", &options);
+        let similar_word_list = result.get_similar_word_list();
+
+        assert!(!result.is_match());
+        assert_eq!(result.get_caps_lock_inversion(), Some("workign"));
+        assert_eq!(similar_word_list[0].spelling, "working");
+        assert_eq!(similar_word_list[0].typo_type, TypoType::Transposition);
+        assert!(similar_word_list
+            .iter()
+            .all(|similar_word| similar_word.typo_type != TypoType::CapsLock));
+        assert!(check_a_word_with("working", &options)
+            .get_caps_lock_inversion()
+            .is_none());
+    }
+
+    #[test]
+    fn test_generic_levenshtein_token_sequence() {
+        let dna_a = [b'G', b'A', b'T', b'T', b'A', b'C', b'A'];
+        let dna_b = [b'G', b'C', b'A', b'T', b'G', b'C', b'U'];
+
+        assert_eq!(generic_levenshtein(dna_a, dna_b), 4);
+        assert_eq!(generic_levenshtein(Vec::<u8>::new(), dna_b), 7);
+        assert_eq!(generic_levenshtein(dna_a, Vec::<u8>::new()), 7);
+    }
+
+    #[test]
+    fn test_check_options_builder() {
+        let options = CheckOptions::default()
+            .output_levenshtein_cutoff(None)
+            .pickup_similar_word_num(3)
+            .sort_order_of_typo_type(vec![TypoType::SimilarShapes])
+            .typo_type_limit(TypoType::UndefinedType, 1)
+            .use_common_misspellings(false)
+            .detect_caps_lock(false);
+
+        assert_eq!(options.output_levenshtein_cutoff, None);
+        assert_eq!(options.pickup_similar_word_num, 3);
+        assert_eq!(
+            options.sort_order_of_typo_type,
+            Some(vec![TypoType::SimilarShapes])
+        );
+        assert_eq!(options.typo_type_limits, vec![(TypoType::UndefinedType, 1)]);
+        assert!(!options.use_common_misspellings);
+        assert!(!options.detect_caps_lock);
+        assert!(options.ranking_weights.is_none());
+    }
+
+    #[test]
+    fn test_check_a_word_with_length_aware_cutoff() {
+        let options = CheckOptions::default()
+            .length_aware_cutoff(true)
+            .pickup_similar_word_num(100);
+
+        // 短い単語は距離1のみ
+        let typo_chec_result = check_a_word_with("hte", &options);
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert!(!similar_word_list.is_empty());
+        assert!(similar_word_list
+            .iter()
+            .all(|word| word.levenshtein_length <= 1));
+
+        // 長い単語は距離3まで
+        let typo_chec_result = check_a_word_with("accomodation", &options);
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert!(similar_word_list
+            .iter()
+            .any(|word| word.levenshtein_length == 3));
+        // 最長の21文字の単語でもパニックしない
+        let typo_chec_result = check_a_word_with("counterrevolutionarie", &options);
+        assert!(typo_chec_result.match_word.is_none());
+    }
+
+    #[test]
+    fn test_check_a_word_with_skipped_word() {
+        let options = CheckOptions::default()
+            .min_word_length(3)
+            .stop_words(["teh"]);
+
+        let typo_chec_result = check_a_word_with("Teh", &options);
+        assert!(typo_chec_result.match_word.is_none());
+        assert!(typo_chec_result.similar_word_list.is_none());
+
+        let typo_chec_result = check_a_word_with("ot", &options);
+        assert!(typo_chec_result.similar_word_list.is_none());
+
+        let typo_chec_result = check_a_word_with("tge", &options);
+        assert!(!typo_chec_result.get_similar_word_list().is_empty());
+    }
+
+    #[test]
+    fn test_check_a_word_number_words() {
+        let options = CheckOptions::default();
+        assert!(check_a_word_with("Twenty-three", &options).is_match());
+        assert!(check_a_word_with("forty-second", &options).is_match());
+
+        let similar_word_list = check_a_word_with("twenty-tree", &options).get_similar_word_list();
+        let spelling_list: Vec<&str> = similar_word_list
+            .iter()
+            .map(SimilarWord::get_spelling)
+            .collect();
+        assert_eq!(spelling_list, vec!["twenty-three"]);
+
+        let similar_word_list = check_a_word_with("fourty", &options).get_similar_word_list();
+        assert_eq!(similar_word_list[0].get_spelling(), "forty");
+        assert_eq!(
+            similar_word_list[0].get_typo_type(),
+            &TypoType::CommonMisspelling
+        );
+    }
+
+    #[test]
+    fn test_check_a_word_split_suggestion() {
+        let options = CheckOptions::default();
+        let similar_word_list =
+            check_a_word_with("passwordmigration", &options).get_similar_word_list();
+        let spelling_list: Vec<&str> = similar_word_list
+            .iter()
+            .take(2)
+            .map(SimilarWord::get_spelling)
+            .collect();
+        assert_eq!(
+            spelling_list,
+            vec!["password migration", "password-migration"]
+        );
+        assert_eq!(
+            similar_word_list[0].get_typo_type(),
+            &TypoType::MissingCharacters {
+                character: ' ',
+                position: CharacterPositon::Middle(8),
+            }
+        );
+        assert_eq!(
+            similar_word_list[0].get_source(),
+            SuggestionSource::WordSplit
+        );
+
+        // 区切り文字の優先する順を変える
+        let options = CheckOptions::default().split_suggestion(10, vec!['-']);
+        let similar_word_list =
+            check_a_word_with("passwordmigration", &options).get_similar_word_list();
+        assert_eq!(similar_word_list[0].get_spelling(), "password-migration");
+        assert!(similar_word_list
+            .iter()
+            .all(|similar_word| similar_word.get_spelling() != "password migration"));
+
+        // 短い単語は分割しない
+        let options = CheckOptions::default().split_suggestion(20, vec![' ']);
+        assert!(check_a_word_with("passwordmigration", &options)
+            .get_similar_word_list()
+            .iter()
+            .all(|similar_word| similar_word.get_source() != SuggestionSource::WordSplit));
+    }
+
+    #[test]
+    fn test_check_a_word_with_abbreviations() {
+        let options = CheckOptions::default().abbreviations([("repo", "repository")]);
+
+        let typo_chec_result = check_a_word_with("Repo", &options);
+        assert_eq!(typo_chec_result.get_match_word(), "repo");
+        assert_eq!(
+            typo_chec_result.get_abbreviation_expansion(),
+            Some("repository")
+        );
+        assert!(typo_chec_result.did_you_mean(1).is_none());
+
+        // 略語でない単語は展開形を持たない
+        let typo_chec_result = check_a_word_with("repp", &options);
+        assert!(!typo_chec_result.is_match());
+        assert!(typo_chec_result.get_abbreviation_expansion().is_none());
+        assert!(check_a_word_with("repo", &CheckOptions::default())
+            .get_abbreviation_expansion()
+            .is_none());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_check_options_deserialize() {
+        let options: CheckOptions = toml::from_str(
+            "pickup_similar_word_num = 10\nsort_order_of_typo_type = [\"SimilarShapes\", \"UndefinedType\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(options.pickup_similar_word_num, 10);
+        assert_eq!(options.output_levenshtein_cutoff, Some(2));
+        assert_eq!(
+            options.sort_order_of_typo_type,
+            Some(vec![TypoType::SimilarShapes, TypoType::UndefinedType])
+        );
+    }
 }
@@ -0,0 +1,189 @@
+use crate::sentence::match_case;
+use crate::{TypoCheckResult, TypoType};
+
+/// Struct that stores a position in a text in the same way as the Language Server Protocol.
+/// `character` is counted in UTF-16 code units.
+///
+/// Language Server Protocolと同じ形式でテキストの位置を格納する構造体です。
+/// `character`はUTF-16のコード単位で数えます。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub line: u32,
+    pub character: u32,
+}
+
+/// Struct that stores a range in a text in the same way as the Language Server Protocol
+///
+/// Language Server Protocolと同じ形式でテキストの範囲を格納する構造体です
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+/// Struct that stores a replacement of a range in the same way as `TextEdit` of the Language Server Protocol
+///
+/// Language Server Protocolの`TextEdit`と同じ形式で範囲の置換を格納する構造体です
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TextEdit {
+    pub range: Range,
+    pub new_text: String,
+}
+
+/// Returns the LSP position of the byte offset in the text. Returns None if the offset is not on a character boundary.
+///
+/// テキストのバイト位置をLSPの位置に変換して返します。位置が文字の境界でない場合はNoneを返します。
+///
+/// # Arguments
+///
+/// * `text` - The whole text(テキスト全体)
+/// * `byte_offset` - Byte offset in the text(テキストのバイト位置)
+pub fn position_at(text: &str, byte_offset: usize) -> Option<Position> {
+    let before_text = text.get(..byte_offset)?;
+    let line_start = before_text.rfind('\n').map_or(0, |index| index + 1);
+    Some(Position {
+        line: before_text.matches('\n').count() as u32,
+        character: before_text[line_start..].encode_utf16().count() as u32,
+    })
+}
+
+/// Returns an LSP TextEdit for each similar word of the result, replacing the word at the byte offset in the text.
+/// Returns an empty list if the word matches the dictionary or the range is not in the text.
+///
+/// 結果の似ている単語ごとに、テキストのバイト位置にある単語を置き換えるLSPのTextEditを返します。
+/// 単語が辞書に一致した場合や、範囲がテキストに無い場合は空のリストを返します。
+///
+/// # Arguments
+///
+/// * `text` - The whole text(テキスト全体)
+/// * `byte_offset` - Byte offset of the checked word in the text(チェックした単語のテキストでのバイト位置)
+/// * `byte_length` - Byte length of the checked word in the text(チェックした単語のテキストでのバイト数)
+/// * `typo_check_result` - Result of typo check of the word(単語のタイポチェックの結果)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_a_word_with, quick_fix_edits, CheckOptions};
+///
+/// let text = "fn main() {\n    // hELLO world\n}";
+/// let byte_offset = text.find("hELLO").unwrap();
+/// let typo_chec_result = check_a_word_with("hELLO", &CheckOptions::default());
+///
+/// let text_edit_list = quick_fix_edits(text, byte_offset, "hELLO".len(), &typo_chec_result);
+/// assert_eq!(text_edit_list[0].new_text, "Hello");
+/// assert_eq!(text_edit_list[0].range.start.line, 1);
+/// assert_eq!(text_edit_list[0].range.start.character, 7);
+/// ```
+pub fn quick_fix_edits(
+    text: &str,
+    byte_offset: usize,
+    byte_length: usize,
+    typo_check_result: &TypoCheckResult,
+) -> Vec<TextEdit> {
+    let similar_word_list = typo_check_result
+        .similar_word_list
+        .as_deref()
+        .unwrap_or(&[]);
+    // Caps Lockの場合は一致する単語があっても修正を提示する
+    if typo_check_result.match_word.is_some()
+        && similar_word_list
+            .iter()
+            .all(|word| word.typo_type != TypoType::CapsLock)
+    {
+        return Vec::new();
+    }
+
+    let range = match (
+        position_at(text, byte_offset),
+        byte_offset
+            .checked_add(byte_length)
+            .and_then(|end| position_at(text, end)),
+    ) {
+        (Some(start), Some(end)) => Range { start, end },
+        _ => return Vec::new(),
+    };
+    let original = text
+        .get(byte_offset..byte_offset + byte_length)
+        .unwrap_or(&typo_check_result.check_word);
+    similar_word_list
+        .iter()
+        .map(|word| TextEdit {
+            range,
+            // Caps Lockの修正以外は元のトークンの大文字小文字に合わせる
+            new_text: if word.typo_type == TypoType::CapsLock {
+                word.spelling.clone()
+            } else {
+                match_case(original, &word.spelling)
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_a_word_with, CheckOptions};
+
+    #[test]
+    fn test_position_at_utf16() {
+        let text = "café\n🦀 applo";
+
+        assert_eq!(
+            position_at(text, text.find("applo").unwrap()),
+            Some(Position {
+                line: 1,
+                character: 3
+            })
+        );
+        assert_eq!(
+            position_at(text, 5),
+            Some(Position {
+                line: 0,
+                character: 4
+            })
+        );
+        // 文字の途中の位置
+        assert_eq!(position_at(text, 4), None);
+    }
+
+    #[test]
+    fn test_quick_fix_edits() {
+        let text = "the applo pie";
+        let typo_chec_result = check_a_word_with("applo", &CheckOptions::default());
+
+        let text_edit_list = quick_fix_edits(text, 4, 5, &typo_chec_result);
+        assert_eq!(
+            text_edit_list.len(),
+            typo_chec_result.get_similar_word_list().len()
+        );
+        assert_eq!(text_edit_list[0].range.end.character, 9);
+        assert!(quick_fix_edits(text, 10, 100, &typo_chec_result).is_empty());
+
+        let typo_chec_result = check_a_word_with("apple", &CheckOptions::default());
+        assert!(quick_fix_edits(text, 4, 5, &typo_chec_result).is_empty());
+    }
+
+    #[test]
+    fn test_quick_fix_edits_match_case() {
+        let text = "Helo world";
+        let typo_chec_result = check_a_word_with("Helo", &CheckOptions::default());
+
+        let text_edit_list = quick_fix_edits(text, 0, 4, &typo_chec_result);
+        assert!(text_edit_list
+            .iter()
+            .any(|text_edit| text_edit.new_text == "Hello"));
+        assert!(text_edit_list
+            .iter()
+            .all(|text_edit| text_edit.new_text.starts_with(char::is_uppercase)));
+
+        let text = "HELO world";
+        let text_edit_list = quick_fix_edits(text, 0, 4, &typo_chec_result);
+        assert!(text_edit_list
+            .iter()
+            .any(|text_edit| text_edit.new_text == "HELLO"));
+    }
+}
@@ -0,0 +1,133 @@
+use crate::ParallelConfig;
+
+/// Returns a matrix of distances between every word of `words_a` (rows) and `words_b` (columns).
+///
+/// `words_a`の各単語(行)と`words_b`の各単語(列)の距離の行列を返します。
+///
+/// # Arguments
+///
+/// * `words_a` - Words for the rows(行になる単語)
+/// * `words_b` - Words for the columns(列になる単語)
+/// * `metric` - Function to calculate the distance(距離を計算する関数)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{distance_matrix, levenshtein};
+///
+/// let matrix = distance_matrix(&["kitten", "sitting"], &["sitting"], levenshtein);
+/// assert_eq!(matrix, vec![vec![3], vec![0]]);
+/// ```
+pub fn distance_matrix<A, B, F>(words_a: &[A], words_b: &[B], metric: F) -> Vec<Vec<usize>>
+where
+    A: AsRef<str>,
+    B: AsRef<str>,
+    F: Fn(&str, &str) -> usize,
+{
+    words_a
+        .iter()
+        .map(|word_a| {
+            words_b
+                .iter()
+                .map(|word_b| metric(word_a.as_ref(), word_b.as_ref()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the same matrix as `distance_matrix`, calculating the rows on `thread_num` threads.
+///
+/// `distance_matrix`と同じ行列を、`thread_num`個のスレッドで行を分担して計算して返します。
+///
+/// # Arguments
+///
+/// * `words_a` - Words for the rows(行になる単語)
+/// * `words_b` - Words for the columns(列になる単語)
+/// * `metric` - Function to calculate the distance(距離を計算する関数)
+/// * `thread_num` - Number of threads(スレッド数)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{distance_matrix_parallel, levenshtein};
+///
+/// let matrix = distance_matrix_parallel(&["kitten", "sitting"], &["sitting"], levenshtein, 2);
+/// assert_eq!(matrix, vec![vec![3], vec![0]]);
+/// ```
+pub fn distance_matrix_parallel<A, B, F>(
+    words_a: &[A],
+    words_b: &[B],
+    metric: F,
+    thread_num: usize,
+) -> Vec<Vec<usize>>
+where
+    A: AsRef<str> + Sync,
+    B: AsRef<str> + Sync,
+    F: Fn(&str, &str) -> usize + Sync,
+{
+    distance_matrix_with_config(
+        words_a,
+        words_b,
+        metric,
+        &ParallelConfig::default()
+            .threads(thread_num.max(1))
+            .min_batch_size(1),
+    )
+}
+
+/// Returns the same matrix as `distance_matrix`, calculating the rows within the limits of the ParallelConfig.
+///
+/// `distance_matrix`と同じ行列を、ParallelConfigの制限の範囲で行を分担して計算して返します。
+///
+/// # Arguments
+///
+/// * `words_a` - Words for the rows(行になる単語)
+/// * `words_b` - Words for the columns(列になる単語)
+/// * `metric` - Function to calculate the distance(距離を計算する関数)
+/// * `parallel_config` - Configuration of the threads(スレッドの設定)
+pub fn distance_matrix_with_config<A, B, F>(
+    words_a: &[A],
+    words_b: &[B],
+    metric: F,
+    parallel_config: &ParallelConfig,
+) -> Vec<Vec<usize>>
+where
+    A: AsRef<str> + Sync,
+    B: AsRef<str> + Sync,
+    F: Fn(&str, &str) -> usize + Sync,
+{
+    parallel_config.map(words_a, |chunk| distance_matrix(chunk, words_b, &metric))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    #[test]
+    fn test_distance_matrix_parallel_same_as_serial() {
+        let words_a = ["apple", "apply", "maple", "ample", "angle"];
+        let words_b = vec!["apple".to_string(), "applet".to_string()];
+
+        let serial_matrix = distance_matrix(&words_a, &words_b, levenshtein);
+        let parallel_matrix = distance_matrix_parallel(&words_a, &words_b, levenshtein, 3);
+
+        assert_eq!(serial_matrix.len(), 5);
+        assert_eq!(serial_matrix[1], vec![1, 2]);
+        assert_eq!(serial_matrix, parallel_matrix);
+
+        let parallel_config = ParallelConfig::default().threads(2).min_batch_size(2);
+        assert_eq!(
+            distance_matrix_with_config(&words_a, &words_b, levenshtein, &parallel_config),
+            serial_matrix
+        );
+    }
+
+    #[test]
+    fn test_distance_matrix_empty() {
+        let words_a: [&str; 0] = [];
+
+        assert!(distance_matrix(&words_a, &["a"], levenshtein).is_empty());
+        assert!(distance_matrix_parallel(&words_a, &["a"], levenshtein, 4).is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{
+    check_a_word_with, check_french_word, check_german_word, check_kana_word, CheckOptions,
+    TypoCheckResult,
+};
+
+/// Enum of the Unicode scripts used to route words to dictionaries
+///
+/// 単語を辞書に振り分けるためのUnicodeの用字の列挙型です
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Script {
+    /// Latin alphabets including accented letters(アクセント記号付きの文字を含むラテン文字)
+    Latin,
+    /// Cyrillic alphabets(キリル文字)
+    Cyrillic,
+    /// Hiragana and katakana(ひらがなとカタカナ)
+    Kana,
+    /// Chinese characters(漢字)
+    Han,
+}
+
+/// Returns the script of the first character of the word that belongs to a known script.
+///
+/// 単語の文字のうち、既知の用字に属する最初の文字の用字を返します。
+///
+/// # Arguments
+///
+/// * `word` - Word to detect the script(用字を判定する単語)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{detect_script, Script};
+///
+/// assert_eq!(detect_script("élève"), Some(Script::Latin));
+/// assert_eq!(detect_script("привет"), Some(Script::Cyrillic));
+/// assert_eq!(detect_script("シャツ"), Some(Script::Kana));
+/// assert_eq!(detect_script("2024"), None);
+/// ```
+pub fn detect_script(word: &str) -> Option<Script> {
+    word.chars().find_map(|c| match c {
+        'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' if c != '×' && c != '÷' => {
+            Some(Script::Latin)
+        }
+        '\u{0400}'..='\u{04FF}' => Some(Script::Cyrillic),
+        '\u{3040}'..='\u{30FF}' => Some(Script::Kana),
+        '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' => Some(Script::Han),
+        _ => None,
+    })
+}
+
+/// Enum of the languages that can be checked
+///
+/// チェックできる言語の列挙型です
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Language {
+    /// English with the built-in dictionary(組み込み辞書による英語)
+    English,
+    /// German checked with `check_german_word`(`check_german_word`でチェックするドイツ語)
+    German,
+    /// French checked with `check_french_word`(`check_french_word`でチェックするフランス語)
+    French,
+    /// Japanese kana checked with `check_kana_word`(`check_kana_word`でチェックする日本語のかな)
+    Kana,
+}
+
+/// Struct that stores a dictionary of one language
+///
+/// 1つの言語の辞書を格納する構造体です
+#[derive(Debug, Clone)]
+pub struct LanguageDictionary {
+    language: Language,
+    word_list: Vec<String>,
+}
+
+impl Language {
+    /// Returns the script of the language(言語の用字を返します)
+    pub fn script(&self) -> Script {
+        match self {
+            Language::English | Language::German | Language::French => Script::Latin,
+            Language::Kana => Script::Kana,
+        }
+    }
+}
+
+impl LanguageDictionary {
+    /// Returns the dictionary of English with the built-in dictionary(組み込み辞書による英語の辞書を返します)
+    pub fn english() -> LanguageDictionary {
+        LanguageDictionary {
+            language: Language::English,
+            word_list: Vec::new(),
+        }
+    }
+
+    /// Returns the dictionary of German with the given words(指定した単語によるドイツ語の辞書を返します)
+    pub fn german<I: IntoIterator<Item = S>, S: Into<String>>(word_list: I) -> LanguageDictionary {
+        LanguageDictionary::with_word_list(Language::German, word_list)
+    }
+
+    /// Returns the dictionary of French with the given words(指定した単語によるフランス語の辞書を返します)
+    pub fn french<I: IntoIterator<Item = S>, S: Into<String>>(word_list: I) -> LanguageDictionary {
+        LanguageDictionary::with_word_list(Language::French, word_list)
+    }
+
+    /// Returns the dictionary of kana with the given words(指定した単語によるかなの辞書を返します)
+    pub fn kana<I: IntoIterator<Item = S>, S: Into<String>>(word_list: I) -> LanguageDictionary {
+        LanguageDictionary::with_word_list(Language::Kana, word_list)
+    }
+
+    fn with_word_list<I: IntoIterator<Item = S>, S: Into<String>>(
+        language: Language,
+        word_list: I,
+    ) -> LanguageDictionary {
+        LanguageDictionary {
+            language,
+            word_list: word_list.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the language of the dictionary(辞書の言語を返します)
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Checks the word with the dictionary(辞書で単語をチェックします)
+    pub fn check(&self, check_word: &str, options: &CheckOptions) -> TypoCheckResult {
+        match self.language {
+            Language::English => check_a_word_with(check_word, options),
+            Language::German => check_german_word(check_word, &self.word_list, options),
+            Language::French => check_french_word(check_word, &self.word_list, options),
+            Language::Kana => check_kana_word(check_word, &self.word_list, options),
+        }
+    }
+}
+
+/// Struct that stores the result of checking a word against several languages.
+///
+/// 複数の言語で単語をチェックした結果を格納する構造体です。
+///
+/// # Arguments
+///
+/// * `check_word` - The check word(チェックした単語)
+/// * `script` - Script of the check word(チェックした単語の用字)
+/// * `accepted_language` - Language whose dictionary contains the word(単語を含んでいた辞書の言語)
+/// * `result_list` - Results of the languages checked until the word was accepted(単語が見つかるまでにチェックした言語ごとの結果)
+#[derive(Debug)]
+pub struct MultilingualCheckResult {
+    pub check_word: String,
+    pub script: Option<Script>,
+    pub accepted_language: Option<Language>,
+    pub result_list: Vec<(Language, TypoCheckResult)>,
+}
+
+impl MultilingualCheckResult {
+    /// Returns true if no dictionary contains the word. A skipped word or a word without a dictionary of its script is not a typo(どの辞書にも単語が無い場合にtrueを返します。チェックしない単語や、用字に対応する辞書が無い単語はタイポではありません)
+    pub fn is_typo(&self) -> bool {
+        self.accepted_language.is_none() && !self.result_list.is_empty()
+    }
+}
+
+/// Checks the word against the dictionaries in order and flags it only if every dictionary misses it.
+/// Only the dictionaries of the script of the word are used, so a word of a script without a dictionary, such as Han, is not checked.
+/// The check stops at the first dictionary that contains the word.
+///
+/// 辞書の順に単語をチェックし、全ての辞書に無い場合のみタイポとします。
+/// 単語の用字に対応する辞書のみを使うため、漢字など辞書が無い用字の単語はチェックしません。
+/// 単語を含む辞書が見つかった時点でチェックを終了します。
+///
+/// # Arguments
+///
+/// * `check_word` - Words to check(チェックしたい単語)
+/// * `dictionary_list` - Dictionaries of the languages to check(チェックする言語の辞書)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_multilingual_word, CheckOptions, Language, LanguageDictionary};
+///
+/// let dictionary_list = [
+///     LanguageDictionary::english(),
+///     LanguageDictionary::french(["élève", "fenêtre"]),
+/// ];
+/// let result = check_multilingual_word("fenêtre", &dictionary_list, &CheckOptions::default());
+/// assert_eq!(result.accepted_language, Some(Language::French));
+///
+/// let result = check_multilingual_word("fenetre", &dictionary_list, &CheckOptions::default());
+/// assert!(result.is_typo());
+/// ```
+pub fn check_multilingual_word(
+    check_word: &str,
+    dictionary_list: &[LanguageDictionary],
+    options: &CheckOptions,
+) -> MultilingualCheckResult {
+    let mut output = MultilingualCheckResult {
+        check_word: check_word.to_string(),
+        script: detect_script(check_word),
+        accepted_language: None,
+        result_list: Vec::new(),
+    };
+    if options.is_skipped_word(check_word) {
+        return output;
+    }
+
+    // 単語の用字に対応する辞書のみでチェックする
+    for dictionary in dictionary_list
+        .iter()
+        .filter(|dictionary| Some(dictionary.language.script()) == output.script)
+    {
+        let typo_check_result = dictionary.check(check_word, options);
+        let is_accepted = typo_check_result.match_word.is_some();
+        output
+            .result_list
+            .push((dictionary.language, typo_check_result));
+        if is_accepted {
+            output.accepted_language = Some(dictionary.language);
+            break;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dictionary_list() -> Vec<LanguageDictionary> {
+        vec![
+            LanguageDictionary::english(),
+            LanguageDictionary::french(["élève", "école", "fenêtre"]),
+        ]
+    }
+
+    #[test]
+    fn test_check_multilingual_word_accepted() {
+        let result = check_multilingual_word("apple", &dictionary_list(), &CheckOptions::default());
+        assert_eq!(result.accepted_language, Some(Language::English));
+        assert_eq!(result.result_list.len(), 1);
+        assert!(!result.is_typo());
+
+        let result = check_multilingual_word("école", &dictionary_list(), &CheckOptions::default());
+        assert_eq!(result.accepted_language, Some(Language::French));
+        assert_eq!(result.result_list.len(), 2);
+    }
+
+    #[test]
+    fn test_check_multilingual_word_typo_and_skipped() {
+        let result = check_multilingual_word("aplpe", &dictionary_list(), &CheckOptions::default());
+        assert!(result.is_typo());
+        assert_eq!(result.result_list.len(), 2);
+
+        let options = CheckOptions::default().stop_words(["aplpe"]);
+        let result = check_multilingual_word("aplpe", &dictionary_list(), &options);
+        assert!(!result.is_typo());
+    }
+
+    #[test]
+    fn test_check_multilingual_word_script_routing() {
+        let mut dictionary_list = dictionary_list();
+        dictionary_list.push(LanguageDictionary::kana(["シャツ"]));
+
+        let result = check_multilingual_word("シャシ", &dictionary_list, &CheckOptions::default());
+        assert_eq!(result.script, Some(Script::Kana));
+        assert_eq!(result.result_list.len(), 1);
+        assert_eq!(result.result_list[0].0, Language::Kana);
+        assert!(result.is_typo());
+
+        // 辞書が無い用字の単語はチェックしない
+        for word in ["привет", "漢字", "2024"] {
+            let result = check_multilingual_word(word, &dictionary_list, &CheckOptions::default());
+            assert!(result.result_list.is_empty(), "{}", word);
+            assert!(!result.is_typo(), "{}", word);
+        }
+    }
+}
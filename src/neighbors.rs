@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::dictionary;
+
+/// 指定した位置の1文字を削除した文字列を返す
+fn delete_char(char_list: &[char], index: usize) -> String {
+    char_list
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+/// Struct that precomputes the words of Levenshtein distance 1 from each other.
+/// Words are indexed by the string with one character deleted and its position, so a lookup does not compare every word.
+///
+/// レーベンシュタイン距離が1の単語を引けるように事前計算した構造体です。
+/// 1文字を削除した文字列とその位置で単語を索引するため、検索時に全ての単語と比較しません。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::NeighborIndex;
+///
+/// let neighbor_index = NeighborIndex::new(["form", "fort", "from", "for", "forms", "farm"]);
+/// assert_eq!(neighbor_index.neighbors("form"), vec!["farm", "for", "forms", "fort"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct NeighborIndex {
+    word_list: Vec<String>,
+    word_index_map: HashMap<String, usize>,
+    deletion_map: HashMap<(String, usize), Vec<usize>>,
+}
+
+impl NeighborIndex {
+    /// Returns a NeighborIndex of the given words. Words are compared in lowercase(指定した単語のNeighborIndexを返します。単語は小文字で比較されます)
+    pub fn new<I, S>(word_list: I) -> NeighborIndex
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut neighbor_index = NeighborIndex::default();
+        for word in word_list {
+            let word = word.as_ref().to_lowercase();
+            if neighbor_index.word_index_map.contains_key(&word) {
+                continue;
+            }
+
+            let index = neighbor_index.word_list.len();
+            let char_list: Vec<char> = word.chars().collect();
+            for position in 0..char_list.len() {
+                neighbor_index
+                    .deletion_map
+                    .entry((delete_char(&char_list, position), position))
+                    .or_default()
+                    .push(index);
+            }
+            neighbor_index.word_index_map.insert(word.clone(), index);
+            neighbor_index.word_list.push(word);
+        }
+        neighbor_index
+    }
+
+    /// Returns a NeighborIndex of the built-in dictionary(組み込み辞書のNeighborIndexを返します)
+    pub fn from_dictionary() -> NeighborIndex {
+        NeighborIndex::new(
+            dictionary::DICTIONARY
+                .iter()
+                .flat_map(|same_length_word_list| {
+                    same_length_word_list.iter().map_while(|word| *word)
+                }),
+        )
+    }
+
+    /// Returns the indexed words of Levenshtein distance 1 from the word, in sorted order. The word itself is not included.
+    ///
+    /// 索引した単語のうち、指定した単語とのレーベンシュタイン距離が1の単語をソートして返します。指定した単語自身は含みません。
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to look up(検索する単語)
+    pub fn neighbors(&self, word: &str) -> Vec<&str> {
+        let word = word.to_lowercase();
+        let char_list: Vec<char> = word.chars().collect();
+        let mut neighbor_list: Vec<&str> = Vec::new();
+
+        for position in 0..char_list.len() {
+            let deleted_word = delete_char(&char_list, position);
+
+            // 同じ位置の1文字が違う単語(置換)
+            if let Some(index_list) = self.deletion_map.get(&(deleted_word.clone(), position)) {
+                neighbor_list.extend(
+                    index_list
+                        .iter()
+                        .map(|index| self.word_list[*index].as_str()),
+                );
+            }
+
+            // 1文字少ない単語(削除)
+            if let Some(index) = self.word_index_map.get(&deleted_word) {
+                neighbor_list.push(self.word_list[*index].as_str());
+            }
+        }
+
+        // 1文字多い単語(挿入)
+        for position in 0..=char_list.len() {
+            if let Some(index_list) = self.deletion_map.get(&(word.clone(), position)) {
+                neighbor_list.extend(
+                    index_list
+                        .iter()
+                        .map(|index| self.word_list[*index].as_str()),
+                );
+            }
+        }
+
+        neighbor_list.retain(|neighbor| *neighbor != word);
+        neighbor_list.sort();
+        neighbor_list.dedup();
+        neighbor_list
+    }
+
+    /// Returns the number of indexed words(索引した単語数を返します)
+    pub fn len(&self) -> usize {
+        self.word_list.len()
+    }
+
+    /// Returns true if no word is indexed(索引した単語が無い場合にtrueを返します)
+    pub fn is_empty(&self) -> bool {
+        self.word_list.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    #[test]
+    fn test_neighbors_same_as_levenshtein() {
+        let word_list = [
+            "cat", "cot", "coat", "at", "cats", "act", "dog", "scat", "ca",
+        ];
+        let neighbor_index = NeighborIndex::new(word_list);
+
+        for word in ["cat", "coat", "xat", "c"] {
+            let mut expected_list: Vec<&str> = word_list
+                .iter()
+                .copied()
+                .filter(|other| levenshtein(word, other) == 1)
+                .collect();
+            expected_list.sort();
+            assert_eq!(neighbor_index.neighbors(word), expected_list, "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_neighbors_duplicate_and_case() {
+        let neighbor_index = NeighborIndex::new(["Book", "book", "look"]);
+
+        assert_eq!(neighbor_index.len(), 2);
+        assert_eq!(neighbor_index.neighbors("BOOK"), vec!["look"]);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::levenshtein;
+
+/// 1から9までの数詞
+const CARDINAL_UNIT_LIST: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// 1から9までの序数詞
+const ORDINAL_UNIT_LIST: [&str; 9] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth",
+];
+
+/// 20から90までの10の倍数の数詞
+const TENS_LIST: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// 1語で書く数詞と序数詞(1から9までと10の倍数を除く)
+const OTHER_NUMBER_WORD_LIST: [&str; 39] = [
+    "zero",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "hundred",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "zeroth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+    "twentieth",
+    "thirtieth",
+    "fortieth",
+    "fiftieth",
+    "sixtieth",
+    "seventieth",
+    "eightieth",
+    "ninetieth",
+    "hundredth",
+    "thousandth",
+    "millionth",
+    "billionth",
+];
+
+/// 候補にする数詞の部分の最大のレーベンシュタイン距離
+const MAX_PART_LEVENSHTEIN_LENGTH: usize = 2;
+
+/// ハイフンの後に付けられる数詞と序数詞
+fn unit_iter() -> impl Iterator<Item = &'static str> {
+    CARDINAL_UNIT_LIST.into_iter().chain(ORDINAL_UNIT_LIST)
+}
+
+/// 1語の数詞と序数詞かどうか
+fn is_single_number_word(word: &str) -> bool {
+    unit_iter()
+        .chain(TENS_LIST)
+        .chain(OTHER_NUMBER_WORD_LIST)
+        .any(|number_word| number_word == word)
+}
+
+/// Returns true if the word is a number word or an ordinal, including hyphenated compounds from 21 to 99 such as "twenty-three" and "forty-second".
+///
+/// 単語が数詞か序数詞の場合にtrueを返します。"twenty-three"や"forty-second"のような、ハイフンで繋いだ21から99までの複合語も含みます。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::is_number_word;
+///
+/// assert!(is_number_word("Twenty-three"));
+/// assert!(is_number_word("ninety-ninth"));
+/// assert!(is_number_word("twelfth"));
+/// assert!(!is_number_word("ten-three"));
+/// ```
+pub fn is_number_word(word: &str) -> bool {
+    let word = word.to_lowercase();
+    match word.split_once('-') {
+        Some((tens, unit)) => {
+            TENS_LIST.contains(&tens) && unit_iter().any(|unit_word| unit_word == unit)
+        }
+        None => is_single_number_word(&word),
+    }
+}
+
+/// Returns the corrected number word if the word is a misspelled hyphenated number word such as "fourty-thre".
+/// Each part is corrected to the closest number word within a Levenshtein distance of 2, and None is returned for a correct number word or a word that is not a number word.
+///
+/// 単語が"fourty-thre"のような綴りを間違えたハイフンで繋いだ数詞の場合に、修正した数詞を返します。
+/// それぞれの部分をレーベンシュタイン距離が2以内の最も近い数詞に修正し、正しい数詞や数詞でない単語の場合はNoneを返します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::correct_number_word;
+///
+/// assert_eq!(correct_number_word("fourty-thre"), Some("forty-three".to_string()));
+/// assert_eq!(correct_number_word("forty-three"), None);
+/// assert_eq!(correct_number_word("fifty-fifty"), None);
+/// assert_eq!(correct_number_word("well-known"), None);
+/// ```
+pub fn correct_number_word(word: &str) -> Option<String> {
+    let word = word.to_lowercase();
+    let (tens, unit) = word.split_once('-')?;
+    // 正しい複合語や"fifty-fifty"のような数詞の組み合わせは修正しない
+    if is_single_number_word(tens) && is_single_number_word(unit) {
+        return None;
+    }
+
+    let corrected_tens = find_closest_number_word(tens, TENS_LIST.into_iter())?;
+    let corrected_unit = find_closest_number_word(unit, unit_iter())?;
+    Some(format!("{}-{}", corrected_tens, corrected_unit))
+}
+
+/// 最も近い数詞を返す。同じ距離の場合は先にあるものを選ぶ
+fn find_closest_number_word<I>(part: &str, number_word_iter: I) -> Option<&'static str>
+where
+    I: Iterator<Item = &'static str>,
+{
+    number_word_iter
+        .map(|number_word| (levenshtein(part, number_word), number_word))
+        .filter(|(levenshtein_length, _)| *levenshtein_length <= MAX_PART_LEVENSHTEIN_LENGTH)
+        .min_by_key(|(levenshtein_length, _)| *levenshtein_length)
+        .map(|(_, number_word)| number_word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_words() {
+        for tens in TENS_LIST {
+            for unit in unit_iter() {
+                let number_word = format!("{}-{}", tens, unit);
+                assert!(is_number_word(&number_word), "{}", number_word);
+                assert_eq!(correct_number_word(&number_word), None);
+            }
+        }
+        assert!(!is_number_word("twenty-"));
+        assert!(!is_number_word("three-twenty"));
+
+        assert_eq!(
+            correct_number_word("twenty-secnd"),
+            Some("twenty-second".to_string())
+        );
+        assert_eq!(
+            correct_number_word("Ninty-nine"),
+            Some("ninety-nine".to_string())
+        );
+        assert_eq!(correct_number_word("twenty"), None);
+        assert_eq!(correct_number_word("sixty-something"), None);
+    }
+}
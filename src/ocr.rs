@@ -0,0 +1,458 @@
+use std::collections::HashMap;
+
+use crate::query::{should_join, split_word};
+use crate::sentence::match_case;
+use crate::untrusted::check_untrusted_with_dictionary;
+use crate::{CheckOptions, Dictionary, Profile};
+
+/// OCRで英字と取り違えやすい数字と、その英字の候補
+const DIGIT_CONFUSION_LIST: [(char, &str); 4] = [('0', "o"), ('1', "li"), ('5', "s"), ('8', "b")];
+
+/// 実在する単語を珍しいとみなす頻度
+const RARE_THRESHOLD: f64 = 1.0;
+/// 実在する単語の形状の似た単語を、よく使われるとみなす頻度
+const COMMON_THRESHOLD: f64 = 10.0;
+
+/// Kind of a change made by `clean`
+///
+/// `clean`による変更の種類です
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OcrChangeKind {
+    /// Two pieces of a word broken by a space or a hyphenated line break are joined(空白やハイフンの改行で分かれた単語を結合しました)
+    Join,
+    /// A word without a space between is split into two words(空白の抜けた単語を2つの単語に分割しました)
+    Split,
+    /// Characters with similar shapes, such as "rn" and "m" or "0" and "o", are replaced(「rn」と「m」や「0」と「o」などの形状の似た文字を置き換えました)
+    ShapeConfusion,
+    /// A misspelled word is replaced with the top suggestion(綴りの誤った単語を最も良い候補に置き換えました)
+    Spelling,
+    /// A rare but correctly spelled word is replaced with a common word of a similar shape(正しい綴りだが珍しい単語を、形状の似たよく使われる単語に置き換えました)
+    RealWord,
+}
+
+/// Struct that stores one change made by `clean`
+///
+/// `clean`による1つの変更を格納する構造体です
+///
+/// # Arguments
+///
+/// * `start` - Byte offset of the start of the changed part in the original text(元のテキストでの変更した部分の開始のバイト位置)
+/// * `end` - Byte offset of the end of the changed part in the original text, exclusive(元のテキストでの変更した部分の終了のバイト位置。終了位置は含みません)
+/// * `original` - Original text of the changed part(変更した部分の元の文字列)
+/// * `replacement` - Text that replaced the original(元の文字列を置き換えた文字列)
+/// * `kind` - Kind of the change(変更の種類)
+/// * `confidence` - Confidence of the change from 0.0 to 1.0. Low confidence changes should be reviewed by a person(0.0から1.0の変更の確からしさ。確からしさが低い変更は人が確認してください)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrChange {
+    pub start: usize,
+    pub end: usize,
+    pub original: String,
+    pub replacement: String,
+    pub kind: OcrChangeKind,
+    pub confidence: f64,
+}
+
+/// Struct that stores the cleaned text and the changes
+///
+/// 修正したテキストと変更の一覧を格納する構造体です
+///
+/// # Arguments
+///
+/// * `text` - Text with every change applied(全ての変更を適用したテキスト)
+/// * `change_list` - Changes in the order of the original text(元のテキストの順の変更の一覧)
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrCleanResult {
+    pub text: String,
+    pub change_list: Vec<OcrChange>,
+}
+
+impl OcrCleanResult {
+    /// Returns the changes whose confidence is below `min_confidence`, to be reviewed by a person(確からしさが`min_confidence`未満の、人が確認するべき変更を返します)
+    pub fn changes_to_review(&self, min_confidence: f64) -> Vec<&OcrChange> {
+        self.change_list
+            .iter()
+            .filter(|change| change.confidence < min_confidence)
+            .collect()
+    }
+}
+
+/// 英数字の並び(開始位置、終了位置)を返す
+fn find_segment_list(text: &str) -> Vec<(usize, usize)> {
+    let mut segment_list = Vec::new();
+    let mut segment_start = None;
+    for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (c.is_alphanumeric(), segment_start) {
+            (true, None) => segment_start = Some(index),
+            (false, Some(start)) => {
+                segment_list.push((start, index));
+                segment_start = None;
+            }
+            _ => {}
+        }
+    }
+    segment_list
+}
+
+/// 2つの単語の間が空白のみ、もしくはハイフンの改行かどうか。ハイフンの改行の場合はSome(true)を返す
+fn find_break(separator: &str) -> Option<bool> {
+    if !separator.is_empty() && separator.chars().all(char::is_whitespace) {
+        return Some(false);
+    }
+    let (head, tail) = separator.split_once('-')?;
+    (head.is_empty() && tail.contains('\n') && tail.chars().all(char::is_whitespace))
+        .then_some(true)
+}
+
+/// オプションの形状が似ている文字と文字の並びで、1か所を置き換えた単語の一覧を返す
+fn shape_variant_list(word: &str, options: &CheckOptions) -> Vec<String> {
+    let mut similar_shape = options.shape_profile.similar_shape_list();
+    similar_shape.extend(options.similar_shapes.iter().cloned());
+
+    let mut variant_list = Vec::new();
+    for (index, c) in word.char_indices() {
+        for shape_group in similar_shape.iter().filter(|group| group.contains(&c)) {
+            for shape in shape_group.iter().filter(|shape| **shape != c) {
+                variant_list.push(format!(
+                    "{}{}{}",
+                    &word[..index],
+                    shape,
+                    &word[index + c.len_utf8()..]
+                ));
+            }
+        }
+    }
+    // 文字の並びの組は双方向に置き換える
+    for (from, to) in options
+        .shape_profile
+        .similar_shape_sequence_list()
+        .into_iter()
+        .flat_map(|(a, b)| [(a, b), (b, a)])
+    {
+        for (index, _) in word.match_indices(from) {
+            variant_list.push(format!(
+                "{}{}{}",
+                &word[..index],
+                to,
+                &word[index + from.len()..]
+            ));
+        }
+    }
+    variant_list
+}
+
+/// 頻度を返す。頻度のハッシュマップに無い単語は頻度0とする
+fn frequency_of(frequency_map: &HashMap<String, f64>, word: &str) -> f64 {
+    frequency_map.get(word).copied().unwrap_or(0.0)
+}
+
+/// 辞書にある形状の似た単語のうち、最も頻度が高い単語を返す
+fn find_shape_variant(
+    word: &str,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> Option<String> {
+    shape_variant_list(word, options)
+        .into_iter()
+        .filter(|variant| dictionary.contains(variant))
+        .max_by(|a, b| {
+            frequency_of(frequency_map, a)
+                .total_cmp(&frequency_of(frequency_map, b))
+                .then_with(|| b.cmp(a))
+        })
+}
+
+/// 数字を取り違えやすい英字に置き換え、辞書の単語になる場合に返す
+fn replace_digit(word: &str, dictionary: &Dictionary) -> Option<String> {
+    // "1"は"l"と"i"の両方を試す
+    (0..2).find_map(|choice| {
+        let replaced_word: String = word
+            .chars()
+            .map(|c| {
+                DIGIT_CONFUSION_LIST
+                    .iter()
+                    .find(|(digit, _)| *digit == c)
+                    .map(|(_, letters)| {
+                        let letters: Vec<char> = letters.chars().collect();
+                        letters[choice.min(letters.len() - 1)]
+                    })
+                    .unwrap_or(c)
+            })
+            .collect();
+        dictionary.contains(&replaced_word).then_some(replaced_word)
+    })
+}
+
+/// 辞書に無い単語を修正し、置き換える単語と種類と確からしさを返す
+fn correct_unknown_word(
+    word: &str,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> Option<(String, OcrChangeKind, f64)> {
+    if let Some(variant) = find_shape_variant(word, options, frequency_map, dictionary) {
+        return Some((variant, OcrChangeKind::ShapeConfusion, 0.8));
+    }
+
+    // OCRでは空白が抜けやすいため、曖昧検索より先に分割する
+    if let Some(split_word) = split_word(word, frequency_map, dictionary) {
+        return Some((split_word, OcrChangeKind::Split, 0.6));
+    }
+    let typo_check_result = check_untrusted_with_dictionary(word, options, dictionary).ok()?;
+    let similar_word = typo_check_result.similar_word_list?.into_iter().next()?;
+    let confidence = if similar_word.levenshtein_length == 1 {
+        0.5
+    } else {
+        0.3
+    };
+    Some((similar_word.spelling, OcrChangeKind::Spelling, confidence))
+}
+
+/// Cleans the text of OCR output with the OCR profile, and returns the corrected text and the changes with their confidence.
+/// Broken words are joined, missing spaces are split, characters with similar shapes are replaced, and misspelled words are corrected.
+/// Real-word errors are not detected without frequencies, see `clean_with`.
+///
+/// OCRのプロファイルでOCRの出力のテキストを修正し、修正したテキストと確からしさ付きの変更の一覧を返します。
+/// 分かれた単語の結合、空白の抜けた単語の分割、形状の似た文字の置き換え、綴りの誤った単語の修正を行います。
+/// 頻度が無い場合は実在する単語の誤りは見つけません。`clean_with`を参照してください。
+///
+/// # Arguments
+///
+/// * `text` - Text of OCR output(OCRの出力のテキスト)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::ocr;
+///
+/// let ocr_clean_result = ocr::clean("The rnodern c1ock is an exam-\nple");
+/// for change in ocr_clean_result.changes_to_review(0.7) {
+///     println!("{} -> {} ({:.1})", change.original, change.replacement, change.confidence);
+/// }
+/// assert_eq!(ocr_clean_result.text, "The modern clock is an example");
+/// assert_eq!(ocr_clean_result.change_list.len(), 3);
+/// ```
+pub fn clean(text: &str) -> OcrCleanResult {
+    clean_with(
+        text,
+        &CheckOptions::with_profile(Profile::Ocr),
+        &HashMap::new(),
+    )
+}
+
+/// Same as `clean`, but with the given options and frequencies.
+/// Characters are replaced with the similar shapes of `shape_profile` and `similar_shapes` of the options.
+/// A correctly spelled word rarer than 1.0 is replaced when a word of a similar shape is at least as frequent as 10.0, such as "bum" for "burn".
+///
+/// `clean`と同じですが、指定したオプションと頻度を使います。
+/// 文字はオプションの`shape_profile`と`similar_shapes`の形状が似ている文字で置き換えます。
+/// "burn"に対する"bum"のように、頻度が1.0未満の正しい綴りの単語は、形状の似た単語の頻度が10.0以上の場合に置き換えます。
+///
+/// # Arguments
+///
+/// * `text` - Text of OCR output(OCRの出力のテキスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+pub fn clean_with(
+    text: &str,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+) -> OcrCleanResult {
+    clean_with_dictionary(text, options, frequency_map, &Dictionary::builtin())
+}
+
+/// Same as `clean_with`, but joins, splits and corrects words with the given dictionary instead of the built-in dictionary.
+///
+/// `clean_with`と同じですが、組み込み辞書の代わりに指定した辞書で単語を結合、分割、修正します。
+///
+/// # Arguments
+///
+/// * `text` - Text of OCR output(OCRの出力のテキスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+/// * `dictionary` - Dictionary to search(探索する辞書)
+pub fn clean_with_dictionary(
+    text: &str,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> OcrCleanResult {
+    let segment_list = find_segment_list(text);
+    let mut change_list = Vec::new();
+
+    let mut index = 0;
+    while index < segment_list.len() {
+        let (start, end) = segment_list[index];
+        let word = &text[start..end];
+        let lowercase_word = word.to_lowercase();
+        index += 1;
+
+        // 数字を含む並びは、英字に置き換えて辞書の単語になる場合のみ修正する
+        if word.chars().any(|c| c.is_ascii_digit()) {
+            if let Some(replaced_word) = word
+                .chars()
+                .any(char::is_alphabetic)
+                .then(|| replace_digit(&lowercase_word, dictionary))
+                .flatten()
+            {
+                change_list.push(OcrChange {
+                    start,
+                    end,
+                    original: word.to_string(),
+                    replacement: match_case(word, &replaced_word),
+                    kind: OcrChangeKind::ShapeConfusion,
+                    confidence: 0.8,
+                });
+            }
+            continue;
+        }
+
+        if let Some(&(next_start, next_end)) = segment_list.get(index) {
+            let next_word = &text[next_start..next_end];
+            let is_hyphenated = find_break(&text[end..next_start]);
+            if let Some(is_hyphenated) = is_hyphenated.filter(|_| {
+                !next_word.chars().any(|c| c.is_ascii_digit())
+                    && should_join(
+                        &lowercase_word,
+                        &next_word.to_lowercase(),
+                        frequency_map,
+                        dictionary,
+                    )
+            }) {
+                change_list.push(OcrChange {
+                    start,
+                    end: next_end,
+                    original: text[start..next_end].to_string(),
+                    replacement: match_case(
+                        word,
+                        &format!("{}{}", lowercase_word, next_word.to_lowercase()),
+                    ),
+                    kind: OcrChangeKind::Join,
+                    // ハイフンの改行は単語の途中で分かれた可能性が高い
+                    confidence: if is_hyphenated { 0.9 } else { 0.7 },
+                });
+                index += 1;
+                continue;
+            }
+        }
+
+        if options.is_skipped_word(word) {
+            continue;
+        }
+        let correction = if dictionary.contains(&lowercase_word) {
+            // 実在する単語の誤りは、頻度が無いと見つけられない
+            find_shape_variant(&lowercase_word, options, frequency_map, dictionary)
+                .filter(|variant| {
+                    frequency_of(frequency_map, &lowercase_word) < RARE_THRESHOLD
+                        && frequency_of(frequency_map, variant) >= COMMON_THRESHOLD
+                })
+                .map(|variant| (variant, OcrChangeKind::RealWord, 0.4))
+        } else {
+            correct_unknown_word(&lowercase_word, options, frequency_map, dictionary)
+        };
+        if let Some((replacement, kind, confidence)) = correction {
+            change_list.push(OcrChange {
+                start,
+                end,
+                original: word.to_string(),
+                replacement: match_case(word, &replacement),
+                kind,
+                confidence,
+            });
+        }
+    }
+
+    let mut cleaned_text = String::with_capacity(text.len());
+    let mut last_end = 0;
+    for change in &change_list {
+        cleaned_text.push_str(&text[last_end..change.start]);
+        cleaned_text.push_str(&change.replacement);
+        last_end = change.end;
+    }
+    cleaned_text.push_str(&text[last_end..]);
+
+    OcrCleanResult {
+        text: cleaned_text,
+        change_list,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShapeProfile;
+
+    #[test]
+    fn test_clean_shape_confusions_and_joins() {
+        let ocr_clean_result = clean("The rnodern c1ock is an exam-\nple, 10km");
+
+        assert_eq!(
+            ocr_clean_result.text,
+            "The modern clock is an example, 10km"
+        );
+        let kind_list: Vec<OcrChangeKind> = ocr_clean_result
+            .change_list
+            .iter()
+            .map(|change| change.kind)
+            .collect();
+        assert_eq!(
+            kind_list,
+            vec![
+                OcrChangeKind::ShapeConfusion,
+                OcrChangeKind::ShapeConfusion,
+                OcrChangeKind::Join,
+            ]
+        );
+        assert_eq!(ocr_clean_result.change_list[2].original, "exam-\nple");
+        assert!(ocr_clean_result.changes_to_review(0.9).len() == 2);
+    }
+
+    #[test]
+    fn test_clean_with_frequency() {
+        let frequency_map: HashMap<String, f64> = [
+            ("burn".to_string(), 50.0),
+            ("the".to_string(), 100.0),
+            ("cat".to_string(), 50.0),
+        ]
+        .into();
+        let options = CheckOptions::with_profile(Profile::Ocr);
+
+        let ocr_clean_result = clean_with("A bum thecat", &options, &frequency_map);
+        assert_eq!(ocr_clean_result.text, "A burn the cat");
+        assert_eq!(
+            ocr_clean_result.change_list[0].kind,
+            OcrChangeKind::RealWord
+        );
+        assert_eq!(ocr_clean_result.change_list[1].kind, OcrChangeKind::Split);
+        assert_eq!(clean("A bum").change_list, Vec::new());
+    }
+
+    #[test]
+    fn test_clean_with_options_and_dictionary() {
+        // 形状が似ている文字はオプションのプロファイルと追加のグループから作る
+        let options = CheckOptions::with_profile(Profile::Ocr).similar_shape_group(vec!['q', 'g']);
+        let ocr_clean_result = clean_with("the qreat rnodel", &options, &HashMap::new());
+        assert_eq!(ocr_clean_result.text, "the great model");
+
+        let options = CheckOptions::with_profile(Profile::Ocr).shape_profile(ShapeProfile::Custom);
+        let ocr_clean_result = clean_with("the rnodel", &options, &HashMap::new());
+        assert!(ocr_clean_result
+            .change_list
+            .iter()
+            .all(|change| change.kind != OcrChangeKind::ShapeConfusion));
+
+        let mut dictionary = Dictionary::builtin();
+        dictionary.add_word("modelx");
+        let ocr_clean_result = clean_with_dictionary(
+            "the rnodelx",
+            &CheckOptions::with_profile(Profile::Ocr),
+            &HashMap::new(),
+            &dictionary,
+        );
+        assert_eq!(ocr_clean_result.text, "the modelx");
+        assert_eq!(
+            ocr_clean_result.change_list[0].kind,
+            OcrChangeKind::ShapeConfusion
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use crate::{check_a_word_with, CheckOptions, SimilarWord, TypoCheckResult};
+
+/// Struct that stores one page of the ranked similar words, for UIs that show more suggestions on request
+///
+/// 要求に応じて候補を追加で表示するUIのための、順位付けした似ている単語の1ページを格納する構造体です
+///
+/// # Arguments
+///
+/// * `page` - Index of the page counted from 0(0から数えたページの番号)
+/// * `page_size` - Maximum number of similar words in a page(1ページの似ている単語の最大数)
+/// * `similar_word_list` - Similar words of the page in the order of the ranking(ページの似ている単語。順位の順です)
+/// * `has_next_page` - Whether there are similar words after the page(ページの後に似ている単語があるかどうか)
+#[derive(Debug, Clone)]
+pub struct SuggestionPage {
+    pub page: usize,
+    pub page_size: usize,
+    pub similar_word_list: Vec<SimilarWord>,
+    pub has_next_page: bool,
+}
+
+impl SuggestionPage {
+    /// Returns the index of the next page, or None if it is the last page(次のページの番号を返します。最後のページの場合はNoneを返します)
+    pub fn next_page(&self) -> Option<usize> {
+        self.has_next_page.then_some(self.page + 1)
+    }
+}
+
+/// Struct that holds all the ranked similar words of a check word, to serve the pages without searching the dictionary again.
+/// As an iterator it returns the pages in order from the first page.
+///
+/// 辞書を再び探索せずにページを返すために、チェックする単語の順位付けした全ての似ている単語を保持する構造体です。
+/// イテレータとしては最初のページから順にページを返します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{suggestion_cursor, CheckOptions};
+///
+/// let cursor = suggestion_cursor("applo", &CheckOptions::default(), 3);
+/// assert_eq!(cursor.page(1).similar_word_list.len(), 3);
+/// for suggestion_page in cursor.take(3) {
+///     for similar_word in &suggestion_page.similar_word_list {
+///         println!("{}: {}", suggestion_page.page, similar_word.get_spelling());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuggestionCursor {
+    similar_word_list: Vec<SimilarWord>,
+    page_size: usize,
+    next_page: Option<usize>,
+}
+
+impl SuggestionCursor {
+    /// Creates a cursor from the similar words in the order of the ranking(順位の順の似ている単語からカーソルを作成します)
+    pub(crate) fn new(similar_word_list: Vec<SimilarWord>, page_size: usize) -> SuggestionCursor {
+        SuggestionCursor {
+            similar_word_list,
+            page_size,
+            next_page: Some(0),
+        }
+    }
+
+    /// Returns the page at the index, without moving the cursor(カーソルを動かさずに、指定した番号のページを返します)
+    pub fn page(&self, page: usize) -> SuggestionPage {
+        let page_start = page
+            .saturating_mul(self.page_size)
+            .min(self.similar_word_list.len());
+        let page_end = page_start
+            .saturating_add(self.page_size)
+            .min(self.similar_word_list.len());
+        SuggestionPage {
+            page,
+            page_size: self.page_size,
+            similar_word_list: self.similar_word_list[page_start..page_end].to_vec(),
+            // ページの大きさが0の場合は、イテレータが終わるように次のページを無しとする
+            has_next_page: self.page_size > 0 && page_end < self.similar_word_list.len(),
+        }
+    }
+
+    /// Returns the number of the ranked similar words(順位付けした似ている単語の数を返します)
+    pub fn similar_word_num(&self) -> usize {
+        self.similar_word_list.len()
+    }
+}
+
+impl Iterator for SuggestionCursor {
+    type Item = SuggestionPage;
+
+    fn next(&mut self) -> Option<SuggestionPage> {
+        let page = self.next_page?;
+        let suggestion_page = self.page(page);
+        self.next_page = suggestion_page.next_page();
+        Some(suggestion_page)
+    }
+}
+
+/// Searches the dictionary once and returns a cursor over the pages of the similar words of the check word, ranked in the same order as `check_a_word_with`.
+/// `pickup_similar_word_num` of the options is ignored and all the similar words within the cutoff are kept.
+///
+/// 辞書を一度だけ探索し、チェックする単語の似ている単語のページを`check_a_word_with`と同じ順位で返すカーソルを返します。
+/// オプションの`pickup_similar_word_num`は使わず、カットオフ値以内の全ての似ている単語を保持します。
+///
+/// # Arguments
+///
+/// * `check_word` - Word to check(チェックしたい単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `page_size` - Maximum number of similar words in a page(1ページの似ている単語の最大数)
+pub fn suggestion_cursor(
+    check_word: &str,
+    options: &CheckOptions,
+    page_size: usize,
+) -> SuggestionCursor {
+    suggestion_cursor_by(options, page_size, |all_options| {
+        check_a_word_with(check_word, all_options)
+    })
+}
+
+/// `suggestion_cursor`と同じだが、指定した関数で全ての候補を探す
+pub(crate) fn suggestion_cursor_by<F>(
+    options: &CheckOptions,
+    page_size: usize,
+    check: F,
+) -> SuggestionCursor
+where
+    F: FnOnce(&CheckOptions) -> TypoCheckResult,
+{
+    let all_options = options.clone().pickup_similar_word_num(usize::MAX);
+    SuggestionCursor::new(check(&all_options).get_similar_word_list(), page_size)
+}
+
+/// Returns one page of the similar words of the check word, ranked in the same order as `check_a_word_with`.
+/// The ranked list is kept only up to the end of the page plus one word to know whether there is a next page, and `pickup_similar_word_num` of the options is ignored.
+/// Every call searches the dictionary again, so use `suggestion_cursor` to show several pages of the same word.
+///
+/// チェックする単語の似ている単語の1ページを、`check_a_word_with`と同じ順位で返します。
+/// 順位付けしたリストはページの終わりと、次のページがあるかを知るための1単語までしか保持せず、オプションの`pickup_similar_word_num`は使いません。
+/// 呼び出しごとに辞書を探索し直すため、同じ単語の複数のページを表示する場合は`suggestion_cursor`を使ってください。
+///
+/// # Arguments
+///
+/// * `check_word` - Word to check(チェックしたい単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `page` - Index of the page counted from 0(0から数えたページの番号)
+/// * `page_size` - Maximum number of similar words in a page(1ページの似ている単語の最大数)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_paged, CheckOptions};
+///
+/// let options = CheckOptions::default();
+/// let mut page = Some(0);
+/// while let Some(page_index) = page {
+///     let suggestion_page = check_paged("applo", &options, page_index, 3);
+///     for similar_word in &suggestion_page.similar_word_list {
+///         println!("{}: {}", page_index, similar_word.get_spelling());
+///     }
+///     page = suggestion_page.next_page().filter(|next_page| *next_page < 3);
+/// }
+/// ```
+pub fn check_paged(
+    check_word: &str,
+    options: &CheckOptions,
+    page: usize,
+    page_size: usize,
+) -> SuggestionPage {
+    check_paged_by(options, page, page_size, |paged_options| {
+        check_a_word_with(check_word, paged_options)
+    })
+}
+
+/// `check_paged`と同じだが、指定した関数でページの終わりまでの候補を探す
+pub(crate) fn check_paged_by<F>(
+    options: &CheckOptions,
+    page: usize,
+    page_size: usize,
+    check: F,
+) -> SuggestionPage
+where
+    F: FnOnce(&CheckOptions) -> TypoCheckResult,
+{
+    let page_start = page.saturating_mul(page_size);
+    let page_end = page_start.saturating_add(page_size);
+    // 次のページがあるかを知るために1単語多く取り出す
+    let paged_options = options
+        .clone()
+        .pickup_similar_word_num(page_end.saturating_add(1));
+    let mut similar_word_list = check(&paged_options).get_similar_word_list();
+
+    let has_next_page = similar_word_list.len() > page_end;
+    similar_word_list.truncate(page_end);
+    let similar_word_list = similar_word_list.split_off(page_start.min(similar_word_list.len()));
+    SuggestionPage {
+        page,
+        page_size,
+        similar_word_list,
+        has_next_page,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TypoChecker;
+
+    #[test]
+    fn test_pages_match_the_ranked_list() {
+        let options = CheckOptions::default().pickup_similar_word_num(1000);
+        let similar_word_list = check_a_word_with("applo", &options).get_similar_word_list();
+        assert!(similar_word_list.len() > 4);
+
+        let mut paged_word_list = Vec::new();
+        let mut page = Some(0);
+        while let Some(page_index) = page {
+            let suggestion_page = check_paged("applo", &CheckOptions::default(), page_index, 4);
+            assert!(suggestion_page.similar_word_list.len() <= 4);
+            paged_word_list.extend(suggestion_page.similar_word_list.clone());
+            page = suggestion_page.next_page();
+        }
+        assert_eq!(paged_word_list, similar_word_list);
+
+        let suggestion_page = check_paged("applo", &options, 1000, 4);
+        assert!(suggestion_page.similar_word_list.is_empty());
+        assert_eq!(suggestion_page.next_page(), None);
+
+        let suggestion_page = TypoChecker::default().check_paged("applo", 1, 4);
+        assert_eq!(suggestion_page.similar_word_list, similar_word_list[4..8]);
+    }
+
+    #[test]
+    fn test_cursor_pages_match_check_paged() {
+        let options = CheckOptions::default();
+        let cursor = suggestion_cursor("applo", &options, 4);
+        assert!(cursor.similar_word_num() > 4);
+
+        let page_list: Vec<SuggestionPage> = cursor.clone().collect();
+        assert_eq!(page_list.len(), cursor.similar_word_num().div_ceil(4));
+        for suggestion_page in &page_list {
+            let expected_page = check_paged("applo", &options, suggestion_page.page, 4);
+            assert_eq!(
+                suggestion_page.similar_word_list,
+                expected_page.similar_word_list
+            );
+            assert_eq!(suggestion_page.has_next_page, expected_page.has_next_page);
+        }
+
+        let suggestion_page = cursor.page(1000);
+        assert!(suggestion_page.similar_word_list.is_empty());
+        assert_eq!(suggestion_page.next_page(), None);
+
+        assert_eq!(suggestion_cursor("applo", &options, 0).count(), 1);
+
+        let checker_cursor = TypoChecker::default().suggestion_cursor("applo", 4);
+        assert_eq!(
+            checker_cursor.page(1).similar_word_list,
+            cursor.page(1).similar_word_list
+        );
+    }
+}
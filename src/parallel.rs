@@ -0,0 +1,146 @@
+use std::num::NonZeroUsize;
+use std::thread;
+
+use crate::{CheckOptions, TypoCheckResult, TypoChecker};
+
+/// Struct that stores the configuration of the parallel modes to cap the CPU usage
+///
+/// CPUの使用量を抑えるための、並列処理の設定を格納する構造体です
+///
+/// # Arguments
+///
+/// * `threads` - Maximum number of threads. 0 uses the available parallelism of the machine(最大のスレッド数。0の場合はマシンで利用できる並列数を使います)
+/// * `min_batch_size` - Minimum number of items per thread. Fewer items are processed on the current thread(1スレッドあたりの最小の件数。これより少ない場合は現在のスレッドで処理します)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ParallelConfig {
+    pub threads: usize,
+    pub min_batch_size: usize,
+}
+
+impl Default for ParallelConfig {
+    fn default() -> ParallelConfig {
+        ParallelConfig {
+            threads: 0,
+            min_batch_size: 64,
+        }
+    }
+}
+
+impl ParallelConfig {
+    /// Sets the maximum number of threads(最大のスレッド数を設定します)
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the minimum number of items per thread(1スレッドあたりの最小の件数を設定します)
+    pub fn min_batch_size(mut self, min_batch_size: usize) -> Self {
+        self.min_batch_size = min_batch_size;
+        self
+    }
+
+    /// Returns the number of items per thread for the number of items(件数に対する1スレッドあたりの件数を返します)
+    pub fn chunk_size(&self, item_num: usize) -> usize {
+        let threads = match self.threads {
+            0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            threads => threads,
+        };
+        item_num.div_ceil(threads).max(self.min_batch_size).max(1)
+    }
+
+    /// 件数を各スレッドに分けて処理し、元の順序で結合して返す
+    pub(crate) fn map<T, R, F>(&self, item_list: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&[T]) -> Vec<R> + Sync,
+    {
+        let chunk_size = self.chunk_size(item_list.len());
+        if chunk_size >= item_list.len() {
+            return f(item_list);
+        }
+
+        let f = &f;
+        thread::scope(|scope| {
+            let handle_list: Vec<_> = item_list
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || f(chunk)))
+                .collect();
+
+            // スレッドの順に結合して順序を保つ
+            handle_list
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+}
+
+/// Checks the words in parallel within the limits of the ParallelConfig and returns the results in the order of the words.
+///
+/// ParallelConfigの制限の範囲で単語を並列にチェックし、単語の順に結果を返します。
+///
+/// # Arguments
+///
+/// * `check_word_list` - Words to check(チェックしたい単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `parallel_config` - Configuration of the threads(スレッドの設定)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_words_parallel, CheckOptions, ParallelConfig};
+///
+/// let parallel_config = ParallelConfig::default().threads(2).min_batch_size(1);
+/// let typo_check_result_list =
+///     check_words_parallel(&["apple", "applo", "teh"], &CheckOptions::default(), &parallel_config);
+/// assert_eq!(typo_check_result_list[0].get_match_word(), "apple");
+/// ```
+pub fn check_words_parallel<S: AsRef<str> + Sync>(
+    check_word_list: &[S],
+    options: &CheckOptions,
+    parallel_config: &ParallelConfig,
+) -> Vec<TypoCheckResult> {
+    // 文字の表は全てのスレッドで共有する
+    let typo_checker = TypoChecker::new(options.clone());
+    parallel_config.map(check_word_list, |chunk| typo_checker.check_words(chunk))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_a_word_with;
+
+    #[test]
+    fn test_chunk_size() {
+        let parallel_config = ParallelConfig::default().threads(4).min_batch_size(10);
+
+        assert_eq!(parallel_config.chunk_size(100), 25);
+        assert_eq!(parallel_config.chunk_size(20), 10);
+        assert_eq!(parallel_config.chunk_size(0), 10);
+        assert!(ParallelConfig::default().threads(0).chunk_size(1) >= 1);
+    }
+
+    #[test]
+    fn test_check_words_parallel_keeps_order() {
+        let check_word_list = ["apple", "applo", "teh", "hello", "wrold"];
+        let options = CheckOptions::default();
+
+        let serial_result_list: Vec<String> = check_word_list
+            .iter()
+            .map(|word| check_a_word_with(word, &options).get_match_word())
+            .collect();
+        let parallel_result_list: Vec<String> = check_words_parallel(
+            &check_word_list,
+            &options,
+            &ParallelConfig::default().threads(3).min_batch_size(1),
+        )
+        .iter()
+        .map(|result| result.get_match_word())
+        .collect();
+
+        assert_eq!(serial_result_list, parallel_result_list);
+    }
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{CheckOptions, RankingWeights, ShapeProfile, TypoType};
+
+/// Enum of the bundled presets of CheckOptions
+///
+/// 同梱のCheckOptionsの設定の組み合わせの列挙型です
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Profile {
+    /// Same as the default options(デフォルトのオプションと同じ)
+    Strict,
+    /// Natural language text. The cutoff scales with the word length and common function words are skipped(自然言語の文章。カットオフ値は単語の文字数に応じて変わり、よく使う機能語はチェックしません)
+    Prose,
+    /// Identifiers and comments in source code. Short words are skipped and mixed case is not reported as Caps Lock(ソースコードの識別子やコメント。短い単語はチェックせず、大文字小文字の混在をCaps Lockとして報告しません)
+    Code,
+    /// Text recognized by OCR. Similar shapes including OCR confusions are ranked first with a wider cutoff(OCRで認識した文章。広いカットオフ値で、OCRの取り違えを含む形状が似ている単語を上位にします)
+    Ocr,
+    /// Short search queries. Candidates are ranked mostly by the frequencies given to `TypoChecker::with_frequency` or `correct_query`, and by the TypoType and the distance without them(短い検索クエリ。候補は主に`TypoChecker::with_frequency`や`correct_query`に渡した頻度で順位付けし、頻度が無い場合はTypoTypeと距離で順位付けします)
+    Query,
+}
+
+/// 機能語として扱うストップワード
+const PROSE_STOP_WORD_LIST: [&str; 16] = [
+    "a", "an", "and", "are", "as", "at", "be", "by", "in", "is", "it", "of", "on", "or", "the",
+    "to",
+];
+
+impl Profile {
+    /// Returns all profiles(全てのプロファイルを返します)
+    pub fn all() -> [Profile; 5] {
+        [
+            Profile::Strict,
+            Profile::Prose,
+            Profile::Code,
+            Profile::Ocr,
+            Profile::Query,
+        ]
+    }
+
+    /// Returns the name of the profile(プロファイルの名前を返します)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Profile::Strict => "strict",
+            Profile::Prose => "prose",
+            Profile::Code => "code",
+            Profile::Ocr => "ocr",
+            Profile::Query => "query",
+        }
+    }
+
+    /// Returns the CheckOptions of the profile(プロファイルのCheckOptionsを返します)
+    pub fn check_options(&self) -> CheckOptions {
+        match self {
+            Profile::Strict => CheckOptions::default(),
+            Profile::Prose => CheckOptions::default()
+                .length_aware_cutoff(true)
+                .stop_words(PROSE_STOP_WORD_LIST),
+            Profile::Code => CheckOptions::default()
+                .length_aware_cutoff(true)
+                .min_word_length(3)
+                .detect_caps_lock(false),
+            Profile::Ocr => CheckOptions::default()
+                .output_levenshtein_cutoff(Some(3))
+                .pickup_similar_word_num(10)
+                .detect_caps_lock(false)
+                .shape_profile(ShapeProfile::Ocr)
+                .sort_order_of_typo_type(vec![
+                    TypoType::SimilarShapes,
+                    TypoType::MissingAccent,
+                    TypoType::CommonMisspelling,
+                    TypoType::CloseKeyboardPlacement,
+                    TypoType::UndefinedType,
+                ]),
+            Profile::Query => CheckOptions::default()
+                .length_aware_cutoff(true)
+                .pickup_similar_word_num(10)
+                .detect_caps_lock(false)
+                .ranking_weights(RankingWeights {
+                    frequency_factor: 2.0,
+                    ..RankingWeights::default()
+                }),
+        }
+    }
+}
+
+/// Error when the profile name is unknown
+///
+/// プロファイルの名前が不明なときのエラーです
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseProfileError(String);
+
+impl fmt::Display for ParseProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown profile: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseProfileError {}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ParseProfileError;
+
+    fn from_str(s: &str) -> Result<Profile, ParseProfileError> {
+        Profile::all()
+            .into_iter()
+            .find(|profile| profile.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseProfileError(s.to_string()))
+    }
+}
+
+impl CheckOptions {
+    /// Returns the CheckOptions of the bundled profile.
+    ///
+    /// 同梱のプロファイルのCheckOptionsを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - Profile of the options(オプションのプロファイル)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::{CheckOptions, Profile};
+    ///
+    /// let options = CheckOptions::with_profile("code".parse::<Profile>().unwrap());
+    /// assert!(options.is_skipped_word("id"));
+    /// let typo_chec_result = typo_checker::check_a_word_with("functoin", &options);
+    /// println!("typo_chec_result: {:?}", typo_chec_result);
+    /// ```
+    pub fn with_profile(profile: Profile) -> CheckOptions {
+        profile.check_options()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_from_str() {
+        for profile in Profile::all() {
+            assert_eq!(profile.to_string().parse::<Profile>(), Ok(profile));
+        }
+        assert_eq!("OCR".parse::<Profile>(), Ok(Profile::Ocr));
+        assert!("fast".parse::<Profile>().is_err());
+    }
+
+    #[test]
+    fn test_prose_profile_skips_stop_words() {
+        let options = CheckOptions::with_profile(Profile::Prose);
+
+        assert!(options.is_skipped_word("The"));
+        assert!(!options.is_skipped_word("teh"));
+        assert_eq!(options.effective_levenshtein_cutoff(3), Some(1));
+    }
+}
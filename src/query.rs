@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+
+use crate::untrusted::check_untrusted_with_dictionary;
+use crate::{CheckOptions, Dictionary, Profile, RankingWeights};
+
+/// 自動で適用できると判断する、1位と2位の候補のスコアの最小の差
+const CONFIDENT_SCORE_MARGIN: f64 = 1.0;
+
+/// Struct that stores the correction of a search query
+///
+/// 検索クエリの修正結果を格納する構造体です
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryCorrection {
+    corrected_query: String,
+    corrected_word_num: usize,
+    confident: bool,
+}
+
+impl QueryCorrection {
+    /// Returns the corrected query in lowercase. It is the same as the query if nothing is corrected(小文字の修正したクエリを返します。修正が無い場合は元のクエリと同じです)
+    pub fn corrected_query(&self) -> &str {
+        &self.corrected_query
+    }
+
+    /// Returns the number of corrected words, counting a split or a join as one(修正した単語数を返します。分割や結合は1つとして数えます)
+    pub fn corrected_word_num(&self) -> usize {
+        self.corrected_word_num
+    }
+
+    /// Returns true if the query is corrected(クエリが修正された場合にtrueを返します)
+    pub fn is_corrected(&self) -> bool {
+        self.corrected_word_num > 0
+    }
+
+    /// Returns true if every correction is confident enough to be applied automatically, as in "showing results for ..."(「showing results for ...」のように、全ての修正を自動で適用できるほど確かな場合にtrueを返します)
+    pub fn is_confident(&self) -> bool {
+        self.confident
+    }
+}
+
+/// 頻度のスコア(頻度の対数)を返す
+fn frequency_score(frequency_map: &HashMap<String, f64>, word: &str) -> f64 {
+    frequency_map
+        .get(word)
+        .copied()
+        .unwrap_or(0.0)
+        .max(0.0)
+        .ln_1p()
+}
+
+/// 辞書に無い単語を2つの辞書の単語に分割する。頻度の合計が最も高い分割を返す
+pub(crate) fn split_word(
+    word: &str,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> Option<String> {
+    let mut best_split: Option<(f64, String)> = None;
+    for (index, _) in word.char_indices().skip(1) {
+        let (head, tail) = word.split_at(index);
+        if !dictionary.contains(head) || !dictionary.contains(tail) {
+            continue;
+        }
+        let score = frequency_score(frequency_map, head) + frequency_score(frequency_map, tail);
+        if best_split
+            .as_ref()
+            .is_none_or(|(best_score, _)| score > *best_score)
+        {
+            best_split = Some((score, format!("{} {}", head, tail)));
+        }
+    }
+    best_split.map(|(_, split_word)| split_word)
+}
+
+/// 隣り合う2つの単語を結合するかどうか。片方が辞書に無いか、結合した単語の方が頻度が高い場合に結合する
+pub(crate) fn should_join(
+    head: &str,
+    tail: &str,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> bool {
+    let joined_word = format!("{}{}", head, tail);
+    if !dictionary.contains(&joined_word) {
+        return false;
+    }
+    !dictionary.contains(head)
+        || !dictionary.contains(tail)
+        || frequency_score(frequency_map, &joined_word)
+            > frequency_score(frequency_map, head).max(frequency_score(frequency_map, tail))
+}
+
+/// 曖昧検索の候補を頻度を含めたスコアで選び直し、最も良い候補と確かかどうかを返す
+fn correct_word(
+    word: &str,
+    options: &CheckOptions,
+    ranking_weights: &RankingWeights,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> Option<(String, bool)> {
+    let typo_check_result = check_untrusted_with_dictionary(word, options, dictionary).ok()?;
+    let mut scored_word_list: Vec<(f64, String, usize)> = typo_check_result
+        .similar_word_list
+        .as_deref()
+        .unwrap_or(&[])
+        .iter()
+        .map(|similar_word| {
+            (
+                ranking_weights.score(
+                    &typo_check_result.check_word,
+                    similar_word,
+                    frequency_score(frequency_map, &similar_word.spelling),
+                ),
+                similar_word.spelling.clone(),
+                similar_word.levenshtein_length,
+            )
+        })
+        .collect();
+    scored_word_list.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let (best_score, best_word, best_distance) = scored_word_list.first()?.clone();
+    let confident = best_distance <= 1
+        && scored_word_list
+            .get(1)
+            .is_none_or(|(score, _, _)| best_score - score >= CONFIDENT_SCORE_MARGIN);
+    Some((best_word, confident))
+}
+
+/// Corrects a short search query with the query profile and returns a single corrected query.
+/// Words are split or joined when the result is in the dictionary, and fuzzy candidates are ranked mostly by frequency.
+///
+/// 検索クエリのプロファイルで短い検索クエリを修正し、1つの修正したクエリを返します。
+/// 辞書の単語になる場合は単語を分割または結合し、曖昧検索の候補は主に頻度で順位付けします。
+///
+/// # Arguments
+///
+/// * `query` - Search query to correct(修正する検索クエリ)
+/// * `frequency_map` - Hashmap of words and their frequency, such as the frequency in the search log(検索ログでの頻度などの、単語とその頻度のハッシュマップ)
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use typo_checker::correct_query;
+///
+/// let frequency_map: HashMap<String, f64> = [("apple", 500.0), ("pie", 300.0)]
+///     .into_iter()
+///     .map(|(word, frequency)| (word.to_string(), frequency))
+///     .collect();
+/// let query_correction = correct_query("applepie recioe", &frequency_map);
+/// if query_correction.is_confident() {
+///     println!("showing results for {}", query_correction.corrected_query());
+/// }
+/// assert_eq!(query_correction.corrected_query(), "apple pie recipe");
+/// ```
+pub fn correct_query(query: &str, frequency_map: &HashMap<String, f64>) -> QueryCorrection {
+    correct_query_with(
+        query,
+        &CheckOptions::with_profile(Profile::Query),
+        frequency_map,
+    )
+}
+
+/// Same as `correct_query`, but with the given options(`correct_query`と同じですが、指定したオプションを使います)
+pub fn correct_query_with(
+    query: &str,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+) -> QueryCorrection {
+    correct_query_with_dictionary(query, options, frequency_map, &Dictionary::builtin())
+}
+
+/// Same as `correct_query_with`, but splits, joins and corrects words with the given dictionary instead of the built-in dictionary.
+///
+/// `correct_query_with`と同じですが、組み込み辞書の代わりに指定した辞書で単語を分割、結合、修正します。
+///
+/// # Arguments
+///
+/// * `query` - Search query to correct(修正する検索クエリ)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+/// * `dictionary` - Dictionary to search(探索する辞書)
+pub fn correct_query_with_dictionary(
+    query: &str,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+    dictionary: &Dictionary,
+) -> QueryCorrection {
+    let default_ranking_weights = RankingWeights::default();
+    let ranking_weights = options
+        .ranking_weights
+        .as_ref()
+        .unwrap_or(&default_ranking_weights);
+
+    let word_list: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let mut corrected_word_list: Vec<String> = Vec::new();
+    let mut corrected_word_num = 0;
+    let mut confident = true;
+
+    let mut index = 0;
+    while index < word_list.len() {
+        let word = &word_list[index];
+        // 文のルールは使わず、結合、辞書の単語、分割、曖昧検索の順に試す
+        if let Some(next_word) = word_list.get(index + 1) {
+            if should_join(word, next_word, frequency_map, dictionary) {
+                corrected_word_list.push(format!("{}{}", word, next_word));
+                corrected_word_num += 1;
+                index += 2;
+                continue;
+            }
+        }
+
+        if dictionary.contains(word) || options.is_skipped_word(word) {
+            corrected_word_list.push(word.clone());
+            index += 1;
+            continue;
+        }
+
+        // 確かな曖昧検索の候補が無い場合のみ分割する(辞書には短い単語が多いため)
+        let corrected_word =
+            correct_word(word, options, ranking_weights, frequency_map, dictionary);
+        match (corrected_word, split_word(word, frequency_map, dictionary)) {
+            (Some((corrected_word, true)), _) => corrected_word_list.push(corrected_word),
+            (_, Some(split_word)) => corrected_word_list.push(split_word),
+            (Some((corrected_word, false)), None) => {
+                corrected_word_list.push(corrected_word);
+                confident = false;
+            }
+            (None, None) => {
+                // 修正できない単語がある場合は自動で適用しない
+                corrected_word_list.push(word.clone());
+                confident = false;
+                index += 1;
+                continue;
+            }
+        }
+        corrected_word_num += 1;
+        index += 1;
+    }
+
+    QueryCorrection {
+        corrected_query: corrected_word_list.join(" "),
+        corrected_word_num,
+        confident: confident && corrected_word_num > 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correct_query_split_and_join() {
+        let frequency_map = HashMap::new();
+
+        let query_correction = correct_query("note book", &frequency_map);
+        assert_eq!(query_correction.corrected_query(), "note book");
+        assert!(!query_correction.is_corrected());
+        assert!(!query_correction.is_confident());
+
+        let frequency_map: HashMap<String, f64> = [("notebook".to_string(), 100.0)].into();
+        let query_correction = correct_query("Note book", &frequency_map);
+        assert_eq!(query_correction.corrected_query(), "notebook");
+        assert!(query_correction.is_confident());
+
+        let query_correction = correct_query("notebookcase", &frequency_map);
+        assert_eq!(query_correction.corrected_query(), "notebook case");
+        assert_eq!(query_correction.corrected_word_num(), 1);
+    }
+
+    #[test]
+    fn test_correct_query_with_dictionary() {
+        let frequency_map = HashMap::new();
+        let mut dictionary = Dictionary::builtin();
+        dictionary.add_word("kubernetes");
+        dictionary.add_word("rustacean");
+
+        // 追加した単語は既知の単語として扱い、分割と結合にも使う
+        let query_correction = correct_query_with_dictionary(
+            "kubernetes rustaceanbook kuber netes",
+            &CheckOptions::with_profile(Profile::Query),
+            &frequency_map,
+            &dictionary,
+        );
+        assert_eq!(
+            query_correction.corrected_query(),
+            "kubernetes rustacean book kubernetes"
+        );
+        assert_eq!(query_correction.corrected_word_num(), 2);
+
+        let query_correction = correct_query("rustaceanbook", &frequency_map);
+        assert_ne!(query_correction.corrected_query(), "rustacean book");
+    }
+
+    #[test]
+    fn test_correct_query_frequency_ranking() {
+        let frequency_map: HashMap<String, f64> = [("apply".to_string(), 1000.0)].into();
+        let query_correction = correct_query("applo", &frequency_map);
+        assert_eq!(query_correction.corrected_query(), "apply");
+        assert!(query_correction.is_confident());
+
+        let frequency_map: HashMap<String, f64> = [("apple".to_string(), 1000.0)].into();
+        assert_eq!(
+            correct_query("applo", &frequency_map).corrected_query(),
+            "apple"
+        );
+    }
+}
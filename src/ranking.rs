@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use crate::{SimilarWord, TypoType};
+
+/// Struct that stores the ranking weight per TypoType
+///
+/// TypoTypeごとのランキングの重みを格納する構造体です
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TypoTypeWeights {
+    pub extra_characters: f64,
+    pub missing_characters: f64,
+    pub similar_shapes: f64,
+    pub close_keyboard_placement: f64,
+    pub common_misspelling: f64,
+    pub caps_lock: f64,
+    pub missing_accent: f64,
+    pub transposition: f64,
+    pub repeated_character: f64,
+    pub custom: f64,
+    pub undefined_type: f64,
+}
+
+impl Default for TypoTypeWeights {
+    fn default() -> TypoTypeWeights {
+        // デフォルトのソート順(CapsLock -> CommonMisspelling -> MissingAccent -> Transposition -> RepeatedCharacter -> ExtraCharacters -> MissingCharacters -> SimilarShapes -> CloseKeyboardPlacement -> UndefinedType -> Custom)に合わせる
+        TypoTypeWeights {
+            extra_characters: 4.0,
+            missing_characters: 3.0,
+            similar_shapes: 2.0,
+            close_keyboard_placement: 1.0,
+            common_misspelling: 5.0,
+            caps_lock: 6.0,
+            missing_accent: 4.5,
+            transposition: 4.2,
+            repeated_character: 4.1,
+            custom: -0.5,
+            undefined_type: 0.0,
+        }
+    }
+}
+
+impl TypoTypeWeights {
+    /// Returns the weight of the given TypoType
+    ///
+    /// 指定したTypoTypeの重みを返します
+    pub fn get(&self, typo_type: &TypoType) -> f64 {
+        match typo_type {
+            TypoType::ExtraCharacters { .. } => self.extra_characters,
+            TypoType::MissingCharacters { .. } => self.missing_characters,
+            TypoType::SimilarShapes => self.similar_shapes,
+            TypoType::CloseKeyboardPlacement => self.close_keyboard_placement,
+            TypoType::CommonMisspelling => self.common_misspelling,
+            TypoType::CapsLock => self.caps_lock,
+            TypoType::MissingAccent => self.missing_accent,
+            TypoType::Transposition => self.transposition,
+            TypoType::RepeatedCharacter { .. } => self.repeated_character,
+            TypoType::Custom(_) => self.custom,
+            TypoType::UndefinedType => self.undefined_type,
+        }
+    }
+}
+
+/// Struct that stores the weights used to score similar words.
+/// A higher score ranks the similar word higher.
+///
+/// 似ている単語のスコア計算に使う重みを格納する構造体です。
+/// スコアが高いほど似ている単語は上位になります。
+///
+/// # Arguments
+///
+/// * `typo_type` - Weight per TypoType(TypoTypeごとの重み)
+/// * `distance_penalty` - Penalty per Levenshtein distance(レーベンシュタイン距離1あたりの減点)
+/// * `frequency_factor` - Weight of word frequency. The built-in dictionary has no frequency, so it is 0 unless frequencies are given with `TypoChecker::with_frequency`(単語の頻度の重み。組み込み辞書は頻度を持たないため、`TypoChecker::with_frequency`で頻度を渡さない場合は0として扱われます)
+/// * `prefix_bonus` - Bonus per character of the common prefix with the check word(チェックする単語と共通する接頭辞1文字あたりの加点)
+/// * `adjacent_extra_character_bonus` - Bonus for ExtraCharacters typed with the next key(隣のキーを押したExtraCharactersの加点)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RankingWeights {
+    pub typo_type: TypoTypeWeights,
+    pub distance_penalty: f64,
+    pub frequency_factor: f64,
+    pub prefix_bonus: f64,
+    pub adjacent_extra_character_bonus: f64,
+}
+
+impl Default for RankingWeights {
+    fn default() -> RankingWeights {
+        RankingWeights {
+            typo_type: TypoTypeWeights::default(),
+            distance_penalty: 1.0,
+            frequency_factor: 0.0,
+            prefix_bonus: 0.1,
+            adjacent_extra_character_bonus: 0.5,
+        }
+    }
+}
+
+impl RankingWeights {
+    /// Loads RankingWeights from a TOML string. Omitted keys keep their default values.
+    ///
+    /// TOML形式の文字列からRankingWeightsを読み込みます。省略したキーはデフォルト値のままになります。
+    ///
+    /// # Arguments
+    ///
+    /// * `toml_str` - TOML string of the weights(重みを記述したTOML形式の文字列)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::RankingWeights;
+    ///
+    /// let weights = RankingWeights::from_toml_str(
+    ///     "distance_penalty = 2.0\n[typo_type]\nclose_keyboard_placement = 5.0\n",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(weights.distance_penalty, 2.0);
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(toml_str: &str) -> Result<RankingWeights, toml::de::Error> {
+        toml::from_str(toml_str)
+    }
+
+    /// Calculates the score of the similar word against the check word
+    ///
+    /// チェックする単語に対する似ている単語のスコアを計算します
+    ///
+    /// # Arguments
+    ///
+    /// * `check_word` - The check word(チェックする単語)
+    /// * `similar_word` - Similar word to be scored(スコアを計算する似ている単語)
+    /// * `frequency` - Frequency of the similar word(似ている単語の頻度)
+    pub fn score(&self, check_word: &str, similar_word: &SimilarWord, frequency: f64) -> f64 {
+        let common_prefix_length = check_word
+            .chars()
+            .zip(similar_word.spelling.chars())
+            .take_while(|(c, s)| c == s)
+            .count();
+
+        self.typo_type.get(&similar_word.typo_type)
+            - self.distance_penalty * similar_word.levenshtein_length as f64
+            + self.frequency_factor * frequency
+            + self.prefix_bonus * common_prefix_length as f64
+            + self.adjacent_extra_character_bonus
+                * f64::from(u8::from(similar_word.is_adjacent_extra_character()))
+    }
+
+    /// Sorts the similar words in descending order of score. Words not in the frequency map have a frequency of 0.0
+    ///
+    /// 似ている単語をスコアの高い順にソートします。頻度のハッシュマップに無い単語の頻度は0.0とします
+    pub(crate) fn sort_by_score(
+        &self,
+        check_word: &str,
+        similar_word_list: &mut [SimilarWord],
+        frequency_map: &HashMap<String, f64>,
+    ) {
+        let frequency_of = |similar_word: &SimilarWord| {
+            frequency_map
+                .get(&similar_word.spelling)
+                .copied()
+                .unwrap_or(0.0)
+        };
+        similar_word_list.sort_by(|a, b| {
+            self.score(check_word, b, frequency_of(b))
+                .total_cmp(&self.score(check_word, a, frequency_of(a)))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SuggestionSource;
+
+    #[test]
+    fn test_score_prefers_classified_word() {
+        let weights = RankingWeights::default();
+        let classified_word = SimilarWord {
+            spelling: "tost".to_string(),
+            levenshtein_length: 1,
+            typo_type: TypoType::SimilarShapes,
+            source: SuggestionSource::FuzzySearch,
+        };
+        let undefined_word = SimilarWord::new("best".to_string(), 1);
+
+        assert!(
+            weights.score("test", &classified_word, 0.0)
+                > weights.score("test", &undefined_word, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_default_weights_follow_default_sort_order() {
+        let weights = TypoTypeWeights::default();
+        let mut sort_order = crate::default_sort_order_of_typo_type();
+        // ソート条件に含まれないCustomは最後になる
+        sort_order.push(TypoType::Custom("custom".to_string()));
+
+        for pair in sort_order.windows(2) {
+            assert!(
+                weights.get(&pair[0]) > weights.get(&pair[1]),
+                "{:?} should rank above {:?}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_sort_by_score_custom_weights() {
+        let weights = RankingWeights {
+            typo_type: TypoTypeWeights {
+                close_keyboard_placement: 10.0,
+                ..TypoTypeWeights::default()
+            },
+            ..RankingWeights::default()
+        };
+        let mut similar_word_list = vec![
+            SimilarWord {
+                spelling: "tost".to_string(),
+                levenshtein_length: 1,
+                typo_type: TypoType::SimilarShapes,
+                source: SuggestionSource::FuzzySearch,
+            },
+            SimilarWord {
+                spelling: "text".to_string(),
+                levenshtein_length: 1,
+                typo_type: TypoType::CloseKeyboardPlacement,
+                source: SuggestionSource::FuzzySearch,
+            },
+        ];
+
+        weights.sort_by_score("test", &mut similar_word_list, &HashMap::new());
+        assert_eq!(similar_word_list[0].spelling, "text");
+
+        // 頻度が高い単語を上位にする
+        let weights = RankingWeights {
+            frequency_factor: 1.0,
+            ..weights
+        };
+        let frequency_map: HashMap<String, f64> = [("tost".to_string(), 100.0)].into();
+        weights.sort_by_score("test", &mut similar_word_list, &frequency_map);
+        assert_eq!(similar_word_list[0].spelling, "tost");
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml_str_partial() {
+        let weights = RankingWeights::from_toml_str(
+            "prefix_bonus = 0.5\n[typo_type]\nundefined_type = -1.0\n",
+        )
+        .unwrap();
+
+        assert_eq!(weights.prefix_bonus, 0.5);
+        assert_eq!(weights.typo_type.undefined_type, -1.0);
+        assert_eq!(weights.distance_penalty, 1.0);
+        assert_eq!(weights.typo_type.similar_shapes, 2.0);
+    }
+}
@@ -0,0 +1,240 @@
+/// SHA-256のブロックの大きさ(バイト)
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// SHA-256のラウンド定数
+const SHA256_ROUND_CONSTANTS: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+/// SHA-256のハッシュ値の初期値
+const SHA256_INITIAL_STATE: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+/// SHA-256の1ブロックを処理する
+fn sha256_compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut schedule = [0u32; 64];
+    for (word, bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    for i in 16..64 {
+        let s0 = schedule[i - 15].rotate_right(7)
+            ^ schedule[i - 15].rotate_right(18)
+            ^ (schedule[i - 15] >> 3);
+        let s1 = schedule[i - 2].rotate_right(17)
+            ^ schedule[i - 2].rotate_right(19)
+            ^ (schedule[i - 2] >> 10);
+        schedule[i] = schedule[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(schedule[i - 7])
+            .wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+    for (round_constant, word) in SHA256_ROUND_CONSTANTS.iter().zip(schedule.iter()) {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(choice)
+            .wrapping_add(*round_constant)
+            .wrapping_add(*word);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(majority);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+    }
+    for (value, added) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+        *value = value.wrapping_add(added);
+    }
+}
+
+/// 複数のバイト列を連結したもののSHA-256を返す
+fn sha256(part_list: &[&[u8]]) -> [u8; 32] {
+    let message: Vec<u8> = part_list.concat();
+    let mut padded_message = message.clone();
+    padded_message.push(0x80);
+    while padded_message.len() % SHA256_BLOCK_SIZE != SHA256_BLOCK_SIZE - 8 {
+        padded_message.push(0);
+    }
+    padded_message.extend_from_slice(&((message.len() as u64) * 8).to_be_bytes());
+
+    let mut state = SHA256_INITIAL_STATE;
+    for block in padded_message.chunks_exact(SHA256_BLOCK_SIZE) {
+        sha256_compress(&mut state, block);
+    }
+    let mut digest = [0u8; 32];
+    for (bytes, value) in digest.chunks_exact_mut(4).zip(state.iter()) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// HMAC-SHA256を返す
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    // ブロックより長いキーはハッシュしてから使う
+    let mut block_key = [0u8; SHA256_BLOCK_SIZE];
+    if key.len() > SHA256_BLOCK_SIZE {
+        block_key[..32].copy_from_slice(&sha256(&[key]));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+    let inner_key: Vec<u8> = block_key.iter().map(|byte| byte ^ 0x36).collect();
+    let outer_key: Vec<u8> = block_key.iter().map(|byte| byte ^ 0x5c).collect();
+
+    let inner_hash = sha256(&[&inner_key, message]);
+    sha256(&[&outer_key, &inner_hash])
+}
+
+/// Returns the HMAC-SHA256 of the word keyed by `key`, to log findings of sensitive documents without the raw text.
+/// The same word gives the same hash regardless of case, so findings can still be counted per word.
+/// Without the key, the words cannot be recovered or guessed from the hashes, even by hashing the whole dictionary.
+///
+/// 機密の文書の指摘を元の文字列なしで記録するために、`key`を使った単語のHMAC-SHA256を返します。
+/// 大文字小文字に関係なく同じ単語は同じハッシュになるため、単語ごとに指摘を数えることができます。
+/// キーが無ければ、辞書の全ての単語をハッシュしてもハッシュから単語を復元したり推測したりできません。
+///
+/// # Arguments
+///
+/// * `word` - Word to redact(秘匿する単語)
+/// * `key` - Secret key of the hash(ハッシュの秘密のキー)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::redact_word;
+///
+/// let redacted_word = redact_word("Jhon", "secret");
+/// assert_eq!(redacted_word, redact_word("jhon", "secret"));
+/// assert_ne!(redacted_word, redact_word("jhon", "other secret"));
+/// assert!(!redacted_word.contains("jhon"));
+/// ```
+pub fn redact_word(word: &str, key: &str) -> String {
+    let digest = hmac_sha256(key.as_bytes(), word.to_lowercase().as_bytes());
+    let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("redacted:{}", hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// バイト列を16進数の文字列にする
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_sha256_and_hmac_test_vectors() {
+        assert_eq!(
+            to_hex(&sha256(&[b""])),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            to_hex(&sha256(&[
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            ])),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        // RFC 4231のテストケース2と6(ブロックより長いキー)
+        assert_eq!(
+            to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            to_hex(&hmac_sha256(
+                &[0xaa; 131],
+                b"Test Using Larger Than Block-Size Key - Hash Key First"
+            )),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_redact_word_is_stable() {
+        // 実行環境やバージョンによらず同じ値になる
+        assert_eq!(
+            redact_word("", ""),
+            "redacted:b613679a0814d9ec772f95d778c35fc5ff1697c493715653c6c712144292c5ad"
+        );
+        assert_ne!(redact_word("ab", "c"), redact_word("b", "ca"));
+        assert_ne!(redact_word("apple", "key"), redact_word("apply", "key"));
+    }
+}
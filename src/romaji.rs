@@ -0,0 +1,161 @@
+use crate::{
+    get_top_similar_words_with_table, levenshtein, CharacterTable, CheckOptions, SimilarWord,
+    SuggestionSource, TypoCheckResult,
+};
+
+/// Returns the canonical spelling of a romanized Japanese word.
+/// Hepburn spellings are converted to Kunrei (shi => si, tsu => tu), long vowels (ou, oo, ō) and doubled consonants (tt) are shortened.
+///
+/// ローマ字の単語を正規化した綴りを返します。
+/// ヘボン式の綴りは訓令式に変換し(shi => si, tsu => tu)、長音(ou, oo, ō)と促音(tt)は短縮します。
+///
+/// # Arguments
+///
+/// * `word` - Romanized Japanese word(ローマ字の単語)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::normalize_romaji;
+///
+/// assert_eq!(normalize_romaji("Shinkansen"), normalize_romaji("sinkansen"));
+/// assert_eq!(normalize_romaji("tōkyō"), normalize_romaji("toukyou"));
+/// ```
+pub fn normalize_romaji(word: &str) -> String {
+    let mut normalized: String = word
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'ā' | 'â' => 'a',
+            'ī' | 'î' => 'i',
+            'ū' | 'û' => 'u',
+            'ē' | 'ê' => 'e',
+            'ō' | 'ô' => 'o',
+            _ => c,
+        })
+        .filter(|c| *c != '\'' && *c != '-')
+        .collect();
+
+    // ヘボン式の綴りを訓令式に変換する(長い綴りから順に置換する)
+    for (hepburn, kunrei) in [
+        ("cch", "tch"),
+        ("shi", "si"),
+        ("sh", "sy"),
+        ("chi", "ti"),
+        ("ch", "ty"),
+        ("tsu", "tu"),
+        ("fu", "hu"),
+        ("ji", "zi"),
+        ("j", "zy"),
+        ("mb", "nb"),
+        ("mp", "np"),
+    ] {
+        normalized = normalized.replace(hepburn, kunrei);
+    }
+
+    // 促音と長音のように連続する同じ文字を1文字にする
+    let mut char_list: Vec<char> = normalized.chars().collect();
+    char_list.dedup();
+    let normalized: String = char_list.into_iter().collect();
+
+    // ou の長音を o にする
+    normalized.replace("ou", "o")
+}
+
+/// Calculate the Levenshtein distance between two romanized Japanese words after normalization, so spelling variants cost nothing.
+///
+/// 正規化した後のローマ字の単語同士のレーベンシュタイン距離を計算します。綴りの揺れは距離に含まれません。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::romaji_levenshtein;
+///
+/// assert_eq!(0, romaji_levenshtein("tsunami", "tunami"));
+/// assert_eq!(1, romaji_levenshtein("sushi", "sishi"));
+/// ```
+pub fn romaji_levenshtein(a: &str, b: &str) -> usize {
+    levenshtein(&normalize_romaji(a), &normalize_romaji(b))
+}
+
+/// Returns the bundled list of romanized Japanese words.
+///
+/// 同梱のローマ字の単語リストを返します。
+pub fn romaji_word_list() -> Vec<&'static str> {
+    include_str!("romaji_words.txt").lines().collect()
+}
+
+/// Returns TypoCheckResult type words that match or are similar to the romanized Japanese word, using the bundled romaji word list.
+/// Spelling variants such as long vowels, doubled consonants and Hepburn/Kunrei differences are not counted as typos.
+///
+/// 同梱のローマ字の単語リストを使って、チェックするローマ字の単語に合致、もしくは類似する単語をTypoCheckResult型で返却します。
+/// 長音、促音、ヘボン式と訓令式の違いなどの綴りの揺れはタイポとして扱いません。
+///
+/// # Arguments
+///
+/// * `check_word` - Romanized Japanese word to check(チェックするローマ字の単語)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_romaji_word, CheckOptions};
+///
+/// let typo_chec_result = check_romaji_word("toukyou", &CheckOptions::default());
+/// assert_eq!(typo_chec_result.get_match_word(), "tokyo");
+/// ```
+pub fn check_romaji_word(check_word: &str, options: &CheckOptions) -> TypoCheckResult {
+    let lowercase_check_word = check_word.to_lowercase();
+    let mut output = TypoCheckResult::new(lowercase_check_word.clone());
+    let mut similar_word_list: Vec<SimilarWord> = Vec::new();
+
+    for word in romaji_word_list() {
+        let levenshtein_length = romaji_levenshtein(&lowercase_check_word, word);
+        if levenshtein_length == 0 {
+            output.match_word = Some(word.to_string());
+            return output;
+        }
+        similar_word_list.push(SimilarWord::new(word.to_string(), levenshtein_length));
+    }
+
+    let check_word_length = lowercase_check_word.chars().count();
+    output.telemetry.scanned_word_num = similar_word_list.len();
+    output.telemetry.pass_list = vec![SuggestionSource::FuzzySearch];
+    output.similar_word_list = Some(get_top_similar_words_with_table(
+        lowercase_check_word,
+        check_word_length,
+        similar_word_list,
+        options,
+        &CharacterTable::for_options(options),
+        &mut output.telemetry,
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_romaji_variants() {
+        assert_eq!(normalize_romaji("shashin"), "syasin");
+        assert_eq!(normalize_romaji("matcha"), normalize_romaji("maccha"));
+        assert_eq!(normalize_romaji("shimbun"), normalize_romaji("sinbun"));
+        assert_eq!(normalize_romaji("ohayoo"), normalize_romaji("ohayou"));
+        assert_eq!(
+            normalize_romaji("kon'nichiwa"),
+            normalize_romaji("konichiwa")
+        );
+        assert_ne!(normalize_romaji("sushi"), normalize_romaji("soba"));
+    }
+
+    #[test]
+    fn test_check_romaji_word_similar() {
+        let typo_chec_result = check_romaji_word("susi", &CheckOptions::default());
+        assert_eq!(typo_chec_result.get_match_word(), "sushi");
+
+        let typo_chec_result = check_romaji_word("sashimj", &CheckOptions::default());
+        let similar_word_list = typo_chec_result.get_similar_word_list();
+        assert_eq!(similar_word_list[0].spelling, "sashimi");
+    }
+}
@@ -0,0 +1,88 @@
+use std::cmp::Ordering;
+
+use crate::CheckOptions;
+
+/// Struct that screens out candidates cheaply before the exact Levenshtein distance is calculated.
+/// The difference in length and the common characters give a lower bound of the distance, so no candidate within the cutoff is lost.
+///
+/// 正確なレーベンシュタイン距離を計算する前に、候補を軽い計算で除外する構造体です。
+/// 文字数の差と共通する文字から距離の下限を求めるため、カットオフ値以内の候補が除外されることはありません。
+#[derive(Debug, Clone)]
+pub(crate) struct Prescreen {
+    sorted_char_list: Vec<char>,
+    cutoff: usize,
+}
+
+/// ソートした文字列同士の共通する文字数(多重集合の共通部分の大きさ)を返す
+fn common_char_num(a: &[char], b: &[char]) -> usize {
+    let (mut i, mut j, mut common_num) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                common_num += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    common_num
+}
+
+impl Prescreen {
+    /// 絞り込みが無効な場合やカットオフ値が無い場合はNoneを返す
+    pub(crate) fn new(
+        check_word: &str,
+        cutoff: Option<usize>,
+        options: &CheckOptions,
+    ) -> Option<Self> {
+        if !options.prescreen {
+            return None;
+        }
+        let mut sorted_char_list: Vec<char> = check_word.chars().collect();
+        sorted_char_list.sort_unstable();
+        Some(Prescreen {
+            sorted_char_list,
+            cutoff: cutoff?,
+        })
+    }
+
+    /// 距離の下限がカットオフ値以内の場合にtrueを返す
+    pub(crate) fn passes(&self, word: &str) -> bool {
+        let word_length = word.chars().count();
+        if word_length.abs_diff(self.sorted_char_list.len()) > self.cutoff {
+            return false;
+        }
+
+        let mut char_list: Vec<char> = word.chars().collect();
+        char_list.sort_unstable();
+        let lower_bound = word_length.max(self.sorted_char_list.len())
+            - common_char_num(&self.sorted_char_list, &char_list);
+        lower_bound <= self.cutoff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::levenshtein;
+
+    #[test]
+    fn test_prescreen_keeps_words_within_cutoff() {
+        let options = CheckOptions::default();
+        let prescreen = Prescreen::new("applo", Some(2), &options).unwrap();
+
+        for word in [
+            "apple", "apply", "appl", "applies", "lopap", "zzzzz", "ap", "banana",
+        ] {
+            if levenshtein("applo", word) <= 2 {
+                assert!(prescreen.passes(word), "{}", word);
+            }
+        }
+        assert!(!prescreen.passes("zzzzz"));
+        assert!(!prescreen.passes("banana"));
+        assert!(Prescreen::new("applo", None, &options).is_none());
+        assert!(Prescreen::new("applo", Some(2), &options.clone().prescreen(false)).is_none());
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+
+use crate::text::check_token;
+use crate::{tokenize_with, CheckOptions, RankingWeights, TypoType};
+
+/// Struct that stores a corrected alternative of a sentence
+///
+/// 文の修正案を格納する構造体です
+///
+/// # Arguments
+///
+/// * `text` - Corrected sentence(修正した文)
+/// * `score` - Sum of the scores of the chosen words. A higher score is a better alternative(選んだ単語のスコアの合計。スコアが高いほど良い修正案です)
+/// * `corrected_word_num` - Number of corrected words(修正した単語数)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentenceAlternative {
+    pub text: String,
+    pub score: f64,
+    pub corrected_word_num: usize,
+}
+
+/// トークンの候補(置き換える綴りとスコア)。Noneの場合は元の綴りのまま
+type WordCandidate = (Option<String>, f64);
+
+/// 元の単語の大文字小文字に合わせる
+pub(crate) fn match_case(original: &str, spelling: &str) -> String {
+    let mut original_chars = original.chars();
+    match original_chars.next() {
+        Some(first) if first.is_uppercase() => {
+            if original.chars().count() > 1 && original_chars.all(char::is_uppercase) {
+                spelling.to_uppercase()
+            } else {
+                let mut spelling_chars = spelling.chars();
+                spelling_chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(spelling_chars).collect())
+                    .unwrap_or_default()
+            }
+        }
+        _ => spelling.to_string(),
+    }
+}
+
+/// Returns the top `max_alternatives` corrected sentences, combining the candidates of each word.
+/// Each word is scored with `ranking_weights` of the options or the default RankingWeights.
+///
+/// 単語ごとの候補を組み合わせて、上位`max_alternatives`個の修正した文を返します。
+/// 各単語はオプションの`ranking_weights`、もしくはデフォルトのRankingWeightsでスコアを計算します。
+///
+/// # Arguments
+///
+/// * `text` - Sentence to correct(修正する文)
+/// * `max_alternatives` - Maximum number of alternatives(修正案の最大数)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{suggest_sentence, CheckOptions};
+///
+/// let alternative_list = suggest_sentence("Teh applo pie", 3, &CheckOptions::default());
+/// for alternative in &alternative_list {
+///     println!("{} ({:.2})", alternative.text, alternative.score);
+/// }
+/// assert!(alternative_list[0].text.ends_with(" apple pie"));
+/// ```
+pub fn suggest_sentence(
+    text: &str,
+    max_alternatives: usize,
+    options: &CheckOptions,
+) -> Vec<SentenceAlternative> {
+    suggest_sentence_with_frequency(text, max_alternatives, options, &HashMap::new())
+}
+
+/// Same as `suggest_sentence`, but adds the logarithm of the frequency of each candidate to its score.
+/// Words not in the frequency map are treated as frequency 0.
+///
+/// `suggest_sentence`と同じですが、各候補の頻度の対数をスコアに加えます。
+/// 頻度のハッシュマップに無い単語は頻度0として扱います。
+///
+/// # Arguments
+///
+/// * `text` - Sentence to correct(修正する文)
+/// * `max_alternatives` - Maximum number of alternatives(修正案の最大数)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `frequency_map` - Hashmap of words and their frequency(単語とその頻度のハッシュマップ)
+pub fn suggest_sentence_with_frequency(
+    text: &str,
+    max_alternatives: usize,
+    options: &CheckOptions,
+    frequency_map: &HashMap<String, f64>,
+) -> Vec<SentenceAlternative> {
+    if max_alternatives == 0 {
+        return Vec::new();
+    }
+    let default_ranking_weights = RankingWeights::default();
+    let ranking_weights = options
+        .ranking_weights
+        .as_ref()
+        .unwrap_or(&default_ranking_weights);
+
+    // 修正案ごとに、トークンごとに選んだ候補の番号とスコアの合計を持つ
+    let token_list = tokenize_with(text, &options.unit_suffixes);
+    let mut candidate_table: Vec<Vec<WordCandidate>> = Vec::new();
+    let mut beam: Vec<(Vec<usize>, f64)> = vec![(Vec::new(), 0.0)];
+    for token in &token_list {
+        let typo_check_result = check_token(token, options);
+        let similar_word_list = typo_check_result
+            .similar_word_list
+            .as_deref()
+            .unwrap_or(&[]);
+
+        let candidate_list: Vec<WordCandidate> = if similar_word_list.is_empty() {
+            vec![(None, 0.0)]
+        } else {
+            similar_word_list
+                .iter()
+                .map(|similar_word| {
+                    let frequency = frequency_map
+                        .get(&similar_word.spelling)
+                        .copied()
+                        .unwrap_or(0.0)
+                        .max(0.0);
+                    // Caps Lockの候補は大文字小文字を直した綴りのまま使う
+                    let spelling = if similar_word.typo_type == TypoType::CapsLock {
+                        similar_word.spelling.clone()
+                    } else {
+                        match_case(token.text, &similar_word.spelling)
+                    };
+                    (
+                        Some(spelling),
+                        ranking_weights.score(&typo_check_result.check_word, similar_word, 0.0)
+                            + frequency.ln_1p(),
+                    )
+                })
+                .collect()
+        };
+
+        // スコアは単語ごとに独立しているため、上位の修正案のみを残しても上位の結果は変わらない
+        let mut next_beam: Vec<(Vec<usize>, f64)> = beam
+            .iter()
+            .flat_map(|(choice_list, score)| {
+                candidate_list
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, (_, candidate_score))| {
+                        let mut choice_list = choice_list.clone();
+                        choice_list.push(i);
+                        (choice_list, score + candidate_score)
+                    })
+            })
+            .collect();
+        next_beam.sort_by(|a, b| b.1.total_cmp(&a.1));
+        next_beam.truncate(max_alternatives);
+        beam = next_beam;
+        candidate_table.push(candidate_list);
+    }
+
+    beam.into_iter()
+        .map(|(choice_list, score)| {
+            let mut corrected_text = String::with_capacity(text.len());
+            let mut corrected_word_num = 0;
+            let mut last_end = 0;
+            for ((token, candidate_list), choice) in
+                token_list.iter().zip(&candidate_table).zip(choice_list)
+            {
+                corrected_text.push_str(&text[last_end..token.start]);
+                match &candidate_list[choice].0 {
+                    Some(spelling) if spelling != token.text => {
+                        corrected_text.push_str(spelling);
+                        corrected_word_num += 1;
+                    }
+                    _ => corrected_text.push_str(token.text),
+                }
+                last_end = token.end;
+            }
+            corrected_text.push_str(&text[last_end..]);
+
+            SentenceAlternative {
+                text: corrected_text,
+                score,
+                corrected_word_num,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest_sentence_keeps_correct_words() {
+        let alternative_list = suggest_sentence("hello, APPLO!", 2, &CheckOptions::default());
+
+        assert_eq!(alternative_list.len(), 2);
+        assert_eq!(alternative_list[0].text, "hello, APPLE!");
+        assert_eq!(alternative_list[0].corrected_word_num, 1);
+        assert!(alternative_list[0].score >= alternative_list[1].score);
+        assert_eq!(
+            suggest_sentence("hello world", 3, &CheckOptions::default()),
+            vec![SentenceAlternative {
+                text: "hello world".to_string(),
+                score: 0.0,
+                corrected_word_num: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_suggest_sentence_with_frequency() {
+        let options = CheckOptions::default();
+        let frequency_map: HashMap<String, f64> = [("apply".to_string(), 1000.0)].into();
+
+        let alternative_list =
+            suggest_sentence_with_frequency("applo", 1, &options, &frequency_map);
+        assert_eq!(alternative_list[0].text, "apply");
+        assert_eq!(suggest_sentence("applo", 1, &options)[0].text, "apple");
+    }
+}
@@ -0,0 +1,146 @@
+use crate::similar_shape_list;
+
+/// Enum of the bundled groups of characters that are similar in shape, used to classify SimilarShapes
+///
+/// SimilarShapesの判別に使う、同梱の形状が似ている文字のグループの列挙型です
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ShapeProfile {
+    /// Groups of `similar_shape_list`(`similar_shape_list`のグループ)
+    #[default]
+    Default,
+    /// Groups of `similar_shape_list` and confusions of scanned text, such as "rn" and "m", "cl" and "d", "0" and "o", and "1", "l" and "i"(`similar_shape_list`のグループと、「rn」と「m」、「cl」と「d」、「0」と「o」、「1」と「l」と「i」などのスキャンした文章の取り違え)
+    Ocr,
+    /// Only the groups added by `similar_shape_group` of CheckOptions(CheckOptionsの`similar_shape_group`で追加したグループのみ)
+    Custom,
+}
+
+impl ShapeProfile {
+    /// Returns the groups of single characters that are similar in shape.
+    ///
+    /// 形状が似ている1文字のグループを返します。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::ShapeProfile;
+    ///
+    /// let similar_group = ShapeProfile::Ocr.similar_shape_list();
+    /// assert!(similar_group.contains(&vec!['0', 'o']));
+    /// assert!(ShapeProfile::Custom.similar_shape_list().is_empty());
+    /// ```
+    pub fn similar_shape_list(&self) -> Vec<Vec<char>> {
+        match self {
+            ShapeProfile::Default => similar_shape_list(),
+            ShapeProfile::Ocr => {
+                let mut similar_group = similar_shape_list();
+                similar_group.push(vec!['0', 'o']);
+                similar_group.push(vec!['1', 'l', 'i']);
+                similar_group
+            }
+            ShapeProfile::Custom => Vec::new(),
+        }
+    }
+
+    /// Returns the pairs of character sequences that are similar in shape, such as "rn" and "m". Each pair can be replaced in both directions(「rn」と「m」などの、形状が似ている文字の並びの組を返します。それぞれの組は双方向に置き換えられます)
+    pub fn similar_shape_sequence_list(&self) -> Vec<(&'static str, &'static str)> {
+        match self {
+            ShapeProfile::Ocr => vec![("rn", "m"), ("cl", "d"), ("vv", "w"), ("li", "h")],
+            ShapeProfile::Default | ShapeProfile::Custom => Vec::new(),
+        }
+    }
+}
+
+/// チェックする単語の1か所の文字の並びを、形状が似ている文字の並びに置き換えると正しい単語になるかどうか
+pub(crate) fn is_similar_shape_sequence(
+    check_word: &str,
+    spelling: &str,
+    similar_shape_sequence: &[(&str, &str)],
+) -> bool {
+    similar_shape_sequence
+        .iter()
+        .flat_map(|(a, b)| [(*a, *b), (*b, *a)])
+        .any(|(from, to)| {
+            check_word.match_indices(from).any(|(index, _)| {
+                let replaced_word = format!(
+                    "{}{}{}",
+                    &check_word[..index],
+                    to,
+                    &check_word[index + from.len()..]
+                );
+                replaced_word == spelling
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_a_word_with, CheckOptions, TypoType};
+
+    #[test]
+    fn test_is_similar_shape_sequence() {
+        let similar_shape_sequence = ShapeProfile::Ocr.similar_shape_sequence_list();
+        assert!(is_similar_shape_sequence(
+            "rnodel",
+            "model",
+            &similar_shape_sequence
+        ));
+        assert!(is_similar_shape_sequence(
+            "dog",
+            "clog",
+            &similar_shape_sequence
+        ));
+        assert!(!is_similar_shape_sequence(
+            "rnodel",
+            "modem",
+            &similar_shape_sequence
+        ));
+        assert!(!is_similar_shape_sequence("rnodel", "model", &[]));
+    }
+
+    #[test]
+    fn test_shape_profile_option() {
+        let find_typo_type = |options: &CheckOptions, check_word: &str, correct_word: &str| {
+            check_a_word_with(check_word, options)
+                .get_similar_word_list()
+                .into_iter()
+                .find(|similar_word| similar_word.get_spelling() == correct_word)
+                .map(|similar_word| similar_word.get_typo_type().clone())
+        };
+        let options = CheckOptions::default().pickup_similar_word_num(100);
+        let ocr_options = options.clone().shape_profile(ShapeProfile::Ocr);
+
+        assert_eq!(
+            find_typo_type(&ocr_options, "rnodel", "model"),
+            Some(TypoType::SimilarShapes)
+        );
+        assert_ne!(
+            find_typo_type(&options, "rnodel", "model"),
+            Some(TypoType::SimilarShapes)
+        );
+        assert_eq!(
+            find_typo_type(&ocr_options, "he1lo", "hello"),
+            Some(TypoType::SimilarShapes)
+        );
+
+        // Customでは追加したグループのみを使う
+        let custom_options = options
+            .clone()
+            .shape_profile(ShapeProfile::Custom)
+            .similar_shape_group(vec!['h', 'k']);
+        assert_eq!(
+            find_typo_type(&custom_options, "kello", "hello"),
+            Some(TypoType::SimilarShapes)
+        );
+        assert_ne!(
+            find_typo_type(&custom_options, "applo", "apple"),
+            Some(TypoType::SimilarShapes)
+        );
+        assert_eq!(
+            find_typo_type(&options, "applo", "apple"),
+            Some(TypoType::SimilarShapes)
+        );
+    }
+}
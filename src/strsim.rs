@@ -0,0 +1,374 @@
+use std::cmp::max;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Error of the metrics that need arguments of the same length, same as `strsim::StrSimError`
+///
+/// 同じ長さの引数が必要な距離のエラーです。`strsim::StrSimError`と同じです
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrSimError {
+    /// The arguments have different lengths(引数の長さが異なります)
+    DifferentLengthArgs,
+}
+
+impl fmt::Display for StrSimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrSimError::DifferentLengthArgs => write!(f, "Differing length arguments provided"),
+        }
+    }
+}
+
+impl std::error::Error for StrSimError {}
+
+/// Result of the Hamming distance, same as `strsim::HammingResult`(ハミング距離の結果です。`strsim::HammingResult`と同じです)
+pub type HammingResult = Result<usize, StrSimError>;
+
+/// Calculate the Hamming distance between two sequences of the same length.
+///
+/// 同じ長さの2つの列のハミング距離を計算します。
+///
+/// # Arguments
+///
+/// * `a` - Sequence A to be compared(比較対象の列A)
+/// * `b` - Sequence B to be compared(比較対象の列B)
+pub fn generic_hamming<Iter1, Iter2, Elem1, Elem2>(a: Iter1, b: Iter2) -> HammingResult
+where
+    Iter1: IntoIterator<Item = Elem1>,
+    Iter2: IntoIterator<Item = Elem2>,
+    Elem1: PartialEq<Elem2>,
+{
+    let mut a_iter = a.into_iter();
+    let mut b_iter = b.into_iter();
+    let mut distance = 0;
+    loop {
+        match (a_iter.next(), b_iter.next()) {
+            (Some(a_elem), Some(b_elem)) => distance += usize::from(a_elem != b_elem),
+            (None, None) => return Ok(distance),
+            _ => return Err(StrSimError::DifferentLengthArgs),
+        }
+    }
+}
+
+/// Calculate the Hamming distance between two words of the same number of characters.
+///
+/// 同じ文字数の2つの単語のハミング距離を計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::{hamming, StrSimError};
+///
+/// assert_eq!(Ok(3), hamming("hamming", "hammers"));
+/// assert_eq!(Err(StrSimError::DifferentLengthArgs), hamming("ham", "hamming"));
+/// ```
+pub fn hamming(a: &str, b: &str) -> HammingResult {
+    generic_hamming(a.chars(), b.chars())
+}
+
+/// Calculate the Levenshtein distance with `levenshtein` of this crate.
+///
+/// このクレートの`levenshtein`でレーベンシュタイン距離を計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::levenshtein;
+///
+/// assert_eq!(3, levenshtein("kitten", "sitting"));
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    crate::levenshtein(a, b)
+}
+
+/// 距離を長い方の文字数で割って0.0から1.0の類似度にする。両方が空の場合は1.0
+fn normalize_distance(distance: usize, a: &str, b: &str) -> f64 {
+    let max_length = max(a.chars().count(), b.chars().count());
+    if max_length == 0 {
+        return 1.0;
+    }
+    1.0 - distance as f64 / max_length as f64
+}
+
+/// Calculate the Levenshtein distance normalized to a similarity from 0.0 to 1.0, where 1.0 means the same word.
+///
+/// 0.0から1.0の類似度に正規化したレーベンシュタイン距離を計算します。1.0は同じ単語を意味します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::normalized_levenshtein;
+///
+/// assert!((normalized_levenshtein("kitten", "sitting") - 0.57142).abs() < 0.00001);
+/// assert_eq!(1.0, normalized_levenshtein("", ""));
+/// ```
+pub fn normalized_levenshtein(a: &str, b: &str) -> f64 {
+    normalize_distance(levenshtein(a, b), a, b)
+}
+
+/// Calculate the optimal string alignment distance, the Levenshtein distance where a swap of adjacent characters costs 1. A substring is never edited more than once.
+///
+/// 隣り合う文字の入れ替えを1とするレーベンシュタイン距離である、最適文字列アライメント距離を計算します。同じ部分を2回以上編集することはありません。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::osa_distance;
+///
+/// assert_eq!(1, osa_distance("ab", "ba"));
+/// assert_eq!(3, osa_distance("ca", "abc"));
+/// ```
+pub fn osa_distance(a: &str, b: &str) -> usize {
+    let a_char_list: Vec<char> = a.chars().collect();
+    let b_char_list: Vec<char> = b.chars().collect();
+
+    // 2つ前の行、1つ前の行、現在の行
+    let mut previous_two_cache: Vec<usize> = vec![0; b_char_list.len() + 1];
+    let mut previous_cache: Vec<usize> = (0..=b_char_list.len()).collect();
+    let mut cache: Vec<usize> = vec![0; b_char_list.len() + 1];
+    for (i, a_char) in a_char_list.iter().enumerate() {
+        cache[0] = i + 1;
+        for (j, b_char) in b_char_list.iter().enumerate() {
+            let cost = usize::from(a_char != b_char);
+            let mut distance = (previous_cache[j] + cost)
+                .min(previous_cache[j + 1] + 1)
+                .min(cache[j] + 1);
+            if i > 0 && j > 0 && *a_char == b_char_list[j - 1] && a_char_list[i - 1] == *b_char {
+                distance = distance.min(previous_two_cache[j - 1] + 1);
+            }
+            cache[j + 1] = distance;
+        }
+        std::mem::swap(&mut previous_two_cache, &mut previous_cache);
+        std::mem::swap(&mut previous_cache, &mut cache);
+    }
+    previous_cache[b_char_list.len()]
+}
+
+/// Calculate the Damerau-Levenshtein distance, the Levenshtein distance where a swap of adjacent characters costs 1, allowing edits between the swapped characters.
+///
+/// 隣り合う文字の入れ替えを1とし、入れ替えた文字の間の編集も許すレーベンシュタイン距離である、ダメラウ・レーベンシュタイン距離を計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::damerau_levenshtein;
+///
+/// assert_eq!(2, damerau_levenshtein("ca", "abc"));
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a_char_list: Vec<char> = a.chars().collect();
+    let b_char_list: Vec<char> = b.chars().collect();
+    let a_length = a_char_list.len();
+    let b_length = b_char_list.len();
+    if a_length == 0 {
+        return b_length;
+    }
+    if b_length == 0 {
+        return a_length;
+    }
+
+    // 先頭に番兵の行と列を加えた表(番兵は最大の距離)
+    let width = b_length + 2;
+    let max_distance = a_length + b_length;
+    let mut table: Vec<usize> = vec![0; (a_length + 2) * width];
+    table[0] = max_distance;
+    for i in 0..=a_length {
+        table[(i + 1) * width] = max_distance;
+        table[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b_length {
+        table[j + 1] = max_distance;
+        table[width + j + 1] = j;
+    }
+
+    // 文字ごとの最後に現れたaの行
+    let mut last_row_map: HashMap<char, usize> = HashMap::new();
+    for i in 1..=a_length {
+        // この行で最後に一致したbの列
+        let mut last_match_column = 0;
+        for j in 1..=b_length {
+            let last_row = *last_row_map.get(&b_char_list[j - 1]).unwrap_or(&0);
+            let last_column = last_match_column;
+            let cost = if a_char_list[i - 1] == b_char_list[j - 1] {
+                last_match_column = j;
+                0
+            } else {
+                1
+            };
+            let transposition = table[last_row * width + last_column]
+                + (i - last_row - 1)
+                + 1
+                + (j - last_column - 1);
+            table[(i + 1) * width + j + 1] = (table[i * width + j] + cost)
+                .min(table[(i + 1) * width + j] + 1)
+                .min(table[i * width + j + 1] + 1)
+                .min(transposition);
+        }
+        last_row_map.insert(a_char_list[i - 1], i);
+    }
+    table[(a_length + 1) * width + b_length + 1]
+}
+
+/// Calculate the Damerau-Levenshtein distance normalized to a similarity from 0.0 to 1.0, where 1.0 means the same word(1.0が同じ単語を意味する、0.0から1.0の類似度に正規化したダメラウ・レーベンシュタイン距離を計算します)
+pub fn normalized_damerau_levenshtein(a: &str, b: &str) -> f64 {
+    normalize_distance(damerau_levenshtein(a, b), a, b)
+}
+
+/// Calculate the Jaro similarity from 0.0 to 1.0, where 1.0 means the same word.
+///
+/// 1.0が同じ単語を意味する、0.0から1.0のジャロ類似度を計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::jaro;
+///
+/// assert!((jaro("martha", "marhta") - 0.94444).abs() < 0.00001);
+/// ```
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let a_char_list: Vec<char> = a.chars().collect();
+    let b_char_list: Vec<char> = b.chars().collect();
+    let a_length = a_char_list.len();
+    let b_length = b_char_list.len();
+    if a_length == 0 && b_length == 0 {
+        return 1.0;
+    }
+    if a_length == 0 || b_length == 0 {
+        return 0.0;
+    }
+
+    // 一致とみなす位置の差の最大値
+    let search_range = (max(a_length, b_length) / 2).saturating_sub(1);
+    let mut a_matched_list = vec![false; a_length];
+    let mut b_matched_list = vec![false; b_length];
+    let mut match_num = 0;
+    for (i, a_char) in a_char_list.iter().enumerate() {
+        let search_end = (i + search_range + 1).min(b_length);
+        for j in i.saturating_sub(search_range)..search_end {
+            if !b_matched_list[j] && *a_char == b_char_list[j] {
+                a_matched_list[i] = true;
+                b_matched_list[j] = true;
+                match_num += 1;
+                break;
+            }
+        }
+    }
+    if match_num == 0 {
+        return 0.0;
+    }
+
+    // 一致した文字を順に並べたときに、位置の合わない文字の数の半分が入れ替えの数
+    let a_matched_char_list = a_char_list
+        .iter()
+        .zip(&a_matched_list)
+        .filter(|(_, matched)| **matched);
+    let b_matched_char_list = b_char_list
+        .iter()
+        .zip(&b_matched_list)
+        .filter(|(_, matched)| **matched);
+    let transposition_num = a_matched_char_list
+        .zip(b_matched_char_list)
+        .filter(|((a_char, _), (b_char, _))| a_char != b_char)
+        .count()
+        / 2;
+
+    let match_num = match_num as f64;
+    (match_num / a_length as f64
+        + match_num / b_length as f64
+        + (match_num - transposition_num as f64) / match_num)
+        / 3.0
+}
+
+/// Calculate the Jaro-Winkler similarity, the Jaro similarity that gives a bonus to a common prefix of up to 4 characters when the Jaro similarity is more than 0.7.
+///
+/// ジャロ類似度が0.7より大きい場合に、4文字までの共通の接頭辞を加点するジャロ・ウィンクラー類似度を計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::jaro_winkler;
+///
+/// assert!((jaro_winkler("martha", "marhta") - 0.96111).abs() < 0.00001);
+/// ```
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let similarity = jaro(a, b);
+    if similarity <= 0.7 {
+        return similarity;
+    }
+    let prefix_length = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(a_char, b_char)| a_char == b_char)
+        .count()
+        .min(4);
+    similarity + 0.1 * prefix_length as f64 * (1.0 - similarity)
+}
+
+/// Calculate the Sørensen-Dice similarity of the character bigrams from 0.0 to 1.0, ignoring whitespace.
+///
+/// 空白を無視して、2文字の並びのセーレンセン・ダイス類似度を0.0から1.0で計算します。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::strsim::sorensen_dice;
+///
+/// assert_eq!(0.25, sorensen_dice("night", "nacht"));
+/// assert_eq!(1.0, sorensen_dice("apple pie", "applepie"));
+/// ```
+pub fn sorensen_dice(a: &str, b: &str) -> f64 {
+    let a_char_list: Vec<char> = a.chars().filter(|c| !c.is_whitespace()).collect();
+    let b_char_list: Vec<char> = b.chars().filter(|c| !c.is_whitespace()).collect();
+    if a_char_list == b_char_list {
+        return 1.0;
+    }
+    if a_char_list.len() < 2 || b_char_list.len() < 2 {
+        return 0.0;
+    }
+
+    let mut a_bigram_map: HashMap<(char, char), usize> = HashMap::new();
+    for pair in a_char_list.windows(2) {
+        *a_bigram_map.entry((pair[0], pair[1])).or_default() += 1;
+    }
+    let mut intersection_num = 0;
+    for pair in b_char_list.windows(2) {
+        if let Some(count) = a_bigram_map.get_mut(&(pair[0], pair[1])) {
+            if *count > 0 {
+                *count -= 1;
+                intersection_num += 1;
+            }
+        }
+    }
+    (2 * intersection_num) as f64 / (a_char_list.len() + b_char_list.len() - 2) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distances() {
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(osa_distance("", ""), 0);
+        assert_eq!(osa_distance("abc", ""), 3);
+        assert_eq!(osa_distance("teh", "the"), 1);
+        assert_eq!(damerau_levenshtein("teh", "the"), 1);
+        assert_eq!(damerau_levenshtein("", "abc"), 3);
+        assert_eq!(damerau_levenshtein("abcdef", "abcdef"), 0);
+        assert_eq!(normalized_damerau_levenshtein("", ""), 1.0);
+        assert_eq!(normalized_levenshtein("abc", ""), 0.0);
+        assert_eq!(generic_hamming([1, 2, 3], [1, 5, 3]), Ok(1));
+    }
+
+    #[test]
+    fn test_similarities() {
+        assert_eq!(jaro("", ""), 1.0);
+        assert_eq!(jaro("abc", ""), 0.0);
+        assert_eq!(jaro("abc", "xyz"), 0.0);
+        assert!((jaro("dixon", "dicksonx") - 0.76666).abs() < 0.00001);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.81333).abs() < 0.00001);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+        assert_eq!(sorensen_dice("", ""), 1.0);
+        assert_eq!(sorensen_dice("a", "b"), 0.0);
+    }
+}
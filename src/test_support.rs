@@ -0,0 +1,274 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    default_sort_order_of_typo_type, get_typo_type_name, CheckOptions, TypoCheckResult, TypoType,
+};
+
+/// Asserts the invariants of the result checked with the options.
+/// The similar words are sorted as configured, have no duplicates, are within the cutoff and their TypoType is consistent with the distance.
+///
+/// オプションでチェックした結果の不変条件を検証します。
+/// 似ている単語が設定通りにソートされ、重複がなく、カットオフ値以内で、TypoTypeが距離と矛盾しないことを確認します。
+///
+/// # Arguments
+///
+/// * `typo_check_result` - Result of typo check(タイポチェックの結果)
+/// * `options` - Options used for the check(チェックに使ったオプション)
+///
+/// # Panics
+///
+/// Panics if any invariant does not hold(いずれかの不変条件を満たさない場合にパニックします)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_a_word_with, CheckOptions};
+/// use typo_checker::test_support::assert_result_invariants;
+///
+/// let options = CheckOptions::default();
+/// assert_result_invariants(&check_a_word_with("applo", &options), &options);
+/// ```
+pub fn assert_result_invariants(typo_check_result: &TypoCheckResult, options: &CheckOptions) {
+    let check_word = &typo_check_result.check_word;
+    let similar_word_list = typo_check_result
+        .similar_word_list
+        .as_deref()
+        .unwrap_or(&[]);
+
+    if typo_check_result.match_word.is_some() {
+        // 完全一致した場合はCaps Lockの候補のみを持つ
+        assert!(
+            similar_word_list
+                .iter()
+                .all(|word| word.typo_type == TypoType::CapsLock),
+            "{}: an exact match has similar words other than CapsLock",
+            check_word
+        );
+    }
+    assert!(
+        similar_word_list.len() <= options.pickup_similar_word_num.max(1),
+        "{}: {} similar words exceed pickup_similar_word_num",
+        check_word,
+        similar_word_list.len()
+    );
+
+    let mut spelling_set = HashSet::new();
+    for word in similar_word_list {
+        assert!(
+            spelling_set.insert(&word.spelling),
+            "{}: \"{}\" is suggested more than once",
+            check_word,
+            word.spelling
+        );
+    }
+
+    let check_word_length = check_word.chars().count();
+    if let Some(cutoff) = options.search_levenshtein_cutoff(check_word_length) {
+        for word in similar_word_list {
+            assert!(
+                word.levenshtein_length <= cutoff
+                    || matches!(word.typo_type, TypoType::CommonMisspelling),
+                "{}: \"{}\" exceeds the cutoff {}",
+                check_word,
+                word.spelling,
+                cutoff
+            );
+        }
+    }
+
+    // 1文字の違いから判別するTypoTypeは距離が1、Caps Lockは距離が0になる
+    for word in similar_word_list {
+        let expected_length = match word.typo_type {
+            TypoType::ExtraCharacters { .. }
+            | TypoType::MissingCharacters { .. }
+            | TypoType::CloseKeyboardPlacement
+            | TypoType::SimilarShapes => Some(1),
+            TypoType::CapsLock => Some(0),
+            _ => None,
+        };
+        if let Some(expected_length) = expected_length {
+            assert_eq!(
+                word.levenshtein_length,
+                expected_length,
+                "{}: \"{}\" is {} with distance {}",
+                check_word,
+                word.spelling,
+                get_typo_type_name(&word.typo_type),
+                word.levenshtein_length
+            );
+        }
+    }
+
+    if let Some(ranking_weights) = &options.ranking_weights {
+        for pair in similar_word_list.windows(2) {
+            assert!(
+                ranking_weights.score(check_word, &pair[0], 0.0)
+                    >= ranking_weights.score(check_word, &pair[1], 0.0),
+                "{}: \"{}\" is ranked above \"{}\" with a lower score",
+                check_word,
+                pair[0].spelling,
+                pair[1].spelling
+            );
+        }
+    } else {
+        let default_sort_typo_type = default_sort_order_of_typo_type();
+        let typo_type_order: HashMap<String, usize> = options
+            .sort_order_of_typo_type
+            .as_ref()
+            .unwrap_or(&default_sort_typo_type)
+            .iter()
+            .enumerate()
+            .map(|(i, typo_type)| (get_typo_type_name(typo_type), i))
+            .collect();
+        let order = |typo_type: &TypoType| {
+            *typo_type_order
+                .get(&get_typo_type_name(typo_type))
+                .unwrap_or(&usize::MAX)
+        };
+        for pair in similar_word_list.windows(2) {
+            assert!(
+                order(&pair[0].typo_type) <= order(&pair[1].typo_type),
+                "{}: \"{}\" is not sorted by sort_order_of_typo_type",
+                check_word,
+                pair[1].spelling
+            );
+        }
+    }
+}
+
+/// Struct that generates random words and typos from a seed. The same seed generates the same sequence.
+///
+/// シードからランダムな単語とタイポを生成する構造体です。同じシードからは同じ並びが生成されます。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::test_support::TypoGenerator;
+///
+/// let mut typo_generator = TypoGenerator::new(42);
+/// let word = typo_generator.word(5);
+/// let typo = typo_generator.typo(&word);
+/// assert_eq!(word.chars().count(), 5);
+/// assert_eq!(typo_checker::levenshtein(&word, &typo), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TypoGenerator {
+    state: u64,
+}
+
+/// 生成する文字
+const ALPHABET: &[u8; 26] = b"abcdefghijklmnopqrstuvwxyz";
+
+impl TypoGenerator {
+    /// Returns a TypoGenerator of the seed(シードのTypoGeneratorを返します)
+    pub fn new(seed: u64) -> TypoGenerator {
+        // 状態が0のままだとxorshiftが0しか返さない
+        TypoGenerator {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// xorshift64で次の乱数を返す
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, max: usize) -> usize {
+        (self.next() % max as u64) as usize
+    }
+
+    fn letter(&mut self) -> char {
+        ALPHABET[self.below(ALPHABET.len())] as char
+    }
+
+    /// Returns a random lowercase word of the length(指定した文字数のランダムな小文字の単語を返します)
+    pub fn word(&mut self, length: usize) -> String {
+        (0..length).map(|_| self.letter()).collect()
+    }
+
+    /// Returns a random dictionary word of the length, or None if there is no word of the length(指定した文字数のランダムな辞書の単語を返します。その文字数の単語が無い場合はNoneを返します)
+    pub fn dictionary_word(&mut self, length: usize) -> Option<&'static str> {
+        let same_length_word_list: Vec<&'static str> = crate::dictionary::DICTIONARY
+            .get(length.checked_sub(1)?)?
+            .iter()
+            .map_while(|word| *word)
+            .collect();
+        if same_length_word_list.is_empty() {
+            return None;
+        }
+        Some(same_length_word_list[self.below(same_length_word_list.len())])
+    }
+
+    /// Returns a typo of Levenshtein distance 1 from the word by inserting, deleting or replacing one character(1文字の挿入・削除・置換で、単語とのレーベンシュタイン距離が1のタイポを返します)
+    pub fn typo(&mut self, word: &str) -> String {
+        let mut char_list: Vec<char> = word.chars().collect();
+        let operation = if char_list.is_empty() {
+            0
+        } else {
+            self.below(3)
+        };
+        match operation {
+            0 => {
+                let position = self.below(char_list.len() + 1);
+                let letter = self.letter();
+                char_list.insert(position, letter);
+            }
+            1 => {
+                let position = self.below(char_list.len());
+                char_list.remove(position);
+            }
+            _ => {
+                let position = self.below(char_list.len());
+                // 同じ文字に置換しないように別の文字を選ぶ
+                let offset = 1 + self.below(ALPHABET.len() - 1);
+                let original = char_list[position];
+                char_list[position] = match ALPHABET.iter().position(|c| *c as char == original) {
+                    Some(index) => ALPHABET[(index + offset) % ALPHABET.len()] as char,
+                    None => self.letter(),
+                };
+            }
+        }
+        char_list.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_a_word_with, levenshtein, RankingWeights};
+
+    #[test]
+    fn test_typo_generator_is_deterministic() {
+        let mut a = TypoGenerator::new(7);
+        let mut b = TypoGenerator::new(7);
+        for length in 1..10 {
+            let word = a.word(length);
+            assert_eq!(word, b.word(length));
+            let typo = a.typo(&word);
+            assert_eq!(typo, b.typo(&word));
+            assert_eq!(levenshtein(&word, &typo), 1);
+        }
+    }
+
+    #[test]
+    fn test_invariants_of_random_typos() {
+        let mut typo_generator = TypoGenerator::new(1);
+        let options_list = [
+            CheckOptions::default(),
+            CheckOptions::default().length_aware_cutoff(true),
+            CheckOptions::default().ranking_weights(RankingWeights::default()),
+            CheckOptions::default().widen_cutoff(5, 3),
+        ];
+        for _ in 0..20 {
+            let length = 3 + typo_generator.below(6);
+            let word = typo_generator.dictionary_word(length).unwrap();
+            let typo = typo_generator.typo(word);
+            for options in &options_list {
+                assert_result_invariants(&check_a_word_with(&typo, options), options);
+            }
+        }
+    }
+}
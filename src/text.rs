@@ -0,0 +1,454 @@
+use crate::{check_a_word_with, dictionary, redact_word, CheckOptions, TypoCheckResult};
+
+/// 短縮形の接尾辞(長いものから順に比較する)
+const CONTRACTION_SUFFIX_LIST: [&str; 7] = ["n't", "'re", "'ve", "'ll", "'s", "'d", "'m"];
+
+/// 接尾辞を除いた語幹が辞書の単語にならない短縮形
+const IRREGULAR_CONTRACTION_LIST: [&str; 3] = ["won't", "can't", "shan't"];
+
+/// Struct that stores a token of a text with its byte offsets
+///
+/// テキストのトークンをバイト位置とともに格納する構造体です
+///
+/// # Arguments
+///
+/// * `text` - Text of the token(トークンの文字列)
+/// * `start` - Byte offset of the start of the token in the text(テキストでのトークンの開始のバイト位置)
+/// * `end` - Byte offset of the end of the token in the text, exclusive(テキストでのトークンの終了のバイト位置。終了位置は含みません)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextToken<'a> {
+    pub text: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Struct that stores a token of a text that does not match the dictionary, with its byte offsets and the result of typo check
+///
+/// 辞書に一致しなかったテキストのトークンを、バイト位置とタイポチェックの結果とともに格納する構造体です
+///
+/// # Arguments
+///
+/// * `text` - Text of the token(トークンの文字列)
+/// * `start` - Byte offset of the start of the token in the text(テキストでのトークンの開始のバイト位置)
+/// * `end` - Byte offset of the end of the token in the text, exclusive(テキストでのトークンの終了のバイト位置。終了位置は含みません)
+/// * `typo_check_result` - Result of typo check of the token(トークンのタイポチェックの結果)
+#[derive(Debug, Clone)]
+pub struct TextTypo {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub typo_check_result: TypoCheckResult,
+}
+
+impl TextTypo {
+    /// Returns the finding with the token and the result replaced by `redact_word`, keeping the byte offsets, for logging findings of sensitive documents.
+    ///
+    /// 機密の文書の指摘を記録するために、バイト位置はそのままに、トークンと結果を`redact_word`で置き換えた指摘を返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Secret key of the hash(ハッシュの秘密のキー)
+    pub fn redacted(&self, key: &str) -> TextTypo {
+        TextTypo {
+            text: redact_word(&self.text, key),
+            start: self.start,
+            end: self.end,
+            typo_check_result: self.typo_check_result.redacted(key),
+        }
+    }
+}
+
+/// Splits the text into tokens of consecutive alphabetic characters. An apostrophe between letters, as in "don't", stays in the token.
+/// Numbers with a unit of the default unit list or a matching ordinal suffix, such as "10km" and "2nd", are not tokens.
+///
+/// テキストを連続した英字などの文字のトークンに分割します。"don't"のように英字に挟まれたアポストロフィはトークンに含めます。
+/// "10km"や"2nd"のように、デフォルトの単位のリストの単位や一致する序数の接尾辞が付いた数は、トークンにしません。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::tokenize;
+///
+/// let token_list = tokenize("Helo, wrold! 5GB on the 3rd");
+/// assert_eq!(token_list[1].text, "wrold");
+/// assert_eq!((token_list[1].start, token_list[1].end), (6, 11));
+/// assert_eq!(token_list.len(), 4);
+/// ```
+pub fn tokenize(text: &str) -> Vec<TextToken<'_>> {
+    tokenize_with(text, &CheckOptions::default().unit_suffixes)
+}
+
+/// Same as `tokenize`, but accepts the numbers with the given units, such as `unit_suffixes` of CheckOptions.
+///
+/// `tokenize`と同じですが、CheckOptionsの`unit_suffixes`などの指定した単位が付いた数を受け入れます。
+///
+/// # Arguments
+///
+/// * `text` - Text to split(分割するテキスト)
+/// * `unit_suffixes` - Units that can follow a number, compared regardless of case(数の後に付けられる単位。大文字小文字に関係なく比較します)
+pub fn tokenize_with<'a>(text: &'a str, unit_suffixes: &[String]) -> Vec<TextToken<'a>> {
+    let mut token_list = Vec::new();
+    let mut segment_start = None;
+    for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        let is_segment_char =
+            c.is_alphanumeric() || c == '°' || is_inner_apostrophe(text, index, c);
+        match (is_segment_char, segment_start) {
+            (true, None) => segment_start = Some(index),
+            (false, Some(start)) => {
+                push_segment_tokens(text, start, index, unit_suffixes, &mut token_list);
+                segment_start = None;
+            }
+            _ => {}
+        }
+    }
+    token_list
+}
+
+/// 英数字の並びからトークンを取り出す。単位や序数が付いた数はトークンにしない
+fn push_segment_tokens<'a>(
+    text: &'a str,
+    start: usize,
+    end: usize,
+    unit_suffixes: &[String],
+    token_list: &mut Vec<TextToken<'a>>,
+) {
+    let segment = &text[start..end];
+    let number_length = segment
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(segment.len());
+    if number_length > 0 {
+        let (number, suffix) = segment.split_at(number_length);
+        if suffix.is_empty() || is_accepted_suffix(number, suffix, unit_suffixes) {
+            return;
+        }
+    }
+
+    // 英字の並びのみをトークンにする
+    let mut token_start = None;
+    for (index, c) in segment.char_indices().chain([(segment.len(), ' ')]) {
+        match (
+            c.is_alphabetic() || is_inner_apostrophe(segment, index, c),
+            token_start,
+        ) {
+            (true, None) => token_start = Some(index),
+            (false, Some(token_start_index)) => {
+                token_list.push(TextToken {
+                    text: &segment[token_start_index..index],
+                    start: start + token_start_index,
+                    end: start + index,
+                });
+                token_start = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 英字に挟まれたアポストロフィ("don't"や"it's"など)の場合にtrueを返す
+fn is_inner_apostrophe(text: &str, index: usize, c: char) -> bool {
+    matches!(c, '\'' | '’')
+        && text[..index]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphabetic)
+        && text[index + c.len_utf8()..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphabetic)
+}
+
+/// 短縮形の語幹と接尾辞に分ける。語幹が辞書の単語にならない短縮形(won'tなど)は語幹をNoneにする
+fn split_contraction(word: &str) -> Option<(Option<&str>, &str)> {
+    if IRREGULAR_CONTRACTION_LIST.contains(&word) {
+        return Some((None, ""));
+    }
+    CONTRACTION_SUFFIX_LIST.iter().find_map(|suffix| {
+        word.strip_suffix(suffix)
+            .filter(|stem| !stem.is_empty() && !stem.contains('\''))
+            .map(|stem| (Some(stem), *suffix))
+    })
+}
+
+/// 数の後の接尾辞が単位、もしくは数に合った序数の場合にtrueを返す
+fn is_accepted_suffix(number: &str, suffix: &str, unit_suffixes: &[String]) -> bool {
+    let suffix = suffix.to_lowercase();
+    if unit_suffixes
+        .iter()
+        .any(|unit_suffix| unit_suffix.to_lowercase() == suffix)
+    {
+        return true;
+    }
+
+    // 下2桁で序数の接尾辞を決める(11〜13は"th")
+    let last_two_digits: u32 = number[number.len().saturating_sub(2)..]
+        .parse()
+        .unwrap_or(0);
+    let ordinal_suffix = match (last_two_digits % 100, last_two_digits % 10) {
+        (11..=13, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    };
+    suffix == ordinal_suffix
+}
+
+/// トークンをチェックする。辞書の最長の単語より長いトークンは候補を探さない
+pub(crate) fn check_token(token: &TextToken, options: &CheckOptions) -> TypoCheckResult {
+    check_token_by(token, |word| check_a_word_with(word, options))
+}
+
+/// `check_token`と同じだが、指定した関数で単語をチェックする
+pub(crate) fn check_token_by<F>(token: &TextToken, check_word: F) -> TypoCheckResult
+where
+    F: Fn(&str) -> TypoCheckResult,
+{
+    let lowercase_text = token.text.to_lowercase();
+    if lowercase_text.chars().count() > dictionary::DICTIONARY.len() + 1 {
+        return TypoCheckResult::new(lowercase_text);
+    }
+
+    // 短縮形は語幹をチェックし、候補には接尾辞を付け直す
+    let contraction_text = lowercase_text.replace('’', "'");
+    if let Some((stem, suffix)) = split_contraction(&contraction_text) {
+        let mut typo_check_result = match stem {
+            Some(stem) => check_word(stem),
+            None => TypoCheckResult {
+                match_word: Some(contraction_text.clone()),
+                ..TypoCheckResult::new(String::new())
+            },
+        };
+        if typo_check_result.is_match() {
+            typo_check_result.match_word = Some(contraction_text.clone());
+        }
+        if let Some(similar_word_list) = typo_check_result.similar_word_list.as_mut() {
+            for similar_word in similar_word_list {
+                similar_word.spelling.push_str(suffix);
+            }
+        }
+        typo_check_result.check_word = contraction_text;
+        return typo_check_result;
+    }
+    check_word(token.text)
+}
+
+/// テキストのトークンのうち、チェックしない単語を除いて辞書に一致しなかったものを集める
+pub(crate) fn collect_text_typos<F>(
+    text: &str,
+    options: &CheckOptions,
+    check_word: F,
+) -> Vec<TextTypo>
+where
+    F: Fn(&str) -> TypoCheckResult,
+{
+    tokenize_with(text, &options.unit_suffixes)
+        .into_iter()
+        .filter(|token| !options.is_skipped_word(token.text))
+        .filter_map(|token| {
+            let typo_check_result = check_token_by(&token, &check_word);
+            (!typo_check_result.is_match()).then(|| TextTypo {
+                text: token.text.to_string(),
+                start: token.start,
+                end: token.end,
+                typo_check_result,
+            })
+        })
+        .collect()
+}
+
+/// Checks every token of the text with the default options and returns the tokens that do not match the dictionary, with their byte offsets.
+/// The offsets can be used to highlight the typos in an editor buffer, see `position_at` for line and column positions.
+///
+/// テキストの全てのトークンをデフォルトのオプションでチェックし、辞書に一致しなかったトークンをバイト位置とともに返します。
+/// バイト位置はエディタのバッファでタイポをハイライトするのに使えます。行と列の位置は`position_at`を参照してください。
+///
+/// # Arguments
+///
+/// * `text` - Text to check(チェックするテキスト)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::check_text;
+///
+/// let text = "Helo wrold, hello world";
+/// for text_typo in check_text(text) {
+///     println!(
+///         "{}..{} {}: {:?}",
+///         text_typo.start,
+///         text_typo.end,
+///         &text[text_typo.start..text_typo.end],
+///         text_typo.typo_check_result.did_you_mean(3)
+///     );
+/// }
+/// assert_eq!(check_text(text).len(), 2);
+/// ```
+pub fn check_text(text: &str) -> Vec<TextTypo> {
+    let options = CheckOptions::default();
+    collect_text_typos(text, &options, |word| check_a_word_with(word, &options))
+}
+
+/// Checks every token of the text and calls the callback with the token and its result, including the tokens that match the dictionary.
+/// The results are not collected, so it can be used for statistics, highlighting and streaming updates.
+///
+/// テキストの全てのトークンをチェックし、辞書に一致したトークンも含めて、トークンとその結果でコールバックを呼び出します。
+/// 結果をまとめないため、統計やハイライト、逐次的な更新に使うことができます。
+///
+/// # Arguments
+///
+/// * `text` - Text to check(チェックするテキスト)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+/// * `callback` - Function called for each token in order(トークンごとに順に呼び出す関数)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_text_with, CheckOptions};
+///
+/// let mut typo_num = 0;
+/// check_text_with("Helo wrold, hello world", &CheckOptions::default(), |token, typo_chec_result| {
+///     if let Some(message) = typo_chec_result.did_you_mean(1) {
+///         typo_num += 1;
+///         println!("{}..{} {}: {}", token.start, token.end, token.text, message);
+///     }
+/// });
+/// assert_eq!(typo_num, 2);
+/// ```
+pub fn check_text_with<F>(text: &str, options: &CheckOptions, mut callback: F)
+where
+    F: FnMut(&TextToken<'_>, &TypoCheckResult),
+{
+    for token in tokenize_with(text, &options.unit_suffixes) {
+        let typo_check_result = check_token(&token, options);
+        callback(&token, &typo_check_result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_offsets() {
+        let text = "café's 10km, naïve";
+        let token_list = tokenize(text);
+
+        let text_list: Vec<&str> = token_list.iter().map(|token| token.text).collect();
+        assert_eq!(text_list, vec!["café's", "naïve"]);
+        for token in &token_list {
+            assert_eq!(&text[token.start..token.end], token.text);
+        }
+        assert!(tokenize(" , 123 ").is_empty());
+    }
+
+    #[test]
+    fn test_tokenize_units_and_ordinals() {
+        let text_list: Vec<&str> = tokenize("10km 5GB 3pm 25°C 1st 22nd 13th 2th 4foo 5 km")
+            .iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(text_list, vec!["th", "foo", "km"]);
+
+        let unit_suffixes = vec!["foo".to_string()];
+        let text_list: Vec<&str> = tokenize_with("10km 4FOO 11th", &unit_suffixes)
+            .iter()
+            .map(|token| token.text)
+            .collect();
+        assert_eq!(text_list, vec!["km"]);
+    }
+
+    #[test]
+    fn test_check_text_spans() {
+        let text = "Ths café is closd, 10km awya";
+        let text_typo_list = check_text(text);
+
+        let text_list: Vec<&str> = text_typo_list
+            .iter()
+            .map(|text_typo| text_typo.text.as_str())
+            .collect();
+        assert_eq!(text_list, vec!["Ths", "café", "closd", "awya"]);
+        for text_typo in &text_typo_list {
+            assert_eq!(&text[text_typo.start..text_typo.end], text_typo.text);
+            assert!(!text_typo.typo_check_result.is_match());
+        }
+    }
+
+    #[test]
+    fn test_check_text_contractions() {
+        assert!(check_text("I don't think it's wrong, and I won't say I’m sure.").is_empty());
+        assert_eq!(
+            tokenize("'quoted' rock 'n' roll")
+                .iter()
+                .map(|token| token.text)
+                .collect::<Vec<&str>>(),
+            vec!["quoted", "rock", "n", "roll"]
+        );
+
+        // 短縮形の語幹のタイポは、接尾辞を付けた候補を返す
+        let text_typo_list = check_text("It shoudn't work, the wrold's end");
+        let text_list: Vec<&str> = text_typo_list
+            .iter()
+            .map(|text_typo| text_typo.text.as_str())
+            .collect();
+        assert_eq!(text_list, vec!["shoudn't", "wrold's"]);
+        assert!(text_typo_list[0]
+            .typo_check_result
+            .get_similar_word_list()
+            .iter()
+            .any(|similar_word| similar_word.get_spelling() == "shouldn't"));
+        assert!(text_typo_list[1]
+            .typo_check_result
+            .get_similar_word_list()
+            .iter()
+            .any(|similar_word| similar_word.get_spelling() == "world's"));
+    }
+
+    #[test]
+    fn test_text_typo_redacted() {
+        let text = "Contact Jhon Smtih";
+        for text_typo in check_text(text) {
+            let redacted_text_typo = text_typo.redacted("secret");
+            let log_line = format!("{:?}", redacted_text_typo);
+
+            assert_eq!(
+                (redacted_text_typo.start, redacted_text_typo.end),
+                (text_typo.start, text_typo.end)
+            );
+            assert_eq!(
+                redacted_text_typo.text,
+                redact_word(&text_typo.text, "secret")
+            );
+            assert!(!log_line
+                .to_lowercase()
+                .contains(&text_typo.text.to_lowercase()));
+            for similar_word in text_typo.typo_check_result.get_similar_word_list() {
+                assert!(!log_line.contains(&format!("\"{}\"", similar_word.get_spelling())));
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_text_with_calls_every_token() {
+        let mut token_list = Vec::new();
+        check_text_with(
+            "The applo is pneumonoultramicroscopicsilicovolcanoconiosis",
+            &CheckOptions::default(),
+            |token, typo_check_result| {
+                token_list.push((token.text.to_string(), typo_check_result.is_match()))
+            },
+        );
+
+        assert_eq!(
+            token_list,
+            vec![
+                ("The".to_string(), true),
+                ("applo".to_string(), false),
+                ("is".to_string(), true),
+                (
+                    "pneumonoultramicroscopicsilicovolcanoconiosis".to_string(),
+                    false
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use std::fmt;
+
+use crate::{check_a_word_with_dictionary, dictionary, CheckOptions, Dictionary, TypoCheckResult};
+
+/// Struct that stores the hard limits of untrusted inputs for server use
+///
+/// サーバーで使うための、信頼できない入力の上限を格納する構造体です
+///
+/// # Arguments
+///
+/// * `max_word_length` - Maximum number of characters of a word. Words longer than the longest dictionary word are always rejected(単語の最大の文字数。辞書の最長の単語より長い単語は常に拒否されます)
+/// * `max_input_bytes` - Maximum number of bytes of the input of a request(1回のリクエストの入力の最大のバイト数)
+/// * `max_findings` - Maximum number of findings of a request(1回のリクエストの最大の指摘数)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ResourceLimits {
+    pub max_word_length: usize,
+    pub max_input_bytes: usize,
+    pub max_findings: usize,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> ResourceLimits {
+        ResourceLimits {
+            max_word_length: dictionary::DICTIONARY.len() + 1,
+            max_input_bytes: 64 * 1024,
+            max_findings: 1000,
+        }
+    }
+}
+
+/// Error when an untrusted input is rejected before the check
+///
+/// 信頼できない入力をチェックの前に拒否したときのエラーです
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UntrustedInputError {
+    /// The input is not valid UTF-8(入力がUTF-8として正しくありません)
+    InvalidUtf8(std::str::Utf8Error),
+    /// The input is empty(入力が空です)
+    Empty,
+    /// The word is longer than `max_word_length` or the longest word of the dictionary(単語が`max_word_length`または辞書の最長の単語より長いです)
+    TooLong { length: usize, max_length: usize },
+    /// The input is larger than `max_input_bytes`(入力が`max_input_bytes`より大きいです)
+    InputTooLarge { size: usize, max_size: usize },
+    /// The findings are more than `max_findings`(指摘が`max_findings`より多いです)
+    TooManyFindings(usize),
+    /// The input contains a control character(入力に制御文字が含まれています)
+    ControlCharacter(char),
+    /// The input contains whitespace and is not a single word(入力に空白が含まれており、1つの単語ではありません)
+    Whitespace,
+}
+
+impl fmt::Display for UntrustedInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UntrustedInputError::InvalidUtf8(error) => write!(f, "invalid utf-8: {}", error),
+            UntrustedInputError::Empty => write!(f, "empty input"),
+            UntrustedInputError::TooLong { length, max_length } => write!(
+                f,
+                "input is too long: {} characters (max {})",
+                length, max_length
+            ),
+            UntrustedInputError::InputTooLarge { size, max_size } => {
+                write!(f, "input is too large: {} bytes (max {})", size, max_size)
+            }
+            UntrustedInputError::TooManyFindings(max_findings) => {
+                write!(f, "too many findings (max {})", max_findings)
+            }
+            UntrustedInputError::ControlCharacter(c) => {
+                write!(f, "input contains a control character: {:?}", c)
+            }
+            UntrustedInputError::Whitespace => write!(f, "input contains whitespace"),
+        }
+    }
+}
+
+impl std::error::Error for UntrustedInputError {}
+
+/// Checks arbitrary bytes of user-generated content without panicking.
+/// Inputs that `check_a_word_with` cannot handle or that exceed `resource_limits` of the options are rejected with UntrustedInputError instead.
+///
+/// ユーザーが作成した任意のバイト列を、パニックせずにチェックします。
+/// `check_a_word_with`で扱えない入力やオプションの`resource_limits`を超える入力はUntrustedInputErrorとして拒否します。
+///
+/// # Arguments
+///
+/// * `input` - Bytes or string to check(チェックするバイト列または文字列)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_untrusted, CheckOptions, UntrustedInputError};
+///
+/// let typo_chec_result = check_untrusted("applo", &CheckOptions::default()).unwrap();
+/// println!("typo_chec_result: {:?}", typo_chec_result);
+///
+/// assert!(matches!(
+///     check_untrusted(b"\xff\xfe", &CheckOptions::default()),
+///     Err(UntrustedInputError::InvalidUtf8(_))
+/// ));
+/// assert_eq!(
+///     check_untrusted("app\u{0}le", &CheckOptions::default()).unwrap_err(),
+///     UntrustedInputError::ControlCharacter('\u{0}')
+/// );
+/// ```
+pub fn check_untrusted<B: AsRef<[u8]>>(
+    input: B,
+    options: &CheckOptions,
+) -> Result<TypoCheckResult, UntrustedInputError> {
+    check_untrusted_with_dictionary(input, options, &Dictionary::builtin())
+}
+
+/// `check_untrusted`と同じだが、組み込み辞書の代わりに指定した辞書を探索する
+pub(crate) fn check_untrusted_with_dictionary<B: AsRef<[u8]>>(
+    input: B,
+    options: &CheckOptions,
+    dictionary: &Dictionary,
+) -> Result<TypoCheckResult, UntrustedInputError> {
+    let resource_limits = &options.resource_limits;
+    let size = input.as_ref().len();
+    if size > resource_limits.max_input_bytes {
+        return Err(UntrustedInputError::InputTooLarge {
+            size,
+            max_size: resource_limits.max_input_bytes,
+        });
+    }
+    let check_word =
+        std::str::from_utf8(input.as_ref()).map_err(UntrustedInputError::InvalidUtf8)?;
+
+    if check_word.is_empty() {
+        return Err(UntrustedInputError::Empty);
+    }
+    if let Some(c) = check_word.chars().find(|c| c.is_control()) {
+        return Err(UntrustedInputError::ControlCharacter(c));
+    }
+    if check_word.chars().any(char::is_whitespace) {
+        return Err(UntrustedInputError::Whitespace);
+    }
+
+    // 小文字にすると文字数が増える文字があるため、小文字にした文字数で判定する
+    let length = check_word.to_lowercase().chars().count();
+    let max_length = resource_limits
+        .max_word_length
+        .min(dictionary::DICTIONARY.len() + 1);
+    if length > max_length {
+        return Err(UntrustedInputError::TooLong { length, max_length });
+    }
+
+    Ok(check_a_word_with_dictionary(
+        check_word, options, dictionary,
+    ))
+}
+
+/// Checks the untrusted words of a request and returns only the findings, which are the words that do not match the dictionary.
+/// The total size of the words is limited by `max_input_bytes` and the number of findings by `max_findings`.
+///
+/// 1回のリクエストの信頼できない単語をチェックし、辞書に一致しなかった単語の指摘のみを返します。
+/// 単語の合計のサイズは`max_input_bytes`で、指摘の数は`max_findings`で制限されます。
+///
+/// # Arguments
+///
+/// * `input_list` - Bytes or strings to check(チェックするバイト列または文字列)
+/// * `options` - Options of typo check(タイポチェックのオプション)
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_untrusted_words, CheckOptions, ResourceLimits, UntrustedInputError};
+///
+/// let finding_list = check_untrusted_words(["apple", "applo"], &CheckOptions::default()).unwrap();
+/// assert_eq!(finding_list.len(), 1);
+///
+/// let options = CheckOptions::default().resource_limits(ResourceLimits {
+///     max_findings: 1,
+///     ..ResourceLimits::default()
+/// });
+/// assert_eq!(
+///     check_untrusted_words(["applo", "bananna"], &options).unwrap_err(),
+///     UntrustedInputError::TooManyFindings(1)
+/// );
+/// ```
+pub fn check_untrusted_words<I, B>(
+    input_list: I,
+    options: &CheckOptions,
+) -> Result<Vec<TypoCheckResult>, UntrustedInputError>
+where
+    I: IntoIterator<Item = B>,
+    B: AsRef<[u8]>,
+{
+    let resource_limits = &options.resource_limits;
+    let mut size = 0;
+    let mut finding_list = Vec::new();
+
+    for input in input_list {
+        size += input.as_ref().len();
+        if size > resource_limits.max_input_bytes {
+            return Err(UntrustedInputError::InputTooLarge {
+                size,
+                max_size: resource_limits.max_input_bytes,
+            });
+        }
+
+        let typo_check_result = check_untrusted(input, options)?;
+        // チェックしなかった単語は指摘に含めない
+        if typo_check_result.match_word.is_some() || typo_check_result.similar_word_list.is_none() {
+            continue;
+        }
+        if finding_list.len() == resource_limits.max_findings {
+            return Err(UntrustedInputError::TooManyFindings(
+                resource_limits.max_findings,
+            ));
+        }
+        finding_list.push(typo_check_result);
+    }
+    Ok(finding_list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_untrusted_rejects() {
+        let options = CheckOptions::default();
+
+        assert_eq!(
+            check_untrusted("", &options).unwrap_err(),
+            UntrustedInputError::Empty
+        );
+        assert_eq!(
+            check_untrusted("two words", &options).unwrap_err(),
+            UntrustedInputError::Whitespace
+        );
+        assert_eq!(
+            check_untrusted("a".repeat(10000), &options).unwrap_err(),
+            UntrustedInputError::TooLong {
+                length: 10000,
+                max_length: 21
+            }
+        );
+    }
+
+    #[test]
+    fn test_resource_limits() {
+        let options = CheckOptions::default().resource_limits(ResourceLimits {
+            max_word_length: 4,
+            max_input_bytes: 10,
+            max_findings: 1,
+        });
+
+        assert_eq!(
+            check_untrusted("applo", &options).unwrap_err(),
+            UntrustedInputError::TooLong {
+                length: 5,
+                max_length: 4
+            }
+        );
+        assert_eq!(
+            check_untrusted_words(["tset", "cat", "dogs"], &options).unwrap_err(),
+            UntrustedInputError::InputTooLarge {
+                size: 11,
+                max_size: 10
+            }
+        );
+        // チェックしなかった単語は指摘に数えない
+        assert_eq!(
+            check_untrusted_words(["tset", "dgo"], &options.clone().stop_words(["dgo"]))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            check_untrusted_words(["tset", "dgo"], &options).unwrap_err(),
+            UntrustedInputError::TooManyFindings(1)
+        );
+        assert_eq!(
+            check_untrusted_words(["tset", "cat"], &options)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_check_untrusted_does_not_panic() {
+        let options = CheckOptions::default();
+        let input_list = [
+            "a".repeat(21),
+            "İİİİİİİİİİİ".to_string(),
+            "ß".repeat(20),
+            "漢字".to_string(),
+            "🦀🦀".to_string(),
+            "\u{200B}".to_string(),
+            "x".to_string(),
+            "'-'".to_string(),
+        ];
+        for input in input_list {
+            let _ = check_untrusted(&input, &options);
+            let _ = check_untrusted(&input, &options.clone().length_aware_cutoff(true));
+        }
+    }
+}
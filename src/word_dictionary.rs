@@ -0,0 +1,595 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+use crate::dictionary;
+
+/// 辞書の単語の持ち方
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WordStorage {
+    /// 組み込み辞書
+    Builtin,
+    /// 文字数ごとに辞書順に並べた単語
+    Custom(BTreeMap<usize, Vec<String>>),
+}
+
+/// 辞書を作成、変更するたびに新しい世代を返す。0は組み込み辞書の世代
+fn next_generation() -> u64 {
+    static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+    NEXT_GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// 組み込み辞書の文字数ごとの単語数(Noneで埋める前の単語数)と、全ての単語数
+struct BuiltinWordNum {
+    same_length_word_num_list: Vec<usize>,
+    total_word_num: usize,
+}
+
+/// 組み込み辞書の単語数を最初の呼び出しで一度だけ数えて返す
+fn builtin_word_num() -> &'static BuiltinWordNum {
+    static BUILTIN_WORD_NUM: OnceLock<BuiltinWordNum> = OnceLock::new();
+    BUILTIN_WORD_NUM.get_or_init(|| {
+        let same_length_word_num_list: Vec<usize> = dictionary::DICTIONARY
+            .iter()
+            .map(|same_length_word_list| {
+                same_length_word_list
+                    .iter()
+                    .take_while(|word| word.is_some())
+                    .count()
+            })
+            .collect();
+        BuiltinWordNum {
+            total_word_num: same_length_word_num_list.iter().sum(),
+            same_length_word_num_list,
+        }
+    })
+}
+
+/// 組み込み辞書の指定した文字数の単語を、Noneで埋めた部分を除いて返す(辞書順に並んでいる)
+fn builtin_words_with_length(length: usize) -> &'static [Option<&'static str>] {
+    match length.checked_sub(2) {
+        Some(index) if index < dictionary::DICTIONARY.len() => {
+            &dictionary::DICTIONARY[index][..builtin_word_num().same_length_word_num_list[index]]
+        }
+        _ => &[],
+    }
+}
+
+/// Struct that stores optional metadata of a dictionary word
+///
+/// 辞書の単語の任意のメタデータを格納する構造体です
+///
+/// # Arguments
+///
+/// * `proper_noun` - Proper noun that starts with an uppercase letter, such as "Paris"("Paris"のように大文字で始まる固有名詞)
+/// * `region` - Region where the spelling is used, such as "GB" for "colour"("colour"に対する"GB"のような、その綴りが使われる地域)
+/// * `domain` - Domain of the word, such as "medical"("medical"のような単語の分野)
+/// * `forbidden` - Word that is never suggested, such as a banned term(禁止されている用語などの、候補にしない単語)
+/// * `replacements` - Words to use instead of the forbidden word(禁止されている単語の代わりに使う単語)
+/// * `tags` - Other tags of the word(単語のその他のタグ)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct WordMetadata {
+    pub proper_noun: bool,
+    pub region: Option<String>,
+    pub domain: Option<String>,
+    pub forbidden: bool,
+    pub replacements: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl WordMetadata {
+    /// Sets whether the word is a proper noun(固有名詞かどうかを設定します)
+    pub fn proper_noun(mut self, proper_noun: bool) -> Self {
+        self.proper_noun = proper_noun;
+        self
+    }
+
+    /// Sets the region where the spelling is used(その綴りが使われる地域を設定します)
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = Some(region.to_string());
+        self
+    }
+
+    /// Sets the domain of the word(単語の分野を設定します)
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    /// Sets whether the word is never suggested(候補にしない単語かどうかを設定します)
+    pub fn forbidden(mut self, forbidden: bool) -> Self {
+        self.forbidden = forbidden;
+        self
+    }
+
+    /// Adds a word to use instead of the forbidden word(禁止されている単語の代わりに使う単語を追加します)
+    pub fn replacement(mut self, replacement: &str) -> Self {
+        self.replacements.push(replacement.to_string());
+        self
+    }
+
+    /// Adds a tag of the word(単語のタグを追加します)
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Returns true if the case of the word follows the metadata, a proper noun must start with an uppercase letter(単語の大文字小文字がメタデータに従っている場合にtrueを返します。固有名詞は大文字で始まる必要があります)
+    pub fn matches_case(&self, word: &str) -> bool {
+        !self.proper_noun || word.chars().next().is_some_and(char::is_uppercase)
+    }
+
+    /// Returns true if the spelling can be used in the region. A word without a region can be used in every region(その地域で綴りを使える場合にtrueを返します。地域が無い単語は全ての地域で使えます)
+    pub fn is_used_in(&self, region: &str) -> bool {
+        self.region
+            .as_ref()
+            .is_none_or(|word_region| word_region.eq_ignore_ascii_case(region))
+    }
+
+    /// 単語のリストの属性を読み込む。属性が無い場合はNoneを返す
+    fn parse<'a>(attribute_list: impl Iterator<Item = &'a str>) -> Option<WordMetadata> {
+        let mut metadata = WordMetadata::default();
+        let mut has_attribute = false;
+        for attribute in attribute_list {
+            has_attribute = true;
+            metadata = match attribute.split_once('=') {
+                None if attribute == "proper_noun" => metadata.proper_noun(true),
+                None if attribute == "forbidden" => metadata.forbidden(true),
+                Some(("region", region)) => metadata.region(region),
+                Some(("domain", domain)) => metadata.domain(domain),
+                Some(("replacement", replacement)) => metadata.replacement(replacement),
+                _ => metadata.tag(attribute),
+            };
+        }
+        has_attribute.then_some(metadata)
+    }
+}
+
+/// Struct of a dictionary searched by the typo check, the built-in dictionary or a word list of your own.
+/// A word list can contain words of any length. The words are read through the query methods, so use `Dictionary::builtin` instead of the raw array of `get_dictionary`.
+///
+/// タイポチェックで探索する辞書の構造体です。組み込み辞書か独自の単語のリストです。
+/// 単語のリストには任意の文字数の単語を含めることができます。単語は問い合わせのメソッドで読むため、`get_dictionary`の配列の代わりに`Dictionary::builtin`を使ってください。
+///
+/// # Examples
+///
+/// ```
+/// use typo_checker::{check_a_word_with_dictionary, CheckOptions, Dictionary};
+///
+/// let word_list = "# project words\nkubernetes\nrustacean\n";
+/// let dictionary = Dictionary::from_lines(word_list.as_bytes()).unwrap();
+/// assert!(dictionary.contains("Kubernetes"));
+///
+/// let typo_chec_result = check_a_word_with_dictionary("kubernetis", &CheckOptions::default(), &dictionary);
+/// assert_eq!(typo_chec_result.did_you_mean(1), Some("did you mean \"kubernetes\"?".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    word_storage: WordStorage,
+    metadata_map: HashMap<String, WordMetadata>,
+    generation: u64,
+}
+
+// 世代は結果のキャッシュのためのもので、単語とメタデータが同じ辞書は等しいとする
+impl PartialEq for Dictionary {
+    fn eq(&self, other: &Dictionary) -> bool {
+        self.word_storage == other.word_storage && self.metadata_map == other.metadata_map
+    }
+}
+
+impl Eq for Dictionary {}
+
+impl Default for Dictionary {
+    fn default() -> Dictionary {
+        Dictionary::builtin()
+    }
+}
+
+impl Dictionary {
+    /// Returns the built-in dictionary(組み込み辞書を返します)
+    pub fn builtin() -> Dictionary {
+        Dictionary {
+            word_storage: WordStorage::Builtin,
+            metadata_map: HashMap::new(),
+            generation: 0,
+        }
+    }
+
+    /// Reads a word list of one word per line. Words are lowercased, and empty lines and lines starting with `#` are ignored.
+    /// Attributes of WordMetadata can follow the word separated by whitespace, such as `colour region=GB domain=art`.
+    /// `proper_noun` and `forbidden` set the flags, `region=` and `domain=` set the values, `replacement=` adds a replacement, and the others are tags.
+    ///
+    /// 1行に1単語の単語のリストを読み込みます。単語は小文字にし、空行と`#`で始まる行は無視します。
+    /// `colour region=GB domain=art`のように、単語の後に空白で区切ってWordMetadataの属性を指定できます。
+    /// `proper_noun`と`forbidden`はフラグを、`region=`と`domain=`は値を設定し、`replacement=`は代わりの単語を追加し、それ以外はタグになります。
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Reader of the word list(単語のリストのリーダー)
+    pub fn from_lines<R: BufRead>(reader: R) -> io::Result<Dictionary> {
+        let mut word_map: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        let mut metadata_map: HashMap<String, WordMetadata> = HashMap::new();
+        for line in reader.lines() {
+            let line = line?;
+            let mut field_list = line.split_whitespace();
+            let word = match field_list.next() {
+                Some(word) if !word.starts_with('#') => word.to_lowercase(),
+                _ => continue,
+            };
+            if let Some(metadata) = WordMetadata::parse(field_list) {
+                metadata_map.insert(word.clone(), metadata);
+            }
+            word_map.entry(word.chars().count()).or_default().push(word);
+        }
+        for same_length_word_list in word_map.values_mut() {
+            same_length_word_list.sort();
+            same_length_word_list.dedup();
+        }
+        Ok(Dictionary {
+            word_storage: WordStorage::Custom(word_map),
+            metadata_map,
+            generation: next_generation(),
+        })
+    }
+
+    /// Same as `from_lines`, but reads the word list from the file(`from_lines`と同じですが、ファイルから単語のリストを読み込みます)
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Dictionary> {
+        Dictionary::from_lines(BufReader::new(File::open(path)?))
+    }
+
+    /// Sets the metadata of the word. The word is not added to the dictionary(単語のメタデータを設定します。単語は辞書に追加されません)
+    pub fn with_metadata(mut self, word: &str, metadata: WordMetadata) -> Self {
+        self.set_metadata(word, metadata);
+        self
+    }
+
+    /// Same as `with_metadata`, but changes the dictionary in place(`with_metadata`と同じですが、辞書をその場で変更します)
+    pub fn set_metadata(&mut self, word: &str, metadata: WordMetadata) {
+        self.metadata_map.insert(word.to_lowercase(), metadata);
+        self.generation = next_generation();
+    }
+
+    /// Returns the generation of the dictionary, which changes whenever the dictionary is created or changed, to key cached results such as `ResultCache::check_with_dictionary`.
+    /// The built-in dictionary is generation 0, and a clone keeps the generation until either of them is changed.
+    /// The generation is only unique within a process, so do not store it.
+    ///
+    /// `ResultCache::check_with_dictionary`などのキャッシュした結果のキーにするために、辞書を作成、変更するたびに変わる辞書の世代を返します。
+    /// 組み込み辞書は世代0で、複製した辞書はどちらかを変更するまで同じ世代です。
+    /// 世代は同じプロセスの中でのみ一意のため、保存しないでください。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::Dictionary;
+    ///
+    /// let mut dictionary = Dictionary::builtin();
+    /// let cloned_dictionary = dictionary.clone();
+    /// assert_eq!(dictionary.generation(), cloned_dictionary.generation());
+    ///
+    /// dictionary.add_word("kubernetes");
+    /// assert_ne!(dictionary.generation(), cloned_dictionary.generation());
+    /// ```
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the metadata of the word regardless of case, or None if it has no metadata(大文字小文字に関係なく単語のメタデータを返します。メタデータが無い場合はNoneを返します)
+    pub fn metadata(&self, word: &str) -> Option<&WordMetadata> {
+        if self.metadata_map.is_empty() {
+            return None;
+        }
+        self.metadata_map.get(&word.to_lowercase())
+    }
+
+    /// 候補にしない単語かどうか
+    pub(crate) fn is_forbidden(&self, word: &str) -> bool {
+        self.metadata(word)
+            .is_some_and(|metadata| metadata.forbidden)
+    }
+
+    /// Adds the word in lowercase and returns true if it was not in the dictionary.
+    /// Changing the built-in dictionary copies its words, so it is no longer the built-in dictionary.
+    ///
+    /// 単語を小文字で追加し、辞書に無かった場合にtrueを返します。
+    /// 組み込み辞書を変更すると単語を複製するため、組み込み辞書ではなくなります。
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to add(追加する単語)
+    pub fn add_word(&mut self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        let same_length_word_list = self.word_map_mut().entry(word.chars().count()).or_default();
+        match same_length_word_list.binary_search(&word) {
+            Ok(_) => false,
+            Err(index) => {
+                same_length_word_list.insert(index, word);
+                self.generation = next_generation();
+                true
+            }
+        }
+    }
+
+    /// Removes the word regardless of case and returns true if it was in the dictionary. The metadata of the word is kept.
+    ///
+    /// 大文字小文字に関係なく単語を削除し、辞書にあった場合にtrueを返します。単語のメタデータは残ります。
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - Word to remove(削除する単語)
+    pub fn remove_word(&mut self, word: &str) -> bool {
+        if !self.contains(word) {
+            return false;
+        }
+        let word = word.to_lowercase();
+        let word_map = self.word_map_mut();
+        let word_length = word.chars().count();
+        if let Some(same_length_word_list) = word_map.get_mut(&word_length) {
+            if let Ok(index) = same_length_word_list.binary_search(&word) {
+                same_length_word_list.remove(index);
+            }
+            if same_length_word_list.is_empty() {
+                word_map.remove(&word_length);
+            }
+        }
+        self.generation = next_generation();
+        true
+    }
+
+    /// 変更できる単語の表を返す。組み込み辞書の場合は単語を複製する
+    fn word_map_mut(&mut self) -> &mut BTreeMap<usize, Vec<String>> {
+        if self.word_storage == WordStorage::Builtin {
+            let word_map = dictionary::DICTIONARY
+                .iter()
+                .enumerate()
+                .map(|(index, same_length_word_list)| {
+                    let word_list: Vec<String> = same_length_word_list
+                        .iter()
+                        .map_while(|word| word.map(str::to_string))
+                        .collect();
+                    (index + 2, word_list)
+                })
+                .filter(|(_, word_list)| !word_list.is_empty())
+                .collect();
+            self.word_storage = WordStorage::Custom(word_map);
+        }
+        match &mut self.word_storage {
+            WordStorage::Custom(word_map) => word_map,
+            WordStorage::Builtin => unreachable!(),
+        }
+    }
+
+    /// Returns true if it is the built-in dictionary(組み込み辞書の場合にtrueを返します)
+    pub fn is_builtin(&self) -> bool {
+        self.word_storage == WordStorage::Builtin
+    }
+
+    /// Returns true if the dictionary contains the word, regardless of case(大文字小文字に関係なく、辞書に単語が含まれる場合にtrueを返します)
+    pub fn contains(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        match &self.word_storage {
+            // 組み込み辞書は2文字以上の単語のため、1文字の単語は"a"と"i"のみを受け入れる
+            WordStorage::Builtin if word == "a" || word == "i" => true,
+            WordStorage::Builtin => builtin_words_with_length(word.chars().count())
+                .binary_search(&Some(word.as_str()))
+                .is_ok(),
+            WordStorage::Custom(word_map) => {
+                word_map
+                    .get(&word.chars().count())
+                    .is_some_and(|same_length_word_list| {
+                        same_length_word_list.binary_search(&word).is_ok()
+                    })
+            }
+        }
+    }
+
+    /// Returns the number of words(単語数を返します)
+    pub fn len(&self) -> usize {
+        match &self.word_storage {
+            WordStorage::Builtin => builtin_word_num().total_word_num,
+            WordStorage::Custom(word_map) => word_map.values().map(Vec::len).sum(),
+        }
+    }
+
+    /// Returns true if the dictionary has no word(辞書に単語が無い場合にtrueを返します)
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the range from the shortest to the longest length of the words, or None if the dictionary has no word(単語の最短から最長までの文字数の範囲を返します。辞書に単語が無い場合はNoneを返します)
+    pub fn length_range(&self) -> Option<RangeInclusive<usize>> {
+        let mut length_iter: Box<dyn DoubleEndedIterator<Item = usize> + '_> =
+            match &self.word_storage {
+                WordStorage::Builtin => Box::new(
+                    dictionary::DICTIONARY
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, same_length_word_list)| same_length_word_list[0].is_some())
+                        .map(|(index, _)| index + 2),
+                ),
+                WordStorage::Custom(word_map) => Box::new(
+                    word_map
+                        .iter()
+                        .filter(|(_, same_length_word_list)| !same_length_word_list.is_empty())
+                        .map(|(length, _)| *length),
+                ),
+            };
+        let min_length = length_iter.next()?;
+        let max_length = length_iter.next_back().unwrap_or(min_length);
+        Some(min_length..=max_length)
+    }
+
+    /// Returns all words in order of length, and in dictionary order for the same length(全ての単語を文字数順に返します。同じ文字数の単語は辞書順です)
+    pub fn words(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        match &self.word_storage {
+            WordStorage::Builtin => Box::new(dictionary::DICTIONARY.iter().flat_map(
+                |same_length_word_list| same_length_word_list.iter().map_while(|word| *word),
+            )),
+            WordStorage::Custom(word_map) => {
+                Box::new(word_map.values().flatten().map(String::as_str))
+            }
+        }
+    }
+
+    /// Returns the words of the length in dictionary order, borrowing them from the dictionary without copying.
+    /// Use it with `length_range` to implement your own candidate generation.
+    /// It returns an iterator instead of `&[&str]`, because the built-in dictionary pads each length with None and a custom dictionary owns its words as String, so neither holds a slice of `&str`.
+    ///
+    /// 指定した文字数の単語を、辞書から複製せずに借用して辞書順に返します。
+    /// `length_range`と組み合わせて独自の候補の生成を実装するために使います。
+    /// 組み込み辞書は文字数ごとにNoneで埋めており、独自の辞書は単語をStringで持つため、どちらも`&str`のスライスを持たないので、`&[&str]`の代わりにイテレータを返します。
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Number of characters of the words(単語の文字数)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typo_checker::Dictionary;
+    ///
+    /// let dictionary = Dictionary::builtin();
+    /// let length_range = dictionary.length_range().unwrap();
+    /// for length in length_range {
+    ///     let prefix_word_list: Vec<&str> = dictionary
+    ///         .words_with_length(length)
+    ///         .filter(|word| word.starts_with("appl"))
+    ///         .collect();
+    ///     println!("{}: {:?}", length, prefix_word_list);
+    /// }
+    /// assert!(dictionary.words_with_length(5).any(|word| word == "apple"));
+    /// ```
+    pub fn words_with_length(&self, length: usize) -> Box<dyn Iterator<Item = &str> + '_> {
+        match &self.word_storage {
+            WordStorage::Builtin => {
+                Box::new(builtin_words_with_length(length).iter().flatten().copied())
+            }
+            WordStorage::Custom(word_map) => Box::new(
+                word_map
+                    .get(&length)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_words_are_sorted() {
+        // containsは二分探索のため、組み込み辞書の単語は文字数ごとに辞書順である必要がある
+        for length in 0..dictionary::DICTIONARY.len() + 3 {
+            let word_list = builtin_words_with_length(length);
+            assert!(word_list.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(word_list.iter().all(Option::is_some));
+        }
+
+        let dictionary = Dictionary::builtin();
+        assert_eq!(dictionary.len(), dictionary.words().count());
+        assert!(dictionary.words().all(|word| dictionary.contains(word)));
+        assert!(!dictionary.contains("applo"));
+        assert!(!dictionary.contains(""));
+    }
+
+    #[test]
+    fn test_dictionary_from_lines() {
+        let dictionary = Dictionary::from_lines(
+            "Apple\n# comment\n\n  apple \na\npneumonoultramicroscopicsilicovolcanoconiosis\n"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert!(!dictionary.is_builtin());
+        assert_eq!(dictionary.len(), 3);
+        assert!(dictionary.contains("APPLE"));
+        assert!(dictionary.contains("a"));
+        assert!(dictionary.contains("pneumonoultramicroscopicsilicovolcanoconiosis"));
+        assert!(!dictionary.contains("apply"));
+        assert_eq!(
+            dictionary.words_with_length(5).collect::<Vec<&str>>(),
+            vec!["apple"]
+        );
+
+        let dictionary = Dictionary::builtin();
+        assert!(dictionary.contains("apple"));
+        assert_eq!(dictionary.words_with_length(1).count(), 0);
+        assert_eq!(dictionary.words_with_length(22).count(), 0);
+        assert_eq!(dictionary.length_range(), Some(2..=21));
+        assert_eq!(dictionary.words().count(), dictionary.len());
+        assert_eq!(
+            dictionary.words().next(),
+            dictionary.words_with_length(2).next()
+        );
+        assert_eq!(
+            Dictionary::from_lines("a\n".as_bytes())
+                .unwrap()
+                .length_range(),
+            Some(1..=1)
+        );
+        assert_eq!(
+            Dictionary::from_lines("".as_bytes())
+                .unwrap()
+                .length_range(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dictionary_metadata() {
+        let dictionary = Dictionary::from_lines(
+            "Paris proper_noun\ncolour region=GB domain=art\nfoo forbidden legacy\n".as_bytes(),
+        )
+        .unwrap()
+        .with_metadata("bar", WordMetadata::default().forbidden(true));
+
+        assert_eq!(dictionary.len(), 3);
+        let metadata = dictionary.metadata("PARIS").unwrap();
+        assert!(metadata.matches_case("Paris"));
+        assert!(!metadata.matches_case("paris"));
+        assert_eq!(
+            dictionary.metadata("colour"),
+            Some(&WordMetadata::default().region("GB").domain("art"))
+        );
+        assert!(dictionary.metadata("colour").unwrap().is_used_in("gb"));
+        assert!(!dictionary.metadata("colour").unwrap().is_used_in("US"));
+        assert_eq!(dictionary.metadata("foo").unwrap().tags, vec!["legacy"]);
+        assert!(dictionary.is_forbidden("Foo"));
+        assert!(dictionary.is_forbidden("bar"));
+        assert!(!dictionary.contains("bar"));
+    }
+
+    #[test]
+    fn test_add_and_remove_word() {
+        let mut dictionary = Dictionary::builtin();
+        let builtin_word_num = dictionary.len();
+
+        assert!(dictionary.add_word("Kubernetes"));
+        assert!(!dictionary.add_word("kubernetes"));
+        assert!(!dictionary.is_builtin());
+        assert_eq!(dictionary.len(), builtin_word_num + 1);
+        assert!(dictionary.contains("kubernetes"));
+        assert!(dictionary.contains("apple"));
+
+        // 単語を変更した場合のみ世代が変わる
+        let generation = dictionary.generation();
+        assert!(!dictionary.remove_word("applo"));
+        assert_eq!(dictionary.generation(), generation);
+        assert!(dictionary.remove_word("APPLE"));
+        assert!(!dictionary.remove_word("apple"));
+        assert_ne!(dictionary.generation(), generation);
+        assert!(!dictionary.contains("apple"));
+        assert_eq!(dictionary.len(), builtin_word_num);
+        // 組み込み辞書の順序は辞書順のまま保たれる
+        let word_list: Vec<&str> = dictionary.words_with_length(10).collect();
+        assert!(word_list.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}